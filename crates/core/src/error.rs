@@ -63,6 +63,11 @@ pub enum Error {
     // Generic I/O error wrapper
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
+
+    /// Aggregates every error collected by `Validated::into_result`; used
+    /// for any `Invalid` value, including one holding a single failure.
+    #[error("{count} validation errors occurred", count = errors.len())]
+    Multiple { errors: Vec<Error> },
 }
 
 impl Error {
@@ -111,6 +116,11 @@ impl Error {
             reason: reason.into(),
         }
     }
+
+    /// Create an aggregate error from multiple validation failures.
+    pub fn multiple(errors: Vec<Error>) -> Self {
+        Self::Multiple { errors }
+    }
 }
 
 #[cfg(test)]
@@ -237,4 +247,14 @@ mod tests {
         let error_string = error.to_string();
         assert!(error_string.contains("bad record"));
     }
+
+    #[test]
+    fn test_multiple_factory() {
+        let error = Error::multiple(vec![
+            Error::invalid_record("first"),
+            Error::invalid_record("second"),
+        ]);
+        assert!(matches!(error, Error::Multiple { .. }));
+        assert!(error.to_string().contains('2'));
+    }
 }
@@ -47,6 +47,13 @@ pub trait ResultExt<T>: Sized {
     /// Convert a Result to an Option, logging the error if present.
     fn into_option_logged(self) -> Option<T>;
 
+    /// Alias for `into_option_logged`, named to match the `ok()`/`err()`
+    /// pair callers already expect from std's `Result`.
+    fn ok_logged(self) -> Option<T>;
+
+    /// Discard the `Ok` value, keeping only the error (if any).
+    fn err(self) -> Option<Error>;
+
     /// Get the value or a default, logging the error if present.
     fn or_default_logged(self, default: T) -> T;
 
@@ -160,6 +167,25 @@ pub trait ResultExt<T>: Sized {
     fn to_either(self) -> Either<Error, T>
     where
         T: Sized;
+
+    /// True if this is `Ok` and the value matches `predicate`, without
+    /// consuming the `Result`.
+    fn is_ok_and<P: FnOnce(&T) -> bool>(&self, predicate: P) -> bool;
+
+    /// True if this is `Err` and the error matches `predicate`. Lets callers
+    /// branch on error *kind* (e.g. `matches!(e, Error::InvalidRecord { .. })`)
+    /// without consuming the `Result`.
+    fn is_err_and<P: FnOnce(&Error) -> bool>(&self, predicate: P) -> bool;
+
+    /// True if this is `Ok` and the value equals `x`.
+    fn contains<U: PartialEq<T>>(&self, x: &U) -> bool;
+
+    /// Collapse both arms into `U` in one pass: `default` runs on `Err`,
+    /// `f` runs on `Ok`.
+    fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce(Error) -> U,
+        F: FnOnce(T) -> U;
 }
 
 #[async_trait]
@@ -174,6 +200,17 @@ impl<T: std::fmt::Debug + Send> ResultExt<T> for Result<T> {
         }
     }
 
+    fn ok_logged(self) -> Option<T> {
+        self.into_option_logged()
+    }
+
+    fn err(self) -> Option<Error> {
+        match self {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
     fn or_default_logged(self, default: T) -> T {
         match self {
             Ok(value) => value,
@@ -266,6 +303,199 @@ impl<T: std::fmt::Debug + Send> ResultExt<T> for Result<T> {
             Err(e) => Either::Left(e),
         }
     }
+
+    fn is_ok_and<P: FnOnce(&T) -> bool>(&self, predicate: P) -> bool {
+        match self {
+            Ok(v) => predicate(v),
+            Err(_) => false,
+        }
+    }
+
+    fn is_err_and<P: FnOnce(&Error) -> bool>(&self, predicate: P) -> bool {
+        match self {
+            Ok(_) => false,
+            Err(e) => predicate(e),
+        }
+    }
+
+    fn contains<U: PartialEq<T>>(&self, x: &U) -> bool {
+        match self {
+            Ok(v) => x == v,
+            Err(_) => false,
+        }
+    }
+
+    fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce(Error) -> U,
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Ok(v) => f(v),
+            Err(e) => default(e),
+        }
+    }
+}
+
+/// Async counterpart to [`ResultExt`]'s combinators, for futures-returning
+/// closures.
+///
+/// `and_then_async` already lets one async step chain into another; this
+/// trait rounds out the rest of the railway surface (`map`, `map_err`,
+/// `or_else`, `inspect_error`, `tap_ok`/`tap_err`, `unwrap_or_else`) so a
+/// multi-step async pipeline never has to drop back into `match`/`?`. Each
+/// method awaits the closure only for the variant it applies to and threads
+/// the other variant through unchanged, matching the sync methods' semantics.
+#[async_trait]
+pub trait AsyncResultExt<T>: Sized {
+    /// Async version of `map`: transforms the `Ok` value, leaving `Err`
+    /// untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let result = fetch().await.map_async(|v| async move { v * 2 }).await;
+    /// ```
+    async fn map_async<F, U, Fut>(self, f: F) -> Result<U>
+    where
+        F: FnOnce(T) -> Fut + Send,
+        Fut: Future<Output = U> + Send,
+        T: Send;
+
+    /// Async version of `map_err`: transforms the `Error`, leaving `Ok`
+    /// untouched.
+    async fn map_err_async<F, Fut>(self, f: F) -> Result<T>
+    where
+        F: FnOnce(Error) -> Fut + Send,
+        Fut: Future<Output = Error> + Send,
+        T: Send;
+
+    /// Async version of `or_else`: tries an alternative async operation if
+    /// this `Result` is `Err`.
+    async fn or_else_async<F, Fut>(self, f: F) -> Result<T>
+    where
+        F: FnOnce(Error) -> Fut + Send,
+        Fut: Future<Output = Result<T>> + Send,
+        T: Send;
+
+    /// Async version of `inspect_error`: awaits a side effect on the error
+    /// without consuming it, returning the original `Result` unchanged.
+    async fn inspect_error_async<F, Fut>(self, f: F) -> Self
+    where
+        F: FnOnce(&Error) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+        T: Send;
+
+    /// Async version of `GenericResultExt::tap_ok`: awaits a side effect on
+    /// the `Ok` value without consuming it.
+    async fn tap_ok_async<F, Fut>(self, f: F) -> Self
+    where
+        F: FnOnce(&T) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+        T: Send;
+
+    /// Async version of `GenericResultExt::tap_err`: awaits a side effect on
+    /// the `Error` without consuming it.
+    async fn tap_err_async<F, Fut>(self, f: F) -> Self
+    where
+        F: FnOnce(&Error) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+        T: Send;
+
+    /// Async version of `unwrap_or_else`: resolves to the `Ok` value or an
+    /// async fallback computed from the error.
+    async fn unwrap_or_else_async<F, Fut>(self, f: F) -> T
+    where
+        F: FnOnce(Error) -> Fut + Send,
+        Fut: Future<Output = T> + Send,
+        T: Send;
+}
+
+#[async_trait]
+impl<T: std::fmt::Debug + Send> AsyncResultExt<T> for Result<T> {
+    async fn map_async<F, U, Fut>(self, f: F) -> Result<U>
+    where
+        F: FnOnce(T) -> Fut + Send,
+        Fut: Future<Output = U> + Send,
+        T: Send,
+    {
+        match self {
+            Ok(v) => Ok(f(v).await),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn map_err_async<F, Fut>(self, f: F) -> Result<T>
+    where
+        F: FnOnce(Error) -> Fut + Send,
+        Fut: Future<Output = Error> + Send,
+        T: Send,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(f(e).await),
+        }
+    }
+
+    async fn or_else_async<F, Fut>(self, f: F) -> Result<T>
+    where
+        F: FnOnce(Error) -> Fut + Send,
+        Fut: Future<Output = Result<T>> + Send,
+        T: Send,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => f(e).await,
+        }
+    }
+
+    async fn inspect_error_async<F, Fut>(self, f: F) -> Self
+    where
+        F: FnOnce(&Error) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+        T: Send,
+    {
+        if let Err(ref e) = self {
+            f(e).await;
+        }
+        self
+    }
+
+    async fn tap_ok_async<F, Fut>(self, f: F) -> Self
+    where
+        F: FnOnce(&T) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+        T: Send,
+    {
+        if let Ok(ref v) = self {
+            f(v).await;
+        }
+        self
+    }
+
+    async fn tap_err_async<F, Fut>(self, f: F) -> Self
+    where
+        F: FnOnce(&Error) -> Fut + Send,
+        Fut: Future<Output = ()> + Send,
+        T: Send,
+    {
+        if let Err(ref e) = self {
+            f(e).await;
+        }
+        self
+    }
+
+    async fn unwrap_or_else_async<F, Fut>(self, f: F) -> T
+    where
+        F: FnOnce(Error) -> Fut + Send,
+        Fut: Future<Output = T> + Send,
+        T: Send,
+    {
+        match self {
+            Ok(v) => v,
+            Err(e) => f(e).await,
+        }
+    }
 }
 
 /// Generic extension trait for any Result type (not just oya_core::Result).
@@ -297,6 +527,22 @@ pub trait GenericResultExt<T, E> {
     ) -> std::result::Result<T, String>
     where
         E: std::fmt::Display;
+
+    /// True if this is `Ok` and the value matches `predicate`.
+    fn is_ok_and<P: FnOnce(&T) -> bool>(&self, predicate: P) -> bool;
+
+    /// True if this is `Err` and the error matches `predicate`.
+    fn is_err_and<P: FnOnce(&E) -> bool>(&self, predicate: P) -> bool;
+
+    /// True if this is `Ok` and the value equals `x`.
+    fn contains<U: PartialEq<T>>(&self, x: &U) -> bool;
+
+    /// Collapse both arms into `U` in one pass: `default` runs on `Err`,
+    /// `f` runs on `Ok`.
+    fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce(E) -> U,
+        F: FnOnce(T) -> U;
 }
 
 impl<T, E> GenericResultExt<T, E> for std::result::Result<T, E> {
@@ -341,6 +587,38 @@ impl<T, E> GenericResultExt<T, E> for std::result::Result<T, E> {
     {
         self.map_err(|e| format!("{}: {}", context(), e))
     }
+
+    fn is_ok_and<P: FnOnce(&T) -> bool>(&self, predicate: P) -> bool {
+        match self {
+            Ok(v) => predicate(v),
+            Err(_) => false,
+        }
+    }
+
+    fn is_err_and<P: FnOnce(&E) -> bool>(&self, predicate: P) -> bool {
+        match self {
+            Ok(_) => false,
+            Err(e) => predicate(e),
+        }
+    }
+
+    fn contains<U: PartialEq<T>>(&self, x: &U) -> bool {
+        match self {
+            Ok(v) => x == v,
+            Err(_) => false,
+        }
+    }
+
+    fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce(E) -> U,
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Ok(v) => f(v),
+            Err(e) => default(e),
+        }
+    }
 }
 
 /// Extension trait for Option types providing Railway-style operations.
@@ -353,6 +631,10 @@ pub trait OptionExt<T> {
 
     /// Tap into None without consuming the Option.
     fn tap_none<F: FnOnce()>(self, f: F) -> Self;
+
+    /// Convert to a `Result`, emitting a `tracing::warn!` event (and
+    /// returning `err`) when this `Option` is `None`.
+    fn ok_or_log(self, err: Error) -> Result<T>;
 }
 
 impl<T> OptionExt<T> for Option<T> {
@@ -373,8 +655,126 @@ impl<T> OptionExt<T> for Option<T> {
         }
         self
     }
+
+    fn ok_or_log(self, err: Error) -> Result<T> {
+        match self {
+            Some(v) => Ok(v),
+            None => {
+                tracing::warn!("Expected Some, got None: {}", err);
+                Err(err)
+            }
+        }
+    }
+}
+
+/// Bridges `Result<Option<T>>` and `Option<Result<T>>`, porting std's
+/// `Result::transpose`/`Option::transpose` as an explicit extension so the
+/// crate's own `Result`/`Option` bridging methods live alongside the rest of
+/// `ResultExt`/`OptionExt`.
+pub trait TransposeExt {
+    type Output;
+
+    fn transpose(self) -> Self::Output;
+}
+
+impl<T> TransposeExt for Result<Option<T>> {
+    type Output = Option<Result<T>>;
+
+    fn transpose(self) -> Self::Output {
+        match self {
+            Ok(Some(v)) => Some(Ok(v)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl<T> TransposeExt for Option<Result<T>> {
+    type Output = Result<Option<T>>;
+
+    fn transpose(self) -> Self::Output {
+        match self {
+            Some(Ok(v)) => Ok(Some(v)),
+            Some(Err(e)) => Err(e),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Ports std's (nightly-only) `Result::flatten` as a stable extension.
+pub trait FlattenExt<T> {
+    fn flatten(self) -> Result<T>;
+}
+
+impl<T> FlattenExt<T> for Result<Result<T>> {
+    fn flatten(self) -> Result<T> {
+        match self {
+            Ok(inner) => inner,
+            Err(e) => Err(e),
+        }
+    }
 }
 
+/// Extension trait for iterators of `Result<T>`, for batch/stream processing
+/// pipelines that want a one-call split between successes and failures
+/// instead of a hand-rolled loop.
+pub trait ResultIterExt<T>: Iterator<Item = Result<T>> + Sized {
+    /// Routes every item into an oks vec or an errs vec by variant,
+    /// iterating the whole sequence exactly once. Unlike std's
+    /// `FromIterator` for `Result`, this never short-circuits.
+    fn partition_results(self) -> (Vec<T>, Vec<Error>) {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for item in self {
+            match item {
+                Ok(v) => oks.push(v),
+                Err(e) => errs.push(e),
+            }
+        }
+        (oks, errs)
+    }
+
+    /// Collects every item, short-circuiting on the first `Err` — matching
+    /// std's `FromIterator` semantics for `Result`.
+    fn collect_first_error(mut self) -> Result<Vec<T>> {
+        let mut values = Vec::new();
+        for item in self.by_ref() {
+            match item {
+                Ok(v) => values.push(v),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(values)
+    }
+
+    /// Returns the first `Ok` encountered, or — if every item failed — the
+    /// last error encountered. An empty iterator is treated as all-failed.
+    fn first_ok(mut self) -> Result<T> {
+        let mut last_err = None;
+        for item in self.by_ref() {
+            match item {
+                Ok(v) => return Ok(v),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| Error::Unknown("empty iterator".to_string())))
+    }
+
+    /// Runs `f` over every `Ok`, logging each `Err` via `tracing::error!`
+    /// (mirroring [`ResultExt::into_option_logged`]) instead of stopping at
+    /// the first failure.
+    fn try_for_each_logged<F: FnMut(T)>(self, mut f: F) {
+        for item in self {
+            match item {
+                Ok(v) => f(v),
+                Err(e) => tracing::error!("Operation failed: {}", e),
+            }
+        }
+    }
+}
+
+impl<T, I: Iterator<Item = Result<T>>> ResultIterExt<T> for I {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -703,4 +1103,329 @@ mod tests {
         let result = opt.ok_or_else_lazy(|| "missing value");
         assert_eq!(result, Err("missing value"));
     }
+
+    // Tests for AsyncResultExt
+    #[tokio::test]
+    async fn test_map_async_ok() {
+        let result: Result<i32> = Ok(21);
+        let mapped = result.map_async(|v| async move { v * 2 }).await;
+        assert_eq!(mapped, Ok(42));
+    }
+
+    #[tokio::test]
+    async fn test_map_async_err() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        let mapped = result.map_async(|v| async move { v * 2 }).await;
+        assert!(mapped.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_map_err_async() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        let mapped = result
+            .map_err_async(|e| async move { Error::InvalidRecord { reason: e.to_string() } })
+            .await;
+        assert!(matches!(mapped, Err(Error::InvalidRecord { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_or_else_async_recovers() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        let recovered = result.or_else_async(|_| async move { Ok(99) }).await;
+        assert_eq!(recovered, Ok(99));
+    }
+
+    #[tokio::test]
+    async fn test_or_else_async_leaves_ok_untouched() {
+        let result: Result<i32> = Ok(42);
+        let recovered = result.or_else_async(|_| async move { Ok(99) }).await;
+        assert_eq!(recovered, Ok(42));
+    }
+
+    #[tokio::test]
+    async fn test_inspect_error_async_runs_only_on_err() {
+        let mut called = false;
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        let _ = result
+            .inspect_error_async(|_| {
+                called = true;
+                async {}
+            })
+            .await;
+        assert!(called);
+    }
+
+    #[tokio::test]
+    async fn test_tap_ok_async_runs_only_on_ok() {
+        let mut observed = 0;
+        let result: Result<i32> = Ok(42);
+        let passthrough = result
+            .tap_ok_async(|v| {
+                observed = *v;
+                async {}
+            })
+            .await;
+        assert_eq!(observed, 42);
+        assert_eq!(passthrough, Ok(42));
+    }
+
+    #[tokio::test]
+    async fn test_tap_err_async_runs_only_on_err() {
+        let mut called = false;
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        let _ = result
+            .tap_err_async(|_| {
+                called = true;
+                async {}
+            })
+            .await;
+        assert!(called);
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_or_else_async() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        let value = result.unwrap_or_else_async(|_| async move { 7 }).await;
+        assert_eq!(value, 7);
+    }
+
+    // Tests for ResultIterExt
+    #[test]
+    fn test_partition_results_splits_oks_and_errs() {
+        let results: Vec<Result<i32>> = vec![
+            Ok(1),
+            Err(Error::Unknown("a".into())),
+            Ok(2),
+            Err(Error::Unknown("b".into())),
+        ];
+        let (oks, errs) = results.into_iter().partition_results();
+        assert_eq!(oks, vec![1, 2]);
+        assert_eq!(errs.len(), 2);
+    }
+
+    #[test]
+    fn test_collect_first_error_short_circuits() {
+        let results: Vec<Result<i32>> =
+            vec![Ok(1), Err(Error::Unknown("first".into())), Ok(2)];
+        let collected = results.into_iter().collect_first_error();
+        assert!(collected.is_err());
+    }
+
+    #[test]
+    fn test_collect_first_error_collects_all_on_success() {
+        let results: Vec<Result<i32>> = vec![Ok(1), Ok(2), Ok(3)];
+        assert_eq!(results.into_iter().collect_first_error(), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_first_ok_returns_first_success() {
+        let results: Vec<Result<i32>> =
+            vec![Err(Error::Unknown("a".into())), Ok(42), Ok(99)];
+        assert_eq!(results.into_iter().first_ok(), Ok(42));
+    }
+
+    #[test]
+    fn test_first_ok_returns_last_error_when_all_fail() {
+        let results: Vec<Result<i32>> = vec![
+            Err(Error::Unknown("first".into())),
+            Err(Error::Unknown("last".into())),
+        ];
+        let err = results.into_iter().first_ok().expect_err("expected error");
+        assert_eq!(err.to_string(), "unknown error: last");
+    }
+
+    #[test]
+    fn test_try_for_each_logged_runs_side_effect_on_ok_only() {
+        let results: Vec<Result<i32>> =
+            vec![Ok(1), Err(Error::Unknown("skip".into())), Ok(2)];
+        let mut seen = Vec::new();
+        results.into_iter().try_for_each_logged(|v| seen.push(v));
+        assert_eq!(seen, vec![1, 2]);
+    }
+
+    // Tests for ok_logged/err
+    #[test]
+    fn test_ok_logged_ok() {
+        let result: Result<i32> = Ok(42);
+        assert_eq!(result.ok_logged(), Some(42));
+    }
+
+    #[test]
+    fn test_ok_logged_err() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        assert_eq!(result.ok_logged(), None);
+    }
+
+    #[test]
+    fn test_err_extracts_error() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        assert!(result.err().is_some());
+    }
+
+    #[test]
+    fn test_err_on_ok_is_none() {
+        let result: Result<i32> = Ok(42);
+        assert!(result.err().is_none());
+    }
+
+    // Tests for TransposeExt/FlattenExt
+    #[test]
+    fn test_transpose_result_of_some() {
+        let result: Result<Option<i32>> = Ok(Some(42));
+        assert_eq!(result.transpose(), Some(Ok(42)));
+    }
+
+    #[test]
+    fn test_transpose_result_of_none() {
+        let result: Result<Option<i32>> = Ok(None);
+        assert_eq!(result.transpose(), None);
+    }
+
+    #[test]
+    fn test_transpose_result_of_err() {
+        let result: Result<Option<i32>> = Err(Error::Unknown("fail".into()));
+        assert!(matches!(result.transpose(), Some(Err(_))));
+    }
+
+    #[test]
+    fn test_transpose_option_of_result() {
+        let opt: Option<Result<i32>> = Some(Ok(42));
+        assert_eq!(opt.transpose(), Ok(Some(42)));
+    }
+
+    #[test]
+    fn test_transpose_option_of_none() {
+        let opt: Option<Result<i32>> = None;
+        assert_eq!(opt.transpose(), Ok(None));
+    }
+
+    #[test]
+    fn test_flatten_nested_ok() {
+        let nested: Result<Result<i32>> = Ok(Ok(42));
+        assert_eq!(nested.flatten(), Ok(42));
+    }
+
+    #[test]
+    fn test_flatten_inner_err() {
+        let nested: Result<Result<i32>> = Ok(Err(Error::Unknown("inner".into())));
+        assert!(nested.flatten().is_err());
+    }
+
+    #[test]
+    fn test_flatten_outer_err() {
+        let nested: Result<Result<i32>> = Err(Error::Unknown("outer".into()));
+        assert!(nested.flatten().is_err());
+    }
+
+    // Tests for OptionExt::ok_or_log
+    #[test]
+    fn test_ok_or_log_some() {
+        let opt = Some(42);
+        assert_eq!(opt.ok_or_log(Error::Unknown("missing".into())), Ok(42));
+    }
+
+    #[test]
+    fn test_ok_or_log_none() {
+        let opt: Option<i32> = None;
+        let result = opt.ok_or_log(Error::Unknown("missing".into()));
+        assert!(result.is_err());
+    }
+
+    // Tests for ResultExt predicate/query combinators. Method-call syntax
+    // would resolve to std's inherent `Result` methods of the same names
+    // (or be ambiguous with `GenericResultExt`), so these call the trait
+    // methods explicitly.
+    #[test]
+    fn test_is_ok_and_true_when_ok_matches() {
+        let result: Result<i32> = Ok(42);
+        assert!(ResultExt::is_ok_and(&result, |v| *v == 42));
+    }
+
+    #[test]
+    fn test_is_ok_and_false_when_ok_does_not_match() {
+        let result: Result<i32> = Ok(42);
+        assert!(!ResultExt::is_ok_and(&result, |v| *v == 0));
+    }
+
+    #[test]
+    fn test_is_ok_and_false_when_err() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        assert!(!ResultExt::is_ok_and(&result, |v| *v == 42));
+    }
+
+    #[test]
+    fn test_is_err_and_true_when_err_matches() {
+        let result: Result<i32> = Err(Error::invalid_record("bad"));
+        assert!(ResultExt::is_err_and(&result, |e| matches!(e, Error::InvalidRecord { .. })));
+    }
+
+    #[test]
+    fn test_is_err_and_false_when_err_does_not_match() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        assert!(!ResultExt::is_err_and(&result, |e| matches!(e, Error::InvalidRecord { .. })));
+    }
+
+    #[test]
+    fn test_is_err_and_false_when_ok() {
+        let result: Result<i32> = Ok(42);
+        assert!(!ResultExt::is_err_and(&result, |_| true));
+    }
+
+    #[test]
+    fn test_contains_true_when_ok_equals() {
+        let result: Result<i32> = Ok(42);
+        assert!(ResultExt::contains(&result, &42));
+    }
+
+    #[test]
+    fn test_contains_false_when_ok_differs() {
+        let result: Result<i32> = Ok(42);
+        assert!(!ResultExt::contains(&result, &0));
+    }
+
+    #[test]
+    fn test_contains_false_when_err() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        assert!(!ResultExt::contains(&result, &42));
+    }
+
+    #[test]
+    fn test_map_or_else_on_ok() {
+        let result: Result<i32> = Ok(21);
+        let mapped = ResultExt::map_or_else(result, |_| 0, |v| v * 2);
+        assert_eq!(mapped, 42);
+    }
+
+    #[test]
+    fn test_map_or_else_on_err() {
+        let result: Result<i32> = Err(Error::Unknown("fail".into()));
+        let mapped = ResultExt::map_or_else(result, |_| -1, |v| v * 2);
+        assert_eq!(mapped, -1);
+    }
+
+    // Tests for GenericResultExt predicate/query combinators, called
+    // explicitly for the same reason as above.
+    #[test]
+    fn test_generic_is_ok_and() {
+        let result: std::result::Result<i32, String> = Ok(42);
+        assert!(GenericResultExt::is_ok_and(&result, |v| *v == 42));
+    }
+
+    #[test]
+    fn test_generic_is_err_and() {
+        let result: std::result::Result<i32, String> = Err("bad".to_string());
+        assert!(GenericResultExt::is_err_and(&result, |e| e == "bad"));
+    }
+
+    #[test]
+    fn test_generic_contains() {
+        let result: std::result::Result<i32, String> = Ok(42);
+        assert!(GenericResultExt::contains(&result, &42));
+    }
+
+    #[test]
+    fn test_generic_map_or_else() {
+        let result: std::result::Result<i32, String> = Ok(21);
+        assert_eq!(GenericResultExt::map_or_else(result, |_| 0, |v| v * 2), 42);
+    }
 }
@@ -0,0 +1,228 @@
+//! Applicative `Validated` type for error-accumulating validation.
+//!
+//! `ResultExt`'s combinators (and std's `FromIterator` for `Result`) all
+//! short-circuit on the first `Err`. Form validation, config parsing, and
+//! batch record checks usually want every error reported at once instead,
+//! which is what `Validated` provides.
+
+use crate::error::Error;
+use crate::result::Result;
+
+/// An applicative validation result: `Valid` holds a value, `Invalid` holds
+/// every error accumulated so far.
+///
+/// Invariant: a `Validated` is `Valid` only when zero errors have been
+/// accumulated. Combining two `Invalid` values (via [`Validated::zip`] or
+/// [`Validated::and`]) concatenates both error vectors, preserving order,
+/// rather than discarding the second.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Validated<T> {
+    Valid(T),
+    Invalid(Vec<Error>),
+}
+
+impl<T> Validated<T> {
+    /// Transforms the value, leaving accumulated errors untouched.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Validated<U> {
+        match self {
+            Self::Valid(v) => Validated::Valid(f(v)),
+            Self::Invalid(errs) => Validated::Invalid(errs),
+        }
+    }
+
+    /// Chains into another `Validated`-producing step. This still
+    /// short-circuits on an `Invalid` input (there is no value to hand `f`);
+    /// use [`Validated::zip`] or [`Validated::and`] to accumulate errors
+    /// across independent checks instead.
+    pub fn and_then<U, F: FnOnce(T) -> Validated<U>>(self, f: F) -> Validated<U> {
+        match self {
+            Self::Valid(v) => f(v),
+            Self::Invalid(errs) => Validated::Invalid(errs),
+        }
+    }
+
+    /// Applicative combination: pairs this value with `other`'s if both are
+    /// `Valid`, or concatenates both error vectors (preserving order) if
+    /// either is `Invalid` — any invalid input poisons the result.
+    pub fn zip<U>(self, other: Validated<U>) -> Validated<(T, U)> {
+        match (self, other) {
+            (Self::Valid(a), Validated::Valid(b)) => Validated::Valid((a, b)),
+            (Self::Valid(_), Validated::Invalid(errs)) => Validated::Invalid(errs),
+            (Self::Invalid(errs), Validated::Valid(_)) => Validated::Invalid(errs),
+            (Self::Invalid(mut errs), Validated::Invalid(more)) => {
+                errs.extend(more);
+                Validated::Invalid(errs)
+            }
+        }
+    }
+
+    /// Like [`Validated::zip`], but keeps this side's value and discards
+    /// `other`'s, while still accumulating `other`'s errors.
+    pub fn and<U>(self, other: Validated<U>) -> Validated<T> {
+        self.zip(other).map(|(kept, _)| kept)
+    }
+
+    /// True when no errors have been accumulated.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid(_))
+    }
+
+    /// Collapses into a [`Result`]: `Valid(v)` becomes `Ok(v)`, `Invalid(errs)`
+    /// becomes `Err` wrapping every accumulated error in [`Error::Multiple`].
+    pub fn into_result(self) -> Result<T> {
+        match self {
+            Self::Valid(v) => Ok(v),
+            Self::Invalid(errs) => Err(Error::multiple(errs)),
+        }
+    }
+}
+
+/// Converts a [`Result`] into a single-or-zero-error [`Validated`].
+pub trait ResultValidatedExt<T> {
+    fn into_validated(self) -> Validated<T>;
+}
+
+impl<T> ResultValidatedExt<T> for Result<T> {
+    fn into_validated(self) -> Validated<T> {
+        match self {
+            Ok(v) => Validated::Valid(v),
+            Err(e) => Validated::Invalid(vec![e]),
+        }
+    }
+}
+
+/// Runs every item of an iterator of `Result<T>`, routing each `Ok` into a
+/// success vec and each `Err` into an error vec, never short-circuiting
+/// (unlike std's `FromIterator` for `Result`). Returns `Err` with every
+/// collected error if any occurred, `Ok` with every value otherwise.
+pub fn validate_all<T, I: IntoIterator<Item = Result<T>>>(
+    iter: I,
+) -> std::result::Result<Vec<T>, Vec<Error>> {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for item in iter {
+        match item {
+            Ok(v) => values.push(v),
+            Err(e) => errors.push(e),
+        }
+    }
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_transforms_valid() {
+        let validated: Validated<i32> = Validated::Valid(21);
+        assert_eq!(validated.map(|v| v * 2), Validated::Valid(42));
+    }
+
+    #[test]
+    fn test_map_leaves_invalid_untouched() {
+        let validated: Validated<i32> = Validated::Invalid(vec![Error::invalid_record("bad")]);
+        let mapped = validated.map(|v| v * 2);
+        assert!(!mapped.is_valid());
+    }
+
+    #[test]
+    fn test_and_then_chains_valid() {
+        let validated: Validated<i32> = Validated::Valid(21);
+        let chained = validated.and_then(|v| Validated::Valid(v * 2));
+        assert_eq!(chained, Validated::Valid(42));
+    }
+
+    #[test]
+    fn test_and_then_short_circuits_invalid() {
+        let validated: Validated<i32> = Validated::Invalid(vec![Error::invalid_record("bad")]);
+        let chained = validated.and_then(|v| Validated::Valid(v * 2));
+        assert!(!chained.is_valid());
+    }
+
+    #[test]
+    fn test_zip_combines_two_valid() {
+        let a: Validated<i32> = Validated::Valid(1);
+        let b: Validated<&str> = Validated::Valid("two");
+        assert_eq!(a.zip(b), Validated::Valid((1, "two")));
+    }
+
+    #[test]
+    fn test_zip_concatenates_errors_from_both_invalid() {
+        let a: Validated<i32> = Validated::Invalid(vec![Error::invalid_record("a")]);
+        let b: Validated<i32> = Validated::Invalid(vec![Error::invalid_record("b")]);
+        match a.zip(b) {
+            Validated::Invalid(errs) => assert_eq!(errs.len(), 2),
+            Validated::Valid(_) => panic!("expected Invalid"),
+        }
+    }
+
+    #[test]
+    fn test_zip_keeps_single_side_errors_when_only_one_invalid() {
+        let a: Validated<i32> = Validated::Valid(1);
+        let b: Validated<i32> = Validated::Invalid(vec![Error::invalid_record("b")]);
+        match a.zip(b) {
+            Validated::Invalid(errs) => assert_eq!(errs.len(), 1),
+            Validated::Valid(_) => panic!("expected Invalid"),
+        }
+    }
+
+    #[test]
+    fn test_and_keeps_left_value_but_accumulates_right_errors() {
+        let a: Validated<i32> = Validated::Valid(1);
+        let b: Validated<&str> = Validated::Invalid(vec![Error::invalid_record("b")]);
+        assert!(!a.and(b).is_valid());
+    }
+
+    #[test]
+    fn test_into_result_valid_is_ok() {
+        let validated: Validated<i32> = Validated::Valid(42);
+        assert_eq!(validated.into_result().expect("ok"), 42);
+    }
+
+    #[test]
+    fn test_into_result_invalid_is_aggregate_error() {
+        let validated: Validated<i32> =
+            Validated::Invalid(vec![Error::invalid_record("a"), Error::invalid_record("b")]);
+        let result = validated.into_result();
+        assert!(matches!(result, Err(Error::Multiple { .. })));
+    }
+
+    #[test]
+    fn test_result_into_validated_ok() {
+        let result: Result<i32> = Ok(42);
+        assert_eq!(result.into_validated(), Validated::Valid(42));
+    }
+
+    #[test]
+    fn test_result_into_validated_err() {
+        let result: Result<i32> = Err(Error::invalid_record("bad"));
+        match result.into_validated() {
+            Validated::Invalid(errs) => assert_eq!(errs.len(), 1),
+            Validated::Valid(_) => panic!("expected Invalid"),
+        }
+    }
+
+    #[test]
+    fn test_validate_all_collects_every_value_when_no_errors() {
+        let results: Vec<Result<i32>> = vec![Ok(1), Ok(2), Ok(3)];
+        assert_eq!(validate_all(results), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_validate_all_collects_every_error_without_short_circuiting() {
+        let results: Vec<Result<i32>> = vec![
+            Ok(1),
+            Err(Error::invalid_record("bad-a")),
+            Ok(2),
+            Err(Error::invalid_record("bad-b")),
+        ];
+        let errors = validate_all(results).expect_err("expected errors");
+        assert_eq!(errors.len(), 2);
+    }
+}
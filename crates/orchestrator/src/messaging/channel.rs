@@ -0,0 +1,1642 @@
+//! Durable message channels with at-least-once and exactly-once delivery.
+//!
+//! A channel holds messages in a time-ordered `queue` until a consumer calls
+//! [`DurableChannel::receive`]. Messages are ordered by `visible_at` (with
+//! `queued_at` as a FIFO tiebreak) rather than plain insertion order, so a
+//! message scheduled for the future via [`DurableChannel::send_after`] or
+//! [`DurableChannel::send_at`] doesn't jump the queue ahead of messages that
+//! are already receivable. For `AtLeastOnce`/`ExactlyOnce` delivery, a
+//! received message isn't dropped from the channel — it's moved into an
+//! `in_flight` lease table keyed by [`MessageId`], stamped with a
+//! `visible_after` deadline. If the consumer crashes before calling
+//! [`DurableChannel::ack`], the lease expires and the next `receive` call
+//! reaps it back onto the queue for redelivery. A message is never
+//! simultaneously in `queue` and `in_flight`.
+//!
+//! Consumers that don't want to busy-poll an empty queue can use
+//! [`DurableChannel::recv`]/[`DurableChannel::recv_timeout`] (or the
+//! [`ChannelReceiver`] `Stream` adapter), which await a `Notify` signaled on
+//! `send` instead. Symmetrically, a bounded channel (`max_queue_depth`) can
+//! make `send` await space rather than fail outright via `block_on_full`.
+//!
+//! A channel created with [`DurableChannel::with_store`]/
+//! [`DurableChannel::recover`] also persists each queued message to the
+//! `channel_message` table (encoded through `config.codec`, see
+//! [`super::codec`]), so [`DurableChannel::rehydrate`] can repopulate
+//! `queue` after a restart instead of silently losing it. Delivered
+//! messages are marked rather than deleted immediately;
+//! [`DurableChannel::compact`] purges them periodically.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Notify, RwLock};
+
+use super::codec::{MessageCodec, NoneCodec, ZstdCodec};
+use super::delivery::{DeliveryMode, DeliveryTracker};
+use super::types::{ChannelId, Message, MessageId, MessageMetadata};
+use crate::persistence::{
+    ChannelMessageRecord, OrchestratorStore, PersistenceError, PersistenceResult,
+};
+
+/// Token-bucket rate limit applied to `send`/`send_after`/`send_at`.
+#[derive(Debug, Clone, Copy)]
+pub struct SendRateLimit {
+    /// Steady-state number of messages the bucket refills per second.
+    pub messages_per_sec: f64,
+    /// Bucket capacity — the largest burst of sends allowed back-to-back.
+    pub burst: u32,
+}
+
+/// Configuration for a durable channel.
+#[derive(Clone)]
+pub struct ChannelConfig {
+    /// Delivery guarantee for messages sent on this channel.
+    pub delivery_mode: DeliveryMode,
+    /// How long a received message stays leased before it's reaped back
+    /// onto the queue for redelivery. Only meaningful for
+    /// `AtLeastOnce`/`ExactlyOnce` delivery.
+    pub visibility_timeout_secs: u64,
+    /// Retry limit for `nack`: each `nack` increments a message's
+    /// `delivery_count`, and once that count exceeds this value the message
+    /// is parked in the dead-letter store instead of being retried again.
+    pub max_delivery_attempts: u32,
+    /// Channel a dead-lettered message conceptually belongs to. Accepted for
+    /// configuration symmetry with a future channel registry; today
+    /// dead-lettered messages are always parked in this channel's own
+    /// `dead_letters` store (see [`DurableChannel::nack`]) since a channel
+    /// doesn't hold a handle to other channels — route them on with
+    /// [`DurableChannel::drain_dead_letter`] and `MessageRouter` instead.
+    pub dead_letter_channel: Option<ChannelId>,
+    /// Base delay for the exponential backoff applied between `nack`
+    /// retries.
+    pub retry_base_backoff_ms: u64,
+    /// Upper bound the exponential backoff delay is clamped to. Jitter (if
+    /// enabled) is added after clamping, so the final delay can exceed this
+    /// by up to half.
+    pub retry_max_backoff_ms: u64,
+    /// Whether to add random jitter to the computed backoff delay.
+    pub retry_jitter: bool,
+    /// Maximum number of messages allowed in the queue at once. `None`
+    /// (the default) means unbounded.
+    pub max_queue_depth: Option<usize>,
+    /// When the queue is at `max_queue_depth` (or `max_total_bytes`),
+    /// `send`/`send_after`/`send_at` await space instead of immediately
+    /// failing with `PersistenceError::InvalidState`; when `send_rate`'s
+    /// bucket is empty, they await the next token instead of failing with
+    /// `PersistenceError::RateLimited`. Has no effect when none of the three
+    /// limits is configured.
+    pub block_on_full: bool,
+    /// Codec applied to a message's encoded bytes before they would be
+    /// persisted, and on load (see [`super::codec`]). Defaults to
+    /// [`NoneCodec`], a passthrough. A store-backed channel (see
+    /// [`DurableChannel::with_store`]) encodes every persisted message
+    /// through it; [`DurableChannel::encode_message`] and
+    /// [`DurableChannel::decode_message`] expose the same encode/decode
+    /// boundary for callers that persist message bytes themselves.
+    pub codec: Arc<dyn MessageCodec>,
+    /// Token-bucket limit on how fast `send`/`send_after`/`send_at` may
+    /// enqueue messages. `None` (the default) means unlimited. When the
+    /// bucket is empty, `block_on_full` decides whether a send awaits the
+    /// next token or is rejected immediately with
+    /// `PersistenceError::RateLimited`.
+    pub send_rate: Option<SendRateLimit>,
+    /// Quota on the summed serialized size (bytes) of messages currently in
+    /// `queue`, independent of `max_queue_depth`'s count limit — guards
+    /// against a few huge payloads exhausting memory. `None` (the default)
+    /// means unbounded. Governed by `block_on_full` the same way as
+    /// `max_queue_depth`.
+    pub max_total_bytes: Option<usize>,
+}
+
+impl std::fmt::Debug for ChannelConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChannelConfig")
+            .field("delivery_mode", &self.delivery_mode)
+            .field("visibility_timeout_secs", &self.visibility_timeout_secs)
+            .field("max_delivery_attempts", &self.max_delivery_attempts)
+            .field("dead_letter_channel", &self.dead_letter_channel)
+            .field("retry_base_backoff_ms", &self.retry_base_backoff_ms)
+            .field("retry_max_backoff_ms", &self.retry_max_backoff_ms)
+            .field("retry_jitter", &self.retry_jitter)
+            .field("max_queue_depth", &self.max_queue_depth)
+            .field("block_on_full", &self.block_on_full)
+            .field("codec", &self.codec.tag())
+            .field("send_rate", &self.send_rate)
+            .field("max_total_bytes", &self.max_total_bytes)
+            .finish()
+    }
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self {
+            delivery_mode: DeliveryMode::default(),
+            visibility_timeout_secs: 30,
+            max_delivery_attempts: 5,
+            dead_letter_channel: None,
+            retry_base_backoff_ms: 1_000,
+            retry_max_backoff_ms: 300_000,
+            retry_jitter: true,
+            max_queue_depth: None,
+            block_on_full: false,
+            codec: Arc::new(NoneCodec),
+            send_rate: None,
+            max_total_bytes: None,
+        }
+    }
+}
+
+impl ChannelConfig {
+    /// Exponential backoff delay for the given (one-based) delivery attempt,
+    /// clamped to `retry_max_backoff_ms` with optional jitter.
+    fn retry_backoff(&self, delivery_count: u32) -> ChronoDuration {
+        let exponential_ms = self
+            .retry_base_backoff_ms
+            .saturating_mul(2_u64.saturating_pow(delivery_count.saturating_sub(1)));
+        let capped_ms = exponential_ms.min(self.retry_max_backoff_ms);
+
+        let delay_ms = if self.retry_jitter {
+            let jitter_range = (capped_ms / 4).max(1);
+            capped_ms.saturating_add(rand::random::<u64>() % (2 * jitter_range))
+        } else {
+            capped_ms
+        };
+
+        ChronoDuration::milliseconds(i64::try_from(delay_ms).unwrap_or(i64::MAX))
+    }
+}
+
+/// Token bucket backing `ChannelConfig::send_rate`. Refills lazily, based on
+/// wall-clock time elapsed since the last refill, rather than via a
+/// background task.
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: DateTime<Utc>,
+}
+
+impl TokenBucket {
+    fn new(burst: u32) -> Self {
+        Self {
+            tokens: f64::from(burst),
+            last_refill: Utc::now(),
+        }
+    }
+
+    /// Add tokens for elapsed time, capped at `burst`.
+    fn refill(&mut self, limit: SendRateLimit) {
+        let now = Utc::now();
+        let elapsed_secs = (now - self.last_refill).num_milliseconds().max(0) as f64 / 1000.0;
+        self.tokens = (self.tokens + elapsed_secs * limit.messages_per_sec).min(f64::from(limit.burst));
+        self.last_refill = now;
+    }
+}
+
+/// A message waiting in a channel's queue, not yet delivered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedMessage {
+    /// The underlying message.
+    pub message: Message,
+    /// Number of times this message has been handed out via `receive`.
+    pub delivery_count: u32,
+    /// When this message was enqueued. Used as a FIFO tiebreak between
+    /// messages that share a `visible_at`. Queued messages have no TTL and
+    /// never expire by age.
+    pub queued_at: DateTime<Utc>,
+    /// When this message becomes eligible for `receive`. Equal to
+    /// `queued_at` for ordinary sends; in the future for messages scheduled
+    /// via `send_after`/`send_at`.
+    pub visible_at: DateTime<Utc>,
+}
+
+/// Orders queued messages so the earliest-visible one sorts first out of a
+/// `BinaryHeap` (a max-heap), with `queued_at` as a FIFO tiebreak for
+/// messages that become visible at the same instant.
+#[derive(Debug, Clone)]
+struct ScheduledMessage(QueuedMessage);
+
+impl PartialEq for ScheduledMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.visible_at == other.0.visible_at && self.0.queued_at == other.0.queued_at
+    }
+}
+
+impl Eq for ScheduledMessage {}
+
+impl PartialOrd for ScheduledMessage {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScheduledMessage {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so the max-heap pops the earliest `visible_at` (then the
+        // earliest `queued_at`) first.
+        other
+            .0
+            .visible_at
+            .cmp(&self.0.visible_at)
+            .then_with(|| other.0.queued_at.cmp(&self.0.queued_at))
+    }
+}
+
+/// A message currently leased to a consumer, pending `ack`.
+#[derive(Debug, Clone)]
+struct InFlightMessage {
+    queued: QueuedMessage,
+    visible_after: DateTime<Utc>,
+}
+
+/// A message parked in the dead-letter store after `nack` was called on it
+/// more times than `max_delivery_attempts` allows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetteredMessage {
+    /// The original message, unmodified.
+    pub message: Message,
+    /// Delivery attempt count and the reason the final attempt failed.
+    pub metadata: MessageMetadata,
+}
+
+/// Snapshot of a channel's `max_total_bytes` quota usage, returned by
+/// [`DurableChannel::quota_usage`].
+#[derive(Debug, Clone, Copy)]
+pub struct QuotaUsage {
+    /// Summed serialized size, in bytes, of messages currently in `queue`.
+    pub bytes_used: usize,
+    /// Remaining capacity before `max_total_bytes` is hit, or `None` if the
+    /// channel has no byte quota configured.
+    pub bytes_remaining: Option<usize>,
+}
+
+/// A durable message channel, optionally backed by persistent storage.
+pub struct DurableChannel {
+    id: ChannelId,
+    config: ChannelConfig,
+    delivery_tracker: Option<Arc<DeliveryTracker>>,
+    store: Option<OrchestratorStore>,
+    queue: RwLock<BinaryHeap<ScheduledMessage>>,
+    in_flight: RwLock<HashMap<String, InFlightMessage>>,
+    dead_letters: RwLock<VecDeque<DeadLetteredMessage>>,
+    /// Token bucket backing `config.send_rate`; absent when unconfigured.
+    rate_bucket: Option<RwLock<TokenBucket>>,
+    /// Summed serialized size (bytes) of messages currently in `queue`,
+    /// tracked against `config.max_total_bytes`.
+    total_bytes: RwLock<usize>,
+    /// Notified whenever a message is pushed onto `queue`, so `recv`
+    /// doesn't need to busy-poll an empty channel.
+    notify_not_empty: Notify,
+    /// Notified whenever a message leaves `queue` by being received, so a
+    /// `send` blocked on `max_queue_depth` or
+    /// `max_total_bytes` wakes up to recheck space.
+    notify_not_full: Notify,
+}
+
+impl DurableChannel {
+    /// Create a new in-memory channel.
+    #[must_use]
+    pub fn new(id: impl Into<ChannelId>, config: ChannelConfig) -> Self {
+        let rate_bucket = config.send_rate.map(|limit| RwLock::new(TokenBucket::new(limit.burst)));
+        Self {
+            id: id.into(),
+            config,
+            delivery_tracker: None,
+            store: None,
+            queue: RwLock::new(BinaryHeap::new()),
+            in_flight: RwLock::new(HashMap::new()),
+            dead_letters: RwLock::new(VecDeque::new()),
+            rate_bucket,
+            total_bytes: RwLock::new(0),
+            notify_not_empty: Notify::new(),
+            notify_not_full: Notify::new(),
+        }
+    }
+
+    /// Create a channel backed by persistent storage and delivery tracking.
+    ///
+    /// Queued and in-flight messages are persisted to the `channel_message`
+    /// table as they're sent/nacked and marked delivered once acked (and
+    /// later purged by [`Self::compact`]), so
+    /// [`Self::recover`]/[`Self::rehydrate`] can repopulate `queue` after a
+    /// restart. A leased message whose in-memory lease simply expires
+    /// (without the process restarting) is redelivered the usual way via
+    /// [`Self::reap_expired`] and doesn't touch the persisted row.
+    #[must_use]
+    pub fn with_store(
+        id: impl Into<ChannelId>,
+        config: ChannelConfig,
+        store: OrchestratorStore,
+        delivery_tracker: Arc<DeliveryTracker>,
+    ) -> Self {
+        let rate_bucket = config.send_rate.map(|limit| RwLock::new(TokenBucket::new(limit.burst)));
+        Self {
+            id: id.into(),
+            config,
+            delivery_tracker: Some(delivery_tracker),
+            store: Some(store),
+            queue: RwLock::new(BinaryHeap::new()),
+            in_flight: RwLock::new(HashMap::new()),
+            dead_letters: RwLock::new(VecDeque::new()),
+            rate_bucket,
+            total_bytes: RwLock::new(0),
+            notify_not_empty: Notify::new(),
+            notify_not_full: Notify::new(),
+        }
+    }
+
+    /// Create a store-backed channel and immediately [`Self::rehydrate`] its
+    /// queue from persisted `channel_message` rows, so a process restart
+    /// doesn't silently drop messages that were queued before the crash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if rehydration fails to query or decode a row.
+    pub async fn recover(
+        id: impl Into<ChannelId>,
+        config: ChannelConfig,
+        store: OrchestratorStore,
+        delivery_tracker: Arc<DeliveryTracker>,
+    ) -> PersistenceResult<Self> {
+        let channel = Self::with_store(id, config, store, delivery_tracker);
+        channel.rehydrate().await?;
+        Ok(channel)
+    }
+
+    /// This channel's ID.
+    #[must_use]
+    pub fn id(&self) -> &ChannelId {
+        &self.id
+    }
+
+    /// Repopulate `queue` from persisted, not-yet-delivered `channel_message`
+    /// rows. A no-op if this channel has no store.
+    ///
+    /// Rows whose `delivery_count` already exceeds `max_delivery_attempts`
+    /// are purged instead of requeued — they should have been dead-lettered
+    /// before the crash, and redelivering them would exceed the limit
+    /// immediately on the next `nack`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or a row's `message_data` can't
+    /// be decoded with its recorded codec tag.
+    pub async fn rehydrate(&self) -> PersistenceResult<()> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+
+        let records = store.list_channel_messages(self.id.as_str()).await?;
+        let mut survivors = Vec::with_capacity(records.len());
+
+        for record in records {
+            if record.delivery_count > self.config.max_delivery_attempts {
+                store.delete_channel_message(&record.message_id).await?;
+                continue;
+            }
+
+            let message = self.decode_message(&record.codec, &record.message_data)?;
+            survivors.push(ScheduledMessage(QueuedMessage {
+                message,
+                delivery_count: record.delivery_count,
+                queued_at: record.queued_at,
+                visible_at: record.visible_at,
+            }));
+        }
+
+        if survivors.is_empty() {
+            return Ok(());
+        }
+
+        let mut queue = self.queue.write().await;
+        queue.extend(survivors);
+        drop(queue);
+
+        self.notify_not_empty.notify_waiters();
+        Ok(())
+    }
+
+    /// Purge persisted `channel_message` rows already marked delivered, so
+    /// storage doesn't grow unbounded. Returns the number of rows removed,
+    /// or `0` for a channel without a store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying query fails.
+    pub async fn compact(&self) -> PersistenceResult<usize> {
+        let Some(store) = &self.store else {
+            return Ok(0);
+        };
+        store.compact_channel_messages(self.id.as_str()).await
+    }
+
+    /// Persist a queued message's current state, upserting by message ID.
+    async fn persist_queued(
+        &self,
+        store: &OrchestratorStore,
+        queued: &QueuedMessage,
+    ) -> PersistenceResult<()> {
+        let (codec, message_data) = self.encode_message(&queued.message)?;
+        let record = ChannelMessageRecord::new(
+            self.id.as_str(),
+            queued.message.id().as_str(),
+            codec,
+            message_data,
+            queued.delivery_count,
+            queued.queued_at,
+            queued.visible_at,
+        );
+        store.save_channel_message(&record).await?;
+        Ok(())
+    }
+
+    /// Encode a message through `config.codec`, returning the codec's tag
+    /// alongside the encoded bytes so the load path can dispatch on it.
+    ///
+    /// A store-backed channel calls this before writing each
+    /// `channel_message` row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if JSON serialization or the codec's `encode` fails.
+    pub fn encode_message(&self, message: &Message) -> PersistenceResult<(String, Vec<u8>)> {
+        let json = serde_json::to_vec(message)
+            .map_err(|e| PersistenceError::serialization_error(format!("{e}")))?;
+        let encoded = self.config.codec.encode(&json)?;
+        Ok((self.config.codec.tag().to_string(), encoded))
+    }
+
+    /// Decode bytes previously produced by [`Self::encode_message`], using
+    /// `codec_tag` to select the codec rather than assuming `config.codec`
+    /// — so messages written under an earlier codec stay readable after
+    /// `config.codec` changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `codec_tag` names neither `config.codec` nor one
+    /// of the keyless built-in codecs (`none`, `zstd`) — an encrypted row is
+    /// readable only while its codec is `config.codec`, since the key isn't
+    /// stored — or if the codec's `decode` or JSON deserialization fails.
+    pub fn decode_message(&self, codec_tag: &str, data: &[u8]) -> PersistenceResult<Message> {
+        let json = if codec_tag == self.config.codec.tag() {
+            self.config.codec.decode(data)?
+        } else {
+            match codec_tag {
+                "none" => NoneCodec.decode(data)?,
+                "zstd" => ZstdCodec::default().decode(data)?,
+                _ => {
+                    return Err(PersistenceError::invalid_state(format!(
+                        "no codec registered for tag '{codec_tag}'"
+                    )));
+                }
+            }
+        };
+        serde_json::from_slice(&json).map_err(|e| PersistenceError::serialization_error(format!("{e}")))
+    }
+
+    /// Send a message, making it immediately receivable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if delivery tracking fails to persist.
+    pub async fn send(&self, message: Message) -> PersistenceResult<MessageId> {
+        let now = Utc::now();
+        self.enqueue(message, now, self.config.delivery_mode, None)
+            .await
+    }
+
+    /// Send a message that only becomes receivable after `delay` has
+    /// elapsed, for retry scheduling and deferred workflow signaling.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if delivery tracking fails to persist.
+    pub async fn send_after(
+        &self,
+        message: Message,
+        delay: ChronoDuration,
+        mode: DeliveryMode,
+    ) -> PersistenceResult<MessageId> {
+        self.send_at(message, Utc::now() + delay, mode).await
+    }
+
+    /// Send a message that only becomes receivable at or after `when`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if delivery tracking fails to persist.
+    pub async fn send_at(
+        &self,
+        message: Message,
+        when: DateTime<Utc>,
+        mode: DeliveryMode,
+    ) -> PersistenceResult<MessageId> {
+        self.enqueue(message, when, mode, None).await
+    }
+
+    /// Shared enqueue path for `send`/`send_after`/`send_at`: tracks
+    /// delivery, reserves queue space if `max_queue_depth`/`max_total_bytes`
+    /// is reached and `block_on_full` is set, then pushes onto the
+    /// time-ordered queue with `queued_at` stamped to now and `visible_at`
+    /// set to `visible_at`.
+    async fn enqueue(
+        &self,
+        message: Message,
+        visible_at: DateTime<Utc>,
+        mode: DeliveryMode,
+        idempotency_key: Option<&str>,
+    ) -> PersistenceResult<MessageId> {
+        let id = message.id().clone();
+        let size = Self::message_size(&message)?;
+
+        self.acquire_send_token().await?;
+
+        if let Some(tracker) = &self.delivery_tracker {
+            tracker.track(id.clone(), mode, idempotency_key).await?;
+        }
+
+        let queued = QueuedMessage {
+            message,
+            delivery_count: 0,
+            queued_at: Utc::now(),
+            visible_at,
+        };
+
+        self.reserve_and_push(size, &queued).await?;
+
+        if let Some(store) = &self.store {
+            self.persist_queued(store, &queued).await?;
+        }
+
+        self.notify_not_empty.notify_one();
+
+        Ok(id)
+    }
+
+    /// Serialized size of a message, in bytes, as counted against
+    /// `config.max_total_bytes`.
+    fn message_size(message: &Message) -> PersistenceResult<usize> {
+        serde_json::to_vec(message)
+            .map(|bytes| bytes.len())
+            .map_err(|e| PersistenceError::serialization_error(format!("{e}")))
+    }
+
+    /// Block (if `block_on_full`) until the queue has room for another
+    /// `size`-byte message under both `max_queue_depth` and
+    /// `max_total_bytes`, then atomically reserve that capacity and push
+    /// `queued` onto the queue, or return `PersistenceError::InvalidState`
+    /// immediately if it's full and blocking isn't enabled.
+    ///
+    /// The capacity check and the reservation (incrementing `total_bytes`
+    /// and pushing onto `queue`) happen under the same held write-lock
+    /// pair, so concurrent senders can't all observe spare capacity and
+    /// all push past the configured limits. The lock is released before
+    /// the caller's optional persistence I/O.
+    async fn reserve_and_push(&self, size: usize, queued: &QueuedMessage) -> PersistenceResult<()> {
+        loop {
+            let notified = self.notify_not_full.notified();
+
+            {
+                let mut queue = self.queue.write().await;
+                let mut total_bytes = self.total_bytes.write().await;
+
+                let depth_ok = self
+                    .config
+                    .max_queue_depth
+                    .is_none_or(|max| queue.len() < max);
+                let bytes_ok = self
+                    .config
+                    .max_total_bytes
+                    .is_none_or(|max| *total_bytes + size <= max);
+
+                if depth_ok && bytes_ok {
+                    *total_bytes += size;
+                    queue.push(ScheduledMessage(queued.clone()));
+                    return Ok(());
+                }
+
+                if !self.config.block_on_full {
+                    return Err(PersistenceError::invalid_state(format!(
+                        "channel '{}' is full (max_queue_depth/max_total_bytes reached)",
+                        self.id
+                    )));
+                }
+            }
+
+            notified.await;
+        }
+    }
+
+    /// Block (if `block_on_full`) until `config.send_rate`'s token bucket
+    /// has a token available, or return `PersistenceError::rate_limited`
+    /// immediately if it's empty and blocking isn't enabled. A no-op when
+    /// no rate limit is configured.
+    async fn acquire_send_token(&self) -> PersistenceResult<()> {
+        let (Some(limit), Some(bucket)) = (self.config.send_rate, &self.rate_bucket) else {
+            return Ok(());
+        };
+
+        loop {
+            let wait_ms = {
+                let mut bucket = bucket.write().await;
+                bucket.refill(limit);
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return Ok(());
+                }
+
+                let deficit = 1.0 - bucket.tokens;
+                ((deficit / limit.messages_per_sec) * 1000.0).ceil().max(1.0) as u64
+            };
+
+            if !self.config.block_on_full {
+                return Err(PersistenceError::rate_limited(format!(
+                    "channel '{}' exceeded send_rate of {} messages/sec",
+                    self.id, limit.messages_per_sec
+                )));
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(wait_ms)).await;
+        }
+    }
+
+    /// Current `max_total_bytes` quota usage for this channel.
+    pub async fn quota_usage(&self) -> QuotaUsage {
+        let bytes_used = *self.total_bytes.read().await;
+        let bytes_remaining = self
+            .config
+            .max_total_bytes
+            .map(|max| max.saturating_sub(bytes_used));
+        QuotaUsage {
+            bytes_used,
+            bytes_remaining,
+        }
+    }
+
+    /// Move any in-flight message whose lease has expired back onto the
+    /// queue for redelivery, incrementing its `delivery_count`.
+    async fn reap_expired(&self) {
+        let now = Utc::now();
+
+        let expired_keys: Vec<String> = {
+            let in_flight = self.in_flight.read().await;
+            in_flight
+                .iter()
+                .filter(|(_, entry)| entry.visible_after <= now)
+                .map(|(key, _)| key.clone())
+                .collect()
+        };
+
+        if expired_keys.is_empty() {
+            return;
+        }
+
+        let mut in_flight = self.in_flight.write().await;
+        let mut queue = self.queue.write().await;
+        let mut reclaimed_bytes = 0usize;
+
+        for key in expired_keys {
+            if let Some(mut entry) = in_flight.remove(&key) {
+                entry.queued.delivery_count += 1;
+                entry.queued.visible_at = now;
+                reclaimed_bytes += Self::message_size(&entry.queued.message).unwrap_or(0);
+                queue.push(ScheduledMessage(entry.queued));
+            }
+        }
+
+        drop(queue);
+        drop(in_flight);
+
+        if reclaimed_bytes > 0 {
+            *self.total_bytes.write().await += reclaimed_bytes;
+        }
+
+        self.notify_not_empty.notify_waiters();
+    }
+
+    /// Receive the next message. Skips over the front of the queue without
+    /// returning it if that message's `visible_at` is still in the future —
+    /// since the queue is ordered by `visible_at`, that means no message is
+    /// currently receivable. For `AtLeastOnce`/`ExactlyOnce` delivery, the
+    /// message is leased out (moved into the in-flight table with a
+    /// `visible_after` deadline) rather than permanently removed; the
+    /// caller must call [`Self::ack`] to confirm processing, or the lease
+    /// expires and the message is redelivered.
+    ///
+    /// Returns the message alongside its ID, since the ID is needed to
+    /// `ack`/`keep_alive` it later.
+    pub async fn receive(&self) -> Option<(Message, MessageId)> {
+        let (message, id, _metadata) = self.receive_core().await?;
+        Some((message, id))
+    }
+
+    /// Non-blocking receive with the same semantics as `receive`, but
+    /// additionally surfacing delivery metadata (attempt count, last
+    /// attempt time) alongside the message instead of just its ID.
+    pub async fn try_recv(&self) -> Option<(Message, MessageMetadata)> {
+        let (message, _id, metadata) = self.receive_core().await?;
+        Some((message, metadata))
+    }
+
+    /// Receive the next message, waiting until one becomes available (the
+    /// queue is non-empty and its earliest message's `visible_at` has
+    /// arrived) instead of returning `None` immediately like `try_recv`.
+    /// Producers signal this on `send`/`send_after`/`send_at`, so this
+    /// doesn't busy-poll the queue.
+    ///
+    /// # Errors
+    ///
+    /// This never actually fails — the `Result` wrapper matches
+    /// `recv_timeout`'s signature for callers that use the two
+    /// interchangeably.
+    pub async fn recv(&self) -> PersistenceResult<(Message, MessageMetadata)> {
+        loop {
+            let notified = self.notify_not_empty.notified();
+
+            if let Some(result) = self.try_recv().await {
+                return Ok(result);
+            }
+
+            let next_visible_at = {
+                let queue = self.queue.read().await;
+                queue.peek().map(|front| front.0.visible_at)
+            };
+
+            match next_visible_at {
+                Some(visible_at) if visible_at > Utc::now() => {
+                    let delay = (visible_at - Utc::now())
+                        .to_std()
+                        .unwrap_or(std::time::Duration::ZERO);
+                    tokio::select! {
+                        () = notified => {}
+                        () = tokio::time::sleep(delay) => {}
+                    }
+                }
+                _ => notified.await,
+            }
+        }
+    }
+
+    /// Like `recv`, but gives up and returns `PersistenceError::Timeout` if
+    /// no message becomes available within `timeout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Timeout` if no message becomes available
+    /// before `timeout` elapses.
+    pub async fn recv_timeout(
+        &self,
+        timeout: std::time::Duration,
+    ) -> PersistenceResult<(Message, MessageMetadata)> {
+        tokio::time::timeout(timeout, self.recv())
+            .await
+            .map_err(|_| {
+                PersistenceError::timeout(u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX))
+            })?
+    }
+
+    /// Shared receive path: reaps expired leases, pops the earliest-visible
+    /// message off the queue (if any is currently visible), leases it out
+    /// for `AtLeastOnce`/`ExactlyOnce` delivery, and wakes any `send` call
+    /// blocked on `max_queue_depth`.
+    async fn receive_core(&self) -> Option<(Message, MessageId, MessageMetadata)> {
+        if self.config.delivery_mode != DeliveryMode::AtMostOnce {
+            self.reap_expired().await;
+        }
+
+        let queued = {
+            let mut queue = self.queue.write().await;
+            let now = Utc::now();
+            if queue.peek().is_some_and(|front| front.0.visible_at > now) {
+                return None;
+            }
+            queue.pop()?.0
+        };
+
+        let size = Self::message_size(&queued.message).unwrap_or(0);
+        {
+            let mut total_bytes = self.total_bytes.write().await;
+            *total_bytes = total_bytes.saturating_sub(size);
+        }
+        self.notify_not_full.notify_one();
+
+        let metadata = MessageMetadata {
+            delivery_attempts: queued.delivery_count,
+            last_attempt_at: Some(Utc::now()),
+            ..MessageMetadata::default()
+        };
+        let id = queued.message.id().clone();
+
+        if self.config.delivery_mode == DeliveryMode::AtMostOnce {
+            return Some((queued.message, id, metadata));
+        }
+
+        let message = queued.message.clone();
+        let visible_after =
+            Utc::now() + ChronoDuration::seconds(self.config.visibility_timeout_secs as i64);
+
+        let mut in_flight = self.in_flight.write().await;
+        in_flight.insert(id.as_str().to_string(), InFlightMessage {
+            queued,
+            visible_after,
+        });
+
+        Some((message, id, metadata))
+    }
+
+    /// Receive the next message and immediately acknowledge it.
+    ///
+    /// For `AtMostOnce` delivery this is equivalent to `receive` (there's no
+    /// lease to release). For `AtLeastOnce`/`ExactlyOnce`, prefer `receive`
+    /// followed by an explicit `ack` once processing has actually
+    /// succeeded — this method exists for fire-and-forget consumers that
+    /// accept the weaker guarantee.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if delivery tracking fails to update.
+    pub async fn receive_and_ack(&self) -> PersistenceResult<Option<Message>> {
+        let Some((message, id)) = self.receive().await else {
+            return Ok(None);
+        };
+
+        self.ack(&id).await?;
+        Ok(Some(message))
+    }
+
+    /// Permanently acknowledge a leased message, removing it from the
+    /// in-flight table so it is never redelivered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if delivery tracking fails to update.
+    pub async fn ack(&self, id: &MessageId) -> PersistenceResult<()> {
+        {
+            let mut in_flight = self.in_flight.write().await;
+            in_flight.remove(id.as_str());
+        }
+
+        if let Some(tracker) = &self.delivery_tracker {
+            tracker.mark_delivered(id).await?;
+        }
+
+        if let Some(store) = &self.store {
+            store.mark_channel_message_delivered(id.as_str()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Negatively acknowledge a leased message, mirroring the SMTP outbound
+    /// queue's retry/DSN behavior: if it hasn't yet exceeded
+    /// `max_delivery_attempts`, re-enqueue it with a growing exponential
+    /// backoff delay; otherwise park it in the dead-letter store (wrapping
+    /// the original message with `reason` and the final attempt count) so a
+    /// poisoned message can't block the queue forever.
+    ///
+    /// A no-op if `id` is not currently in-flight.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if delivery tracking fails to update.
+    pub async fn nack(&self, id: &MessageId, reason: impl Into<String>) -> PersistenceResult<()> {
+        let Some(mut entry) = ({
+            let mut in_flight = self.in_flight.write().await;
+            in_flight.remove(id.as_str())
+        }) else {
+            return Ok(());
+        };
+
+        entry.queued.delivery_count += 1;
+        let reason = reason.into();
+
+        if entry.queued.delivery_count > self.config.max_delivery_attempts {
+            if let Some(tracker) = &self.delivery_tracker {
+                tracker.mark_failed(id, reason.clone()).await?;
+            }
+
+            if let Some(store) = &self.store {
+                store.delete_channel_message(id.as_str()).await?;
+            }
+
+            let metadata = MessageMetadata {
+                delivery_attempts: entry.queued.delivery_count,
+                last_attempt_at: Some(Utc::now()),
+                failure_reason: Some(reason),
+                ..MessageMetadata::default()
+            };
+
+            let mut dead_letters = self.dead_letters.write().await;
+            dead_letters.push_back(DeadLetteredMessage {
+                message: entry.queued.message,
+                metadata,
+            });
+
+            return Ok(());
+        }
+
+        entry.queued.visible_at =
+            Utc::now() + self.config.retry_backoff(entry.queued.delivery_count);
+
+        if let Some(store) = &self.store {
+            self.persist_queued(store, &entry.queued).await?;
+        }
+
+        let size = Self::message_size(&entry.queued.message).unwrap_or(0);
+        *self.total_bytes.write().await += size;
+
+        let mut queue = self.queue.write().await;
+        queue.push(ScheduledMessage(entry.queued));
+        drop(queue);
+
+        self.notify_not_empty.notify_one();
+
+        Ok(())
+    }
+
+    /// Number of messages parked in the dead-letter store.
+    pub async fn dead_letter_depth(&self) -> usize {
+        self.dead_letters.read().await.len()
+    }
+
+    /// Drain every message currently parked in the dead-letter store, for
+    /// operators to inspect and replay poisoned messages (e.g. by feeding
+    /// them back into `send` once the underlying issue is fixed).
+    pub async fn drain_dead_letter(&self) -> Vec<DeadLetteredMessage> {
+        self.dead_letters.write().await.drain(..).collect()
+    }
+
+    /// Push a leased message's visibility deadline forward by the channel's
+    /// `visibility_timeout_secs`, without acking it, so a long-running
+    /// consumer can checkpoint progress without losing its lease.
+    ///
+    /// Returns `true` if `id` was in-flight and its lease was extended.
+    pub async fn keep_alive(&self, id: &MessageId) -> bool {
+        let mut in_flight = self.in_flight.write().await;
+        if let Some(entry) = in_flight.get_mut(id.as_str()) {
+            entry.visible_after =
+                Utc::now() + ChronoDuration::seconds(self.config.visibility_timeout_secs as i64);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Number of messages waiting in the queue.
+    pub async fn queue_depth(&self) -> usize {
+        self.queue.read().await.len()
+    }
+
+    /// Number of messages currently leased out, pending `ack`.
+    pub async fn in_flight_count(&self) -> usize {
+        self.in_flight.read().await.len()
+    }
+}
+
+/// A cloneable, `Stream`-based handle for consuming a channel's messages,
+/// e.g. `while let Some((msg, meta)) = receiver.next().await`. Each item is
+/// fetched via [`DurableChannel::recv`], so it waits for a message rather
+/// than polling.
+pub struct ChannelReceiver {
+    channel: Arc<DurableChannel>,
+    pending: Option<BoxFuture<'static, PersistenceResult<(Message, MessageMetadata)>>>,
+}
+
+impl ChannelReceiver {
+    /// Wrap a channel handle for `Stream`-based consumption.
+    #[must_use]
+    pub fn new(channel: Arc<DurableChannel>) -> Self {
+        Self {
+            channel,
+            pending: None,
+        }
+    }
+}
+
+impl Clone for ChannelReceiver {
+    fn clone(&self) -> Self {
+        Self::new(Arc::clone(&self.channel))
+    }
+}
+
+impl futures::Stream for ChannelReceiver {
+    type Item = (Message, MessageMetadata);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        let channel = Arc::clone(&this.channel);
+        let fut = this
+            .pending
+            .get_or_insert_with(|| async move { channel.recv().await }.boxed());
+
+        match fut.as_mut().poll(cx) {
+            Poll::Ready(result) => {
+                this.pending = None;
+                Poll::Ready(result.ok())
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_send_and_receive() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        let msg = Message::one_way(serde_json::json!({"data": "hello"}));
+
+        let sent_id = channel.send(msg).await.expect("send");
+        let (received, received_id) = channel.receive().await.expect("receive");
+
+        assert_eq!(received.id(), &sent_id);
+        assert_eq!(received_id, sent_id);
+    }
+
+    #[tokio::test]
+    async fn test_at_least_once_leases_instead_of_removing() {
+        let config = ChannelConfig {
+            delivery_mode: DeliveryMode::AtLeastOnce,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("test", config);
+        channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+
+        let (_, id) = channel.receive().await.expect("receive");
+
+        assert_eq!(channel.queue_depth().await, 0);
+        assert_eq!(channel.in_flight_count().await, 1);
+
+        channel.ack(&id).await.expect("ack");
+        assert_eq!(channel.in_flight_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_at_most_once_never_leases() {
+        let config = ChannelConfig {
+            delivery_mode: DeliveryMode::AtMostOnce,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("test", config);
+        channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+
+        let _ = channel.receive().await.expect("receive");
+
+        assert_eq!(channel.queue_depth().await, 0);
+        assert_eq!(channel.in_flight_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_expired_lease_is_redelivered() {
+        let config = ChannelConfig {
+            delivery_mode: DeliveryMode::AtLeastOnce,
+            visibility_timeout_secs: 0,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("test", config);
+        channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+
+        let (_, first_id) = channel.receive().await.expect("first receive");
+
+        // With a zero-second timeout the lease is already expired, so the
+        // next receive reaps it back onto the queue instead of the message
+        // being lost.
+        let (_, second_id) = channel.receive().await.expect("second receive");
+
+        assert_eq!(first_id, second_id);
+        assert_eq!(channel.in_flight_count().await, 1);
+        assert_eq!(channel.queue_depth().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_extends_lease() {
+        let config = ChannelConfig {
+            delivery_mode: DeliveryMode::AtLeastOnce,
+            visibility_timeout_secs: 60,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("test", config);
+        channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+
+        let (_, id) = channel.receive().await.expect("receive");
+        assert!(channel.keep_alive(&id).await);
+
+        let unknown_id = MessageId::new();
+        assert!(!channel.keep_alive(&unknown_id).await);
+    }
+
+    #[tokio::test]
+    async fn test_send_after_delays_visibility() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        channel
+            .send_after(
+                Message::one_way(serde_json::json!({})),
+                ChronoDuration::hours(1),
+                DeliveryMode::AtLeastOnce,
+            )
+            .await
+            .expect("send_after");
+
+        assert_eq!(channel.queue_depth().await, 1);
+        assert!(channel.receive().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_send_at_past_is_immediately_receivable() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        let sent_id = channel
+            .send_at(
+                Message::one_way(serde_json::json!({})),
+                Utc::now() - ChronoDuration::seconds(1),
+                DeliveryMode::AtMostOnce,
+            )
+            .await
+            .expect("send_at");
+
+        let (_, received_id) = channel.receive().await.expect("receive");
+        assert_eq!(received_id, sent_id);
+    }
+
+    #[tokio::test]
+    async fn test_delayed_message_does_not_block_earlier_ones() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        channel
+            .send_after(
+                Message::one_way(serde_json::json!({"which": "delayed"})),
+                ChronoDuration::hours(1),
+                DeliveryMode::AtMostOnce,
+            )
+            .await
+            .expect("send_after");
+        let ready_id = channel
+            .send(Message::one_way(serde_json::json!({"which": "ready"})))
+            .await
+            .expect("send");
+
+        let (_, received_id) = channel.receive().await.expect("receive");
+        assert_eq!(received_id, ready_id);
+        assert_eq!(channel.queue_depth().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_nack_redelivers_with_backoff() {
+        let config = ChannelConfig {
+            max_delivery_attempts: 2,
+            retry_jitter: false,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("test", config);
+        channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+
+        let (_, id) = channel.receive().await.expect("receive");
+        channel.nack(&id, "handler failed").await.expect("nack 1");
+        assert_eq!(channel.queue_depth().await, 1);
+        assert_eq!(channel.dead_letter_depth().await, 0);
+        // Still in the future thanks to the backoff delay, so not yet
+        // receivable.
+        assert!(channel.receive().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_nack_beyond_max_attempts_dead_letters() {
+        let config = ChannelConfig {
+            max_delivery_attempts: 0,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("test", config);
+        channel
+            .send(Message::one_way(serde_json::json!({"payload": 1})))
+            .await
+            .expect("send");
+
+        let (_, id) = channel.receive().await.expect("receive");
+        // `max_delivery_attempts` of 0 means the first `nack` (which raises
+        // `delivery_count` to 1) already exceeds the limit, so it goes
+        // straight to the dead-letter store instead of being re-enqueued.
+        channel.nack(&id, "first failure").await.expect("nack");
+
+        assert_eq!(channel.queue_depth().await, 0);
+        assert_eq!(channel.dead_letter_depth().await, 1);
+        assert_eq!(channel.in_flight_count().await, 0);
+
+        let dead = channel.drain_dead_letter().await;
+        assert_eq!(dead.len(), 1);
+        assert_eq!(
+            dead[0].metadata.failure_reason.as_deref(),
+            Some("first failure")
+        );
+        assert_eq!(dead[0].metadata.delivery_attempts, 1);
+        assert_eq!(channel.dead_letter_depth().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_nack_unknown_id_is_a_no_op() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        channel
+            .nack(&MessageId::new(), "no such lease")
+            .await
+            .expect("nack");
+        assert_eq!(channel.dead_letter_depth().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_try_recv_surfaces_delivery_metadata() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+
+        let (_, metadata) = channel.try_recv().await.expect("try_recv");
+        assert_eq!(metadata.delivery_attempts, 0);
+        assert!(channel.try_recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_recv_waits_for_send() {
+        let channel = Arc::new(DurableChannel::new("test", ChannelConfig::default()));
+        let sender = Arc::clone(&channel);
+
+        let recv_task = tokio::spawn(async move { channel.recv().await });
+        tokio::task::yield_now().await;
+
+        sender
+            .send(Message::one_way(serde_json::json!({"data": "late"})))
+            .await
+            .expect("send");
+
+        let (message, _metadata) = recv_task
+            .await
+            .expect("task")
+            .expect("recv");
+        assert_eq!(message.payload(), &serde_json::json!({"data": "late"}));
+    }
+
+    #[tokio::test]
+    async fn test_recv_timeout_elapses_on_empty_channel() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        let result = channel
+            .recv_timeout(std::time::Duration::from_millis(10))
+            .await;
+        assert!(matches!(result, Err(PersistenceError::Timeout { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_send_blocks_on_full_queue_until_space_frees() {
+        let config = ChannelConfig {
+            max_queue_depth: Some(1),
+            block_on_full: true,
+            ..ChannelConfig::default()
+        };
+        let channel = Arc::new(DurableChannel::new("test", config));
+        channel
+            .send(Message::one_way(serde_json::json!({"which": "first"})))
+            .await
+            .expect("send first");
+
+        let sender = Arc::clone(&channel);
+        let send_task = tokio::spawn(async move {
+            sender
+                .send(Message::one_way(serde_json::json!({"which": "second"})))
+                .await
+        });
+        tokio::task::yield_now().await;
+        assert!(!send_task.is_finished());
+
+        channel.receive().await.expect("drain first");
+        send_task.await.expect("task").expect("send second");
+        assert_eq!(channel.queue_depth().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_rejects_when_full_and_not_blocking() {
+        let config = ChannelConfig {
+            max_queue_depth: Some(1),
+            block_on_full: false,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("test", config);
+        channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send first");
+
+        let result = channel.send(Message::one_way(serde_json::json!({}))).await;
+        assert!(matches!(result, Err(PersistenceError::InvalidState { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_channel_receiver_stream_yields_sent_messages() {
+        let channel = Arc::new(DurableChannel::new("test", ChannelConfig::default()));
+        channel
+            .send(Message::one_way(serde_json::json!({"n": 1})))
+            .await
+            .expect("send");
+        channel
+            .send(Message::one_way(serde_json::json!({"n": 2})))
+            .await
+            .expect("send");
+
+        let mut receiver = ChannelReceiver::new(Arc::clone(&channel));
+        let (first, _) = receiver.next().await.expect("first");
+        let (second, _) = receiver.next().await.expect("second");
+
+        assert_eq!(first.payload(), &serde_json::json!({"n": 1}));
+        assert_eq!(second.payload(), &serde_json::json!({"n": 2}));
+    }
+
+    #[tokio::test]
+    async fn test_receive_and_ack_removes_lease() {
+        let config = ChannelConfig {
+            delivery_mode: DeliveryMode::ExactlyOnce,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("test", config);
+        channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+
+        let received = channel.receive_and_ack().await.expect("receive_and_ack");
+        assert!(received.is_some());
+        assert_eq!(channel.in_flight_count().await, 0);
+        assert_eq!(channel.queue_depth().await, 0);
+    }
+
+    #[test]
+    fn test_encode_decode_message_roundtrips_with_default_codec() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        let message = Message::one_way(serde_json::json!({"n": 1}));
+
+        let (tag, encoded) = channel.encode_message(&message).expect("encode");
+        assert_eq!(tag, "none");
+
+        let decoded = channel.decode_message(&tag, &encoded).expect("decode");
+        assert_eq!(decoded.payload(), message.payload());
+    }
+
+    #[test]
+    fn test_encode_decode_message_roundtrips_with_zstd_codec() {
+        let config = ChannelConfig {
+            codec: Arc::new(super::super::codec::ZstdCodec::default()),
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("test", config);
+        let message = Message::one_way(serde_json::json!({"big": "x".repeat(256)}));
+
+        let (tag, encoded) = channel.encode_message(&message).expect("encode");
+        assert_eq!(tag, "zstd");
+
+        let decoded = channel.decode_message(&tag, &encoded).expect("decode");
+        assert_eq!(decoded.payload(), message.payload());
+    }
+
+    #[test]
+    fn test_decode_message_rejects_mismatched_codec_tag() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        let message = Message::one_way(serde_json::json!({}));
+        let (_, encoded) = channel.encode_message(&message).expect("encode");
+
+        assert!(channel.decode_message("zstd", &encoded).is_err());
+    }
+
+    async fn setup_store() -> OrchestratorStore {
+        let store = OrchestratorStore::connect(crate::persistence::StoreConfig::in_memory())
+            .await
+            .expect("connect");
+        let _ = store.initialize_schema().await;
+        store
+    }
+
+    #[tokio::test]
+    async fn test_recover_rehydrates_queue_in_order_after_restart() {
+        let store = setup_store().await;
+        let tracker = Arc::new(DeliveryTracker::new(Default::default()));
+
+        let channel = DurableChannel::with_store(
+            "durable",
+            ChannelConfig::default(),
+            store.clone(),
+            Arc::clone(&tracker),
+        );
+        channel
+            .send(Message::one_way(serde_json::json!({"n": 1})))
+            .await
+            .expect("send first");
+        channel
+            .send(Message::one_way(serde_json::json!({"n": 2})))
+            .await
+            .expect("send second");
+        drop(channel);
+
+        // Recover against the same store, as a restarted process would.
+        let recovered =
+            DurableChannel::recover("durable", ChannelConfig::default(), store, tracker)
+                .await
+                .expect("recover");
+
+        assert_eq!(recovered.queue_depth().await, 2);
+        let (first, _) = recovered.receive().await.expect("first");
+        let (second, _) = recovered.receive().await.expect("second");
+        assert_eq!(first.payload(), &serde_json::json!({"n": 1}));
+        assert_eq!(second.payload(), &serde_json::json!({"n": 2}));
+    }
+
+    #[tokio::test]
+    async fn test_rehydrate_skips_acked_messages() {
+        let store = setup_store().await;
+        let tracker = Arc::new(DeliveryTracker::new(Default::default()));
+
+        let channel = DurableChannel::with_store(
+            "durable-ack",
+            ChannelConfig::default(),
+            store.clone(),
+            Arc::clone(&tracker),
+        );
+        let id = channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+        let (_, received_id) = channel.receive().await.expect("receive");
+        assert_eq!(received_id, id);
+        channel.ack(&id).await.expect("ack");
+
+        let queue_depth_before_drop = channel.queue_depth().await;
+        assert_eq!(queue_depth_before_drop, 0);
+        drop(channel);
+
+        let recovered =
+            DurableChannel::recover("durable-ack", ChannelConfig::default(), store, tracker)
+                .await
+                .expect("recover");
+
+        assert_eq!(recovered.queue_depth().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_compact_removes_delivered_rows() {
+        let store = setup_store().await;
+        let tracker = Arc::new(DeliveryTracker::new(Default::default()));
+        let channel = DurableChannel::with_store(
+            "durable-compact",
+            ChannelConfig::default(),
+            store,
+            tracker,
+        );
+
+        let id = channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+        channel.receive().await.expect("receive");
+        channel.ack(&id).await.expect("ack");
+
+        let removed = channel.compact().await.expect("compact");
+        assert_eq!(removed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_rehydrate_is_a_no_op_without_a_store() {
+        let channel = DurableChannel::new("test", ChannelConfig::default());
+        channel.rehydrate().await.expect("rehydrate");
+        assert_eq!(channel.queue_depth().await, 0);
+        assert_eq!(channel.compact().await.expect("compact"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_send_rate_limit_rejects_once_burst_exhausted() {
+        let config = ChannelConfig {
+            send_rate: Some(SendRateLimit {
+                messages_per_sec: 1.0,
+                burst: 2,
+            }),
+            block_on_full: false,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("rate-limited", config);
+
+        channel
+            .send(Message::one_way(serde_json::json!({"n": 1})))
+            .await
+            .expect("first send within burst");
+        channel
+            .send(Message::one_way(serde_json::json!({"n": 2})))
+            .await
+            .expect("second send within burst");
+
+        let result = channel
+            .send(Message::one_way(serde_json::json!({"n": 3})))
+            .await;
+        assert!(matches!(
+            result,
+            Err(PersistenceError::RateLimited { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_rate_limit_blocks_then_succeeds_when_configured_to_block() {
+        let config = ChannelConfig {
+            send_rate: Some(SendRateLimit {
+                messages_per_sec: 50.0,
+                burst: 1,
+            }),
+            block_on_full: true,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("rate-limited-blocking", config);
+
+        channel
+            .send(Message::one_way(serde_json::json!({"n": 1})))
+            .await
+            .expect("first send within burst");
+
+        let second = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            channel.send(Message::one_way(serde_json::json!({"n": 2}))),
+        )
+        .await
+        .expect("should not time out waiting for a refilled token");
+        second.expect("second send after refill");
+    }
+
+    #[tokio::test]
+    async fn test_max_total_bytes_rejects_oversized_enqueue() {
+        let config = ChannelConfig {
+            max_total_bytes: Some(32),
+            block_on_full: false,
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("byte-quota", config);
+
+        let result = channel
+            .send(Message::one_way(serde_json::json!({"payload": "x".repeat(256)})))
+            .await;
+        assert!(matches!(result, Err(PersistenceError::InvalidState { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_quota_usage_tracks_queue_and_frees_on_receive() {
+        let config = ChannelConfig {
+            max_total_bytes: Some(10_000),
+            ..ChannelConfig::default()
+        };
+        let channel = DurableChannel::new("byte-quota-usage", config);
+
+        channel
+            .send(Message::one_way(serde_json::json!({"n": 1})))
+            .await
+            .expect("send");
+
+        let usage = channel.quota_usage().await;
+        assert!(usage.bytes_used > 0);
+        assert_eq!(usage.bytes_remaining, Some(10_000 - usage.bytes_used));
+
+        channel.receive().await.expect("receive");
+
+        let usage_after = channel.quota_usage().await;
+        assert_eq!(usage_after.bytes_used, 0);
+        assert_eq!(usage_after.bytes_remaining, Some(10_000));
+    }
+
+    #[tokio::test]
+    async fn test_quota_usage_without_limit_reports_no_remaining_cap() {
+        let channel = DurableChannel::new("no-quota", ChannelConfig::default());
+        channel
+            .send(Message::one_way(serde_json::json!({})))
+            .await
+            .expect("send");
+
+        let usage = channel.quota_usage().await;
+        assert!(usage.bytes_used > 0);
+        assert_eq!(usage.bytes_remaining, None);
+    }
+}
@@ -0,0 +1,224 @@
+//! Pluggable codecs for messages at rest.
+//!
+//! A [`MessageCodec`] transforms a message's raw bytes before they would be
+//! written to storage, and reverses the transformation on load. Each codec
+//! carries a stable [`MessageCodec::tag`] so that messages written under one
+//! codec remain readable after [`ChannelConfig::codec`] is changed for new
+//! messages — the load path dispatches on the tag rather than assuming the
+//! channel's current codec.
+//!
+//! A channel created with
+//! [`DurableChannel::with_store`](super::channel::DurableChannel::with_store)
+//! runs each queued message through its codec before writing the
+//! `channel_message` row, and records the codec's tag on that row. On load,
+//! the channel's current codec and the keyless built-ins (`none`, `zstd`)
+//! can be selected by tag; an [`EncryptedCodec`] row is readable only while
+//! it is the channel's current codec, since its key is not stored.
+
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::{ChaCha20Poly1305, KeyInit, Nonce};
+
+use crate::persistence::{PersistenceError, PersistenceResult};
+
+/// Size in bytes of the random nonce [`EncryptedCodec`] prepends to each
+/// ciphertext.
+const NONCE_LEN: usize = 12;
+
+/// Transforms message bytes before they are persisted, and reverses the
+/// transformation on load.
+///
+/// Implementations must round-trip: `decode(&encode(data)?)? == data`.
+pub trait MessageCodec: Send + Sync {
+    /// Stable tag identifying this codec, stored alongside encoded bytes so
+    /// the load path knows which codec to decode with.
+    fn tag(&self) -> &'static str;
+
+    /// Encode raw message bytes for storage.
+    fn encode(&self, data: &[u8]) -> PersistenceResult<Vec<u8>>;
+
+    /// Decode stored bytes back into the original message bytes.
+    fn decode(&self, data: &[u8]) -> PersistenceResult<Vec<u8>>;
+}
+
+/// Passthrough codec: stores message bytes unmodified.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoneCodec;
+
+impl MessageCodec for NoneCodec {
+    fn tag(&self) -> &'static str {
+        "none"
+    }
+
+    fn encode(&self, data: &[u8]) -> PersistenceResult<Vec<u8>> {
+        Ok(data.to_vec())
+    }
+
+    fn decode(&self, data: &[u8]) -> PersistenceResult<Vec<u8>> {
+        Ok(data.to_vec())
+    }
+}
+
+/// Upper bound on a single decompressed message body, so a corrupt or
+/// maliciously large `zstd` frame can't be used to exhaust memory.
+const ZSTD_DECOMPRESS_CAPACITY: usize = 64 * 1024 * 1024;
+
+/// Zstd compression codec, for shrinking large JSON payloads at rest.
+#[derive(Debug, Clone, Copy)]
+pub struct ZstdCodec {
+    level: i32,
+}
+
+impl ZstdCodec {
+    /// Create a codec using the given zstd compression level.
+    #[must_use]
+    pub fn new(level: i32) -> Self {
+        Self { level }
+    }
+}
+
+impl Default for ZstdCodec {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl MessageCodec for ZstdCodec {
+    fn tag(&self) -> &'static str {
+        "zstd"
+    }
+
+    fn encode(&self, data: &[u8]) -> PersistenceResult<Vec<u8>> {
+        zstd::bulk::compress(data, self.level)
+            .map_err(|e| PersistenceError::serialization_error(format!("zstd compression failed: {e}")))
+    }
+
+    fn decode(&self, data: &[u8]) -> PersistenceResult<Vec<u8>> {
+        zstd::bulk::decompress(data, ZSTD_DECOMPRESS_CAPACITY).map_err(|e| {
+            PersistenceError::serialization_error(format!("zstd decompression failed: {e}"))
+        })
+    }
+}
+
+/// AEAD codec for sensitive workflow messages at rest, using
+/// ChaCha20-Poly1305 with a caller-supplied 256-bit key.
+///
+/// A fresh random nonce is generated for every [`encode`](Self::encode) call
+/// and prepended to the ciphertext so [`decode`](Self::decode) can recover
+/// it; reusing a nonce with the same key would break the AEAD's
+/// confidentiality guarantees, so callers never need to manage nonces
+/// themselves.
+pub struct EncryptedCodec {
+    cipher: ChaCha20Poly1305,
+}
+
+impl EncryptedCodec {
+    /// Create a codec from a 32-byte key.
+    #[must_use]
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self {
+            cipher: ChaCha20Poly1305::new(key.into()),
+        }
+    }
+}
+
+impl std::fmt::Debug for EncryptedCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptedCodec").finish_non_exhaustive()
+    }
+}
+
+impl MessageCodec for EncryptedCodec {
+    fn tag(&self) -> &'static str {
+        "chacha20poly1305"
+    }
+
+    fn encode(&self, data: &[u8]) -> PersistenceResult<Vec<u8>> {
+        let nonce_bytes = rand::random::<[u8; NONCE_LEN]>();
+        let nonce = Nonce::from_slice(&nonce_bytes);
+        let ciphertext = self
+            .cipher
+            .encrypt(nonce, data)
+            .map_err(|e| PersistenceError::serialization_error(format!("encryption failed: {e}")))?;
+
+        let mut out = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        out.extend_from_slice(&nonce_bytes);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    fn decode(&self, data: &[u8]) -> PersistenceResult<Vec<u8>> {
+        if data.len() < NONCE_LEN {
+            return Err(PersistenceError::serialization_error(
+                "ciphertext shorter than nonce",
+            ));
+        }
+        let (nonce_bytes, ciphertext) = data.split_at(NONCE_LEN);
+        let nonce = Nonce::from_slice(nonce_bytes);
+        self.cipher
+            .decrypt(nonce, ciphertext)
+            .map_err(|e| PersistenceError::serialization_error(format!("decryption failed: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_none_codec_roundtrip() {
+        let codec = NoneCodec;
+        let data = b"hello world";
+        assert_eq!(codec.decode(&codec.encode(data).unwrap()).unwrap(), data);
+        assert_eq!(codec.tag(), "none");
+    }
+
+    #[test]
+    fn test_zstd_codec_roundtrip() {
+        let codec = ZstdCodec::default();
+        let data = b"hello world, hello world, hello world";
+        let encoded = codec.encode(data).unwrap();
+        assert_eq!(codec.decode(&encoded).unwrap(), data);
+        assert_eq!(codec.tag(), "zstd");
+    }
+
+    #[test]
+    fn test_zstd_codec_shrinks_repetitive_payload() {
+        let codec = ZstdCodec::default();
+        let data = vec![b'a'; 4096];
+        let encoded = codec.encode(&data).unwrap();
+        assert!(encoded.len() < data.len());
+    }
+
+    #[test]
+    fn test_encrypted_codec_roundtrip() {
+        let codec = EncryptedCodec::new(&[7u8; 32]);
+        let data = b"sensitive payload";
+        let encoded = codec.encode(data).unwrap();
+        assert_eq!(codec.decode(&encoded).unwrap(), data);
+        assert_eq!(codec.tag(), "chacha20poly1305");
+    }
+
+    #[test]
+    fn test_encrypted_codec_produces_distinct_ciphertext_each_call() {
+        let codec = EncryptedCodec::new(&[3u8; 32]);
+        let data = b"same payload";
+        let a = codec.encode(data).unwrap();
+        let b = codec.encode(data).unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_encrypted_codec_rejects_tampered_ciphertext() {
+        let codec = EncryptedCodec::new(&[9u8; 32]);
+        let mut encoded = codec.encode(b"data").unwrap();
+        let last = encoded.len() - 1;
+        encoded[last] ^= 0xFF;
+        assert!(codec.decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_encrypted_codec_decode_rejects_short_ciphertext() {
+        let codec = EncryptedCodec::new(&[1u8; 32]);
+        assert!(codec.decode(&[0u8; 4]).is_err());
+    }
+}
@@ -21,11 +21,15 @@
 #![allow(dead_code)]
 
 mod channel;
+mod codec;
 mod delivery;
 mod router;
 mod types;
 
-pub use channel::{ChannelConfig, DurableChannel};
+pub use channel::{
+    ChannelConfig, ChannelReceiver, DeadLetteredMessage, DurableChannel, QuotaUsage, SendRateLimit,
+};
+pub use codec::{EncryptedCodec, MessageCodec, NoneCodec, ZstdCodec};
 pub use delivery::{DeliveryMode, DeliveryStatus, DeliveryTracker};
 pub use router::{MessageRouter, RouteConfig};
 pub use types::{ChannelId, Message, MessageId, MessagePayload};
@@ -265,6 +265,9 @@ pub struct MessageMetadata {
     pub delivery_attempts: u32,
     /// Last delivery attempt time
     pub last_attempt_at: Option<DateTime<Utc>>,
+    /// Reason the last delivery attempt failed, if any. Set when a message
+    /// is parked in a dead-letter store after exhausting its retries.
+    pub failure_reason: Option<String>,
 }
 
 impl MessageMetadata {
@@ -0,0 +1,251 @@
+//! Durable message persistence for channel crash recovery.
+//!
+//! CRUD operations for channel message records in SurrealDB, backing
+//! [`DurableChannel`](crate::messaging::DurableChannel)'s crash recovery —
+//! without this, a process restart would silently drop the in-memory queue.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+use super::client::OrchestratorStore;
+use super::error::{from_surrealdb_error, PersistenceError, PersistenceResult};
+
+/// A message queued on a channel, persisted so it survives a restart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelMessageRecord {
+    /// SurrealDB record ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Thing>,
+    /// Channel this message belongs to.
+    pub channel_id: String,
+    /// The message's own ID, used as the record key.
+    pub message_id: String,
+    /// Tag of the codec (see `crate::messaging::codec`) that `message_data`
+    /// was encoded with.
+    pub codec: String,
+    /// The message, JSON-serialized then run through `codec`.
+    pub message_data: Vec<u8>,
+    /// Number of times this message has been handed out via `receive`.
+    pub delivery_count: u32,
+    /// When this message was enqueued.
+    pub queued_at: DateTime<Utc>,
+    /// When this message becomes eligible for `receive`.
+    pub visible_at: DateTime<Utc>,
+    /// Whether this message has been acknowledged. Delivered rows aren't
+    /// deleted immediately so a concurrent `rehydrate` can't race a
+    /// just-acked message back into the queue; `compact` purges them.
+    pub delivered: bool,
+}
+
+impl ChannelMessageRecord {
+    /// Create a new, not-yet-delivered record.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        channel_id: impl Into<String>,
+        message_id: impl Into<String>,
+        codec: impl Into<String>,
+        message_data: Vec<u8>,
+        delivery_count: u32,
+        queued_at: DateTime<Utc>,
+        visible_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: None,
+            channel_id: channel_id.into(),
+            message_id: message_id.into(),
+            codec: codec.into(),
+            message_data,
+            delivery_count,
+            queued_at,
+            visible_at,
+            delivered: false,
+        }
+    }
+}
+
+impl OrchestratorStore {
+    /// Persist (or overwrite) a channel message record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn save_channel_message(
+        &self,
+        record: &ChannelMessageRecord,
+    ) -> PersistenceResult<ChannelMessageRecord> {
+        let result: Option<ChannelMessageRecord> = self
+            .db()
+            .upsert(("channel_message", record.message_id.as_str()))
+            .content(record.clone())
+            .await
+            .map_err(from_surrealdb_error)?;
+
+        result.ok_or_else(|| PersistenceError::query_failed("failed to save channel message"))
+    }
+
+    /// Mark a channel message as delivered, so `compact_channel_messages`
+    /// can purge it later.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn mark_channel_message_delivered(&self, message_id: &str) -> PersistenceResult<()> {
+        let _: Option<ChannelMessageRecord> = self
+            .db()
+            .query("UPDATE type::thing('channel_message', $id) SET delivered = true")
+            .bind(("id", message_id.to_string()))
+            .await
+            .map_err(from_surrealdb_error)?
+            .take(0)
+            .map_err(from_surrealdb_error)?;
+
+        Ok(())
+    }
+
+    /// List all not-yet-delivered messages for a channel, ordered by when
+    /// they were enqueued (oldest first).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_channel_messages(
+        &self,
+        channel_id: &str,
+    ) -> PersistenceResult<Vec<ChannelMessageRecord>> {
+        let records: Vec<ChannelMessageRecord> = self
+            .db()
+            .query(
+                "SELECT * FROM channel_message \
+                 WHERE channel_id = $channel_id AND delivered = false \
+                 ORDER BY queued_at ASC",
+            )
+            .bind(("channel_id", channel_id.to_string()))
+            .await
+            .map_err(from_surrealdb_error)?
+            .take(0)
+            .map_err(from_surrealdb_error)?;
+
+        Ok(records)
+    }
+
+    /// Delete delivered channel message rows for a channel, so storage
+    /// doesn't grow unbounded. Returns the number of rows removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn compact_channel_messages(&self, channel_id: &str) -> PersistenceResult<usize> {
+        let removed: Vec<ChannelMessageRecord> = self
+            .db()
+            .query("DELETE FROM channel_message WHERE channel_id = $channel_id AND delivered = true RETURN BEFORE")
+            .bind(("channel_id", channel_id.to_string()))
+            .await
+            .map_err(from_surrealdb_error)?
+            .take(0)
+            .map_err(from_surrealdb_error)?;
+
+        Ok(removed.len())
+    }
+
+    /// Delete a single channel message row outright, used when a message
+    /// exceeds its dead-letter threshold rather than being delivered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn delete_channel_message(&self, message_id: &str) -> PersistenceResult<()> {
+        let _: Option<ChannelMessageRecord> = self
+            .db()
+            .delete(("channel_message", message_id))
+            .await
+            .map_err(from_surrealdb_error)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::client::StoreConfig;
+
+    async fn setup_store() -> OrchestratorStore {
+        let config = StoreConfig::in_memory();
+        let store = OrchestratorStore::connect(config).await.unwrap();
+        let _ = store.initialize_schema().await;
+        store
+    }
+
+    fn sample_record(channel_id: &str, message_id: &str) -> ChannelMessageRecord {
+        ChannelMessageRecord::new(
+            channel_id,
+            message_id,
+            "none",
+            b"hello".to_vec(),
+            0,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_save_and_list_channel_message() {
+        let store = setup_store().await;
+        let record = sample_record("chan-1", "msg-1");
+
+        store.save_channel_message(&record).await.expect("save");
+        let listed = store.list_channel_messages("chan-1").await.expect("list");
+
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].message_id, "msg-1");
+    }
+
+    #[tokio::test]
+    async fn test_mark_delivered_excludes_from_list() {
+        let store = setup_store().await;
+        let record = sample_record("chan-2", "msg-2");
+        store.save_channel_message(&record).await.expect("save");
+
+        store
+            .mark_channel_message_delivered("msg-2")
+            .await
+            .expect("mark delivered");
+        let listed = store.list_channel_messages("chan-2").await.expect("list");
+
+        assert!(listed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_compact_removes_delivered_rows() {
+        let store = setup_store().await;
+        let record = sample_record("chan-3", "msg-3");
+        store.save_channel_message(&record).await.expect("save");
+        store
+            .mark_channel_message_delivered("msg-3")
+            .await
+            .expect("mark delivered");
+
+        let removed = store
+            .compact_channel_messages("chan-3")
+            .await
+            .expect("compact");
+        assert_eq!(removed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_channel_message_removes_row() {
+        let store = setup_store().await;
+        let record = sample_record("chan-4", "msg-4");
+        store.save_channel_message(&record).await.expect("save");
+
+        store
+            .delete_channel_message("msg-4")
+            .await
+            .expect("delete");
+        let listed = store.list_channel_messages("chan-4").await.expect("list");
+
+        assert!(listed.is_empty());
+    }
+}
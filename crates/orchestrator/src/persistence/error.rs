@@ -48,6 +48,10 @@ pub enum PersistenceError {
     /// Schema error
     #[error("schema error: {reason}")]
     SchemaError { reason: String },
+
+    /// Operation rejected by a rate limit or quota
+    #[error("rate limited: {reason}")]
+    RateLimited { reason: String },
 }
 
 impl PersistenceError {
@@ -114,6 +118,13 @@ impl PersistenceError {
         }
     }
 
+    /// Create a rate limited error.
+    pub fn rate_limited(reason: impl Into<String>) -> Self {
+        Self::RateLimited {
+            reason: reason.into(),
+        }
+    }
+
     /// Check if error is retryable.
     #[must_use]
     pub const fn is_retryable(&self) -> bool {
@@ -202,4 +213,11 @@ mod tests {
         let err = from_surrealdb_error("some random error");
         assert!(matches!(err, PersistenceError::QueryFailed { .. }));
     }
+
+    #[test]
+    fn test_rate_limited_error() {
+        let err = PersistenceError::rate_limited("exceeded 10 messages/sec");
+        assert!(matches!(err, PersistenceError::RateLimited { .. }));
+        assert!(!err.is_retryable());
+    }
 }
@@ -27,6 +27,7 @@
 //! ```
 
 pub mod bead_store;
+pub mod channel_message_store;
 pub mod checkpoint_store;
 pub mod client;
 pub mod error;
@@ -34,6 +35,7 @@ pub mod workflow_store;
 
 // Re-export main types
 pub use bead_store::{BeadRecord, BeadState};
+pub use channel_message_store::ChannelMessageRecord;
 pub use checkpoint_store::CheckpointRecord;
 pub use client::{Credentials, OrchestratorStore, StoreConfig};
 pub use error::{PersistenceError, PersistenceResult};
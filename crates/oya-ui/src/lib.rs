@@ -19,6 +19,7 @@
 //! - `layout`: Graph layout algorithms
 //! - `utils`: Helper functions and utilities
 //! - `error`: Error types and handling
+//! - `tui` (feature `tui`): ratatui dashboard for headless/CI/SSH use
 
 #![forbid(unsafe_code)]
 
@@ -31,6 +32,8 @@ pub mod models;
 pub mod pages;
 pub mod router;
 pub mod state;
+#[cfg(feature = "tui")]
+pub mod tui;
 pub mod utils;
 
 // Re-export main App component for convenience - Trunk will auto-mount it
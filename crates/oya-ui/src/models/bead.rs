@@ -49,6 +49,33 @@ impl BeadStatus {
     }
 }
 
+/// Structured failure reason for a bead, with a stable string code so the UI
+/// and API can display and filter by cause.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BeadError {
+    /// A declared dependency does not exist or is itself unusable
+    InvalidDependency,
+    /// The bead's command exceeded its time budget
+    CommandTimeout,
+    /// An external validation gate rejected the result
+    FailedExternalValidation,
+    /// Any other failure, carrying a free-form description
+    Other(String),
+}
+
+impl BeadError {
+    /// Returns the stable machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            BeadError::InvalidDependency => "invalid-dependency",
+            BeadError::CommandTimeout => "command-timeout",
+            BeadError::FailedExternalValidation => "failed-external-validation",
+            BeadError::Other(_) => "other",
+        }
+    }
+}
+
 /// Bead priority enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -92,6 +119,19 @@ impl BeadPriority {
     }
 }
 
+/// A single recorded status change, for lifecycle timing and observability.
+///
+/// `at` is a caller-supplied logical timestamp (e.g. milliseconds since
+/// epoch, or a test clock's tick count) rather than one sourced from
+/// `std::time` or `js_sys::Date`, keeping the model usable in both the WASM
+/// build and plain unit tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusTransition {
+    pub from: BeadStatus,
+    pub to: BeadStatus,
+    pub at: u64,
+}
+
 /// Bead data structure representing an issue/work item
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Bead {
@@ -104,6 +144,26 @@ pub struct Bead {
     pub tags: Vec<String>,
     pub created_at: String,
     pub updated_at: String,
+    /// Number of times execution has failed and been retried
+    #[serde(default)]
+    pub retry_count: u32,
+    /// Maximum retries before the bead is considered terminally `Failed`
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// The most recent failure reason, if any
+    #[serde(default)]
+    pub last_error: Option<BeadError>,
+    /// Backoff delay (seconds) to wait before the next retry attempt
+    #[serde(default)]
+    pub next_retry_at: Option<u64>,
+    /// History of status changes, oldest first, recorded via [`Self::transition_to`]
+    #[serde(default)]
+    pub transitions: Vec<StatusTransition>,
+}
+
+/// Default retry ceiling applied to beads that don't specify one.
+fn default_max_retries() -> u32 {
+    3
 }
 
 impl Bead {
@@ -120,6 +180,11 @@ impl Bead {
             tags: Vec::new(),
             created_at: now.clone(),
             updated_at: now,
+            retry_count: 0,
+            max_retries: default_max_retries(),
+            last_error: None,
+            next_retry_at: None,
+            transitions: Vec::new(),
         }
     }
 
@@ -165,6 +230,50 @@ impl Bead {
         self
     }
 
+    /// Builder: set the retry ceiling
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Builder: record a failure, useful for constructing fixtures mid-retry
+    pub fn with_failure(mut self, err: BeadError) -> Self {
+        self.record_failure(err);
+        self
+    }
+
+    /// Record an execution failure and apply the retry/backoff policy.
+    ///
+    /// Increments `retry_count`, stores the error, and computes `next_retry_at`
+    /// as an exponential backoff (`BASE_RETRY_DELAY × 2^retry_count`, capped at
+    /// `MAX_RETRY_DELAY`). The bead transitions to `Failed` only once
+    /// `retry_count >= max_retries`; until then it returns to `Pending` to be
+    /// retried.
+    pub fn record_failure(&mut self, err: BeadError) {
+        const BASE_RETRY_DELAY: u64 = 5;
+        const MAX_RETRY_DELAY: u64 = 300;
+
+        self.retry_count += 1;
+        self.last_error = Some(err);
+
+        let delay = BASE_RETRY_DELAY
+            .checked_shl(self.retry_count)
+            .unwrap_or(MAX_RETRY_DELAY)
+            .min(MAX_RETRY_DELAY);
+        self.next_retry_at = Some(delay);
+
+        self.status = if self.retry_count >= self.max_retries {
+            BeadStatus::Failed
+        } else {
+            BeadStatus::Pending
+        };
+    }
+
+    /// Returns true if the bead has failed at least once but has retries left.
+    pub fn is_retrying(&self) -> bool {
+        self.retry_count > 0 && !self.status.is_terminal()
+    }
+
     /// Check if bead matches search term (case-insensitive)
     pub fn matches_search(&self, search_term: &str) -> bool {
         if search_term.is_empty() {
@@ -184,6 +293,36 @@ impl Bead {
     pub fn is_blocked(&self) -> bool {
         !self.dependencies.is_empty() && self.status == BeadStatus::Pending
     }
+
+    /// Moves the bead to `to`, appending a [`StatusTransition`] from the
+    /// current status. `at` should be non-decreasing across calls on the same
+    /// bead so [`Self::time_in_status`] can compute meaningful durations.
+    pub fn transition_to(&mut self, to: BeadStatus, at: u64) {
+        self.transitions.push(StatusTransition {
+            from: self.status,
+            to,
+            at,
+        });
+        self.status = to;
+    }
+
+    /// Total time spent in `status`, summed over every recorded interval.
+    ///
+    /// An interval runs from a transition landing on `status` to the next
+    /// transition, or to `now` if `status` is still current. Time before the
+    /// bead's first recorded transition is not attributed to any status,
+    /// since no timestamp is known for it.
+    pub fn time_in_status(&self, status: BeadStatus, now: u64) -> u64 {
+        let mut total = 0u64;
+        let mut iter = self.transitions.iter().peekable();
+        while let Some(transition) = iter.next() {
+            let end = iter.peek().map_or(now, |next| next.at);
+            if transition.to == status {
+                total = total.saturating_add(end.saturating_sub(transition.at));
+            }
+        }
+        total
+    }
 }
 
 /// Bead filter options
@@ -331,6 +470,103 @@ mod tests {
         assert!(!running_with_deps.is_blocked());
     }
 
+    #[test]
+    fn test_transition_to_records_history_and_updates_status() {
+        let mut bead = Bead::new("bead-12", "Transitions").with_status(BeadStatus::Pending);
+
+        bead.transition_to(BeadStatus::Ready, 10);
+        bead.transition_to(BeadStatus::Running, 20);
+
+        assert_eq!(bead.status, BeadStatus::Running);
+        assert_eq!(
+            bead.transitions,
+            vec![
+                StatusTransition {
+                    from: BeadStatus::Pending,
+                    to: BeadStatus::Ready,
+                    at: 10
+                },
+                StatusTransition {
+                    from: BeadStatus::Ready,
+                    to: BeadStatus::Running,
+                    at: 20
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_time_in_status_sums_intervals() {
+        let mut bead = Bead::new("bead-13", "Timed").with_status(BeadStatus::Pending);
+        bead.transition_to(BeadStatus::Running, 0);
+        bead.transition_to(BeadStatus::Failed, 5);
+        bead.transition_to(BeadStatus::Pending, 7);
+        bead.transition_to(BeadStatus::Running, 8);
+
+        // Two separate Running intervals: [0, 5) and [8, now).
+        assert_eq!(bead.time_in_status(BeadStatus::Running, 20), 5 + 12);
+        assert_eq!(bead.time_in_status(BeadStatus::Failed, 20), 2);
+        assert_eq!(bead.time_in_status(BeadStatus::Completed, 20), 0);
+    }
+
+    #[test]
+    fn test_time_in_status_with_no_transitions_is_zero() {
+        let bead = Bead::new("bead-14", "Untouched");
+        assert_eq!(bead.time_in_status(BeadStatus::Pending, 100), 0);
+    }
+
+    #[test]
+    fn test_bead_error_codes_are_stable() {
+        assert_eq!(BeadError::InvalidDependency.code(), "invalid-dependency");
+        assert_eq!(BeadError::CommandTimeout.code(), "command-timeout");
+        assert_eq!(
+            BeadError::FailedExternalValidation.code(),
+            "failed-external-validation"
+        );
+    }
+
+    #[test]
+    fn test_record_failure_retries_then_fails() {
+        let mut bead = Bead::new("b", "Flaky").with_max_retries(2);
+
+        bead.record_failure(BeadError::CommandTimeout);
+        assert_eq!(bead.retry_count, 1);
+        assert_eq!(bead.status, BeadStatus::Pending);
+        assert!(bead.is_retrying());
+        assert_eq!(bead.last_error, Some(BeadError::CommandTimeout));
+        assert!(bead.next_retry_at.is_some());
+
+        bead.record_failure(BeadError::CommandTimeout);
+        assert_eq!(bead.retry_count, 2);
+        assert_eq!(bead.status, BeadStatus::Failed);
+        assert!(!bead.is_retrying());
+    }
+
+    #[test]
+    fn test_record_failure_backoff_is_capped() {
+        let mut bead = Bead::new("b", "Flaky").with_max_retries(100);
+        for _ in 0..20 {
+            bead.record_failure(BeadError::CommandTimeout);
+        }
+        assert_eq!(bead.next_retry_at, Some(300));
+    }
+
+    #[test]
+    fn test_bead_deserialization_defaults_retry_fields() -> Result<(), Box<dyn std::error::Error>> {
+        // Legacy payloads without retry fields still deserialize.
+        let json = r#"{
+            "id": "b", "title": "t", "description": "",
+            "status": "pending", "priority": "medium",
+            "dependencies": [], "tags": [],
+            "created_at": "x", "updated_at": "x"
+        }"#;
+        let bead: Bead = serde_json::from_str(json)?;
+        assert_eq!(bead.retry_count, 0);
+        assert_eq!(bead.max_retries, 3);
+        assert_eq!(bead.last_error, None);
+        Ok(())
+    }
+
     #[test]
     fn test_status_is_terminal() {
         assert!(!BeadStatus::Pending.is_terminal());
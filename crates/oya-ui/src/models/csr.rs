@@ -0,0 +1,206 @@
+//! Compressed sparse row (CSR) adjacency index for fast neighbor/degree queries
+
+use super::edge::{Edge, EdgeType};
+use super::node::NodeId;
+use std::collections::HashMap;
+
+/// A single outgoing adjacency entry: the target node and the edge type that
+/// connects to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Neighbor<'a> {
+    /// The target node of the adjacency
+    pub target: &'a NodeId,
+    /// The type of edge connecting to the target
+    pub edge_type: EdgeType,
+}
+
+/// A compressed sparse row representation of a directed graph.
+///
+/// Repeatedly scanning a `Vec<Edge>` to find a node's neighbors is `O(|E|)` per
+/// query. `CsrGraph` maps each string [`NodeId`] to a compact integer index and
+/// stores adjacency as two flat arrays:
+///
+/// * `column_indices[row_offsets[i]..row_offsets[i + 1]]` are the target
+///   indices of node `i`'s outgoing edges, and
+/// * `edge_types` runs parallel to `column_indices` so callers can filter by
+///   [`EdgeType`] without a second lookup.
+///
+/// This gives `O(degree)` neighbor iteration and `O(1)` degree queries over an
+/// `O(|V| + |E|)`-space, cache-friendly structure.
+#[derive(Debug, Clone)]
+pub struct CsrGraph {
+    /// index -> `NodeId` (reverse table)
+    node_ids: Vec<NodeId>,
+    /// `NodeId` string -> compact index
+    index_of: HashMap<String, usize>,
+    /// length `n + 1`; node `i`'s edges live in `[row_offsets[i], row_offsets[i + 1])`
+    row_offsets: Vec<usize>,
+    /// target node indices, grouped by source
+    column_indices: Vec<usize>,
+    /// edge type for each entry in `column_indices`
+    edge_types: Vec<EdgeType>,
+}
+
+impl CsrGraph {
+    /// Builds a CSR index from the given node set and edge list.
+    ///
+    /// # Errors
+    /// Returns an error if any edge references a source or target node that is
+    /// not present in `nodes`.
+    pub fn build(nodes: &[NodeId], edges: &[Edge]) -> Result<Self, String> {
+        let n = nodes.len();
+        let mut index_of: HashMap<String, usize> = HashMap::with_capacity(n);
+        for (i, node) in nodes.iter().enumerate() {
+            index_of.insert(node.as_str().to_string(), i);
+        }
+
+        // First pass: per-source out-degree counts.
+        let mut degrees = vec![0usize; n];
+        for edge in edges {
+            let src = *index_of
+                .get(edge.source().as_str())
+                .ok_or_else(|| format!("Unknown source node: {}", edge.source().as_str()))?;
+            if !index_of.contains_key(edge.target().as_str()) {
+                return Err(format!("Unknown target node: {}", edge.target().as_str()));
+            }
+            degrees[src] += 1;
+        }
+
+        // Prefix sum into row offsets (length n + 1).
+        let mut row_offsets = vec![0usize; n + 1];
+        for i in 0..n {
+            row_offsets[i + 1] = row_offsets[i] + degrees[i];
+        }
+
+        // Second pass: scatter targets into their source's slice.
+        let total = row_offsets[n];
+        let mut column_indices = vec![0usize; total];
+        let mut edge_types = vec![EdgeType::Dependency; total];
+        let mut cursor = row_offsets.clone();
+        for edge in edges {
+            let src = index_of[edge.source().as_str()];
+            let dst = index_of[edge.target().as_str()];
+            let slot = cursor[src];
+            column_indices[slot] = dst;
+            edge_types[slot] = edge.edge_type();
+            cursor[src] += 1;
+        }
+
+        Ok(Self {
+            node_ids: nodes.to_vec(),
+            index_of,
+            row_offsets,
+            column_indices,
+            edge_types,
+        })
+    }
+
+    /// Returns the number of nodes in the graph
+    pub fn node_count(&self) -> usize {
+        self.node_ids.len()
+    }
+
+    /// Returns the total number of directed edges in the graph
+    pub fn edge_count(&self) -> usize {
+        self.column_indices.len()
+    }
+
+    /// Returns the out-degree of `node`, or `None` if it is not in the graph
+    pub fn out_degree(&self, node: &NodeId) -> Option<usize> {
+        let i = *self.index_of.get(node.as_str())?;
+        Some(self.row_offsets[i + 1] - self.row_offsets[i])
+    }
+
+    /// Iterates over the outgoing neighbors of `node`, preserving edge type.
+    ///
+    /// Returns `None` if `node` is not part of the graph. The iterator itself
+    /// runs in `O(degree)`.
+    pub fn neighbors(&self, node: &NodeId) -> Option<impl Iterator<Item = Neighbor<'_>>> {
+        let i = *self.index_of.get(node.as_str())?;
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        Some(range.map(move |slot| Neighbor {
+            target: &self.node_ids[self.column_indices[slot]],
+            edge_type: self.edge_types[slot],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nodes(ids: &[&str]) -> Result<Vec<NodeId>, String> {
+        ids.iter().map(|id| NodeId::new(*id)).collect()
+    }
+
+    #[test]
+    fn test_build_empty() -> Result<(), String> {
+        let csr = CsrGraph::build(&[], &[])?;
+        assert_eq!(csr.node_count(), 0);
+        assert_eq!(csr.edge_count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbors_and_degree() -> Result<(), String> {
+        let ns = nodes(&["a", "b", "c"])?;
+        let edges = vec![
+            Edge::new(ns[0].clone(), ns[1].clone(), EdgeType::Dependency)?,
+            Edge::new(ns[0].clone(), ns[2].clone(), EdgeType::DataFlow)?,
+            Edge::new(ns[1].clone(), ns[2].clone(), EdgeType::Dependency)?,
+        ];
+        let csr = CsrGraph::build(&ns, &edges)?;
+
+        assert_eq!(csr.edge_count(), 3);
+        assert_eq!(csr.out_degree(&ns[0]), Some(2));
+        assert_eq!(csr.out_degree(&ns[1]), Some(1));
+        assert_eq!(csr.out_degree(&ns[2]), Some(0));
+
+        let mut targets: Vec<&str> = csr
+            .neighbors(&ns[0])
+            .ok_or("node a should exist")?
+            .map(|n| n.target.as_str())
+            .collect();
+        targets.sort_unstable();
+        assert_eq!(targets, vec!["b", "c"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbors_preserve_edge_type() -> Result<(), String> {
+        let ns = nodes(&["a", "b", "c"])?;
+        let edges = vec![
+            Edge::new(ns[0].clone(), ns[1].clone(), EdgeType::Dependency)?,
+            Edge::new(ns[0].clone(), ns[2].clone(), EdgeType::Trigger)?,
+        ];
+        let csr = CsrGraph::build(&ns, &edges)?;
+
+        let deps: Vec<&str> = csr
+            .neighbors(&ns[0])
+            .ok_or("node a should exist")?
+            .filter(|n| n.edge_type == EdgeType::Dependency)
+            .map(|n| n.target.as_str())
+            .collect();
+        assert_eq!(deps, vec!["b"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_node_queries_return_none() -> Result<(), String> {
+        let ns = nodes(&["a"])?;
+        let csr = CsrGraph::build(&ns, &[])?;
+        let missing = NodeId::new("ghost")?;
+        assert_eq!(csr.out_degree(&missing), None);
+        assert!(csr.neighbors(&missing).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_rejects_unknown_endpoint() -> Result<(), String> {
+        let ns = nodes(&["a"])?;
+        let ghost = NodeId::new("ghost")?;
+        let edges = vec![Edge::new(ns[0].clone(), ghost, EdgeType::Dependency)?];
+        assert!(CsrGraph::build(&ns, &edges).is_err());
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 //! Edge data structure for graph visualization
 
-use super::node::NodeId;
+use super::node::{Node, NodeId};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 /// Type of edge relationship
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -41,6 +42,12 @@ pub struct Edge {
     style: EdgeStyle,
     state: EdgeState,
     label: Option<String>,
+    /// Arbitrary set of boolean flags attached to the edge
+    #[serde(default)]
+    flags: BTreeSet<String>,
+    /// Arbitrary typed metadata attached to the edge
+    #[serde(default)]
+    attributes: BTreeMap<String, String>,
 }
 
 impl Edge {
@@ -61,6 +68,8 @@ impl Edge {
             style: EdgeStyle::default(),
             state: EdgeState::default(),
             label: None,
+            flags: BTreeSet::new(),
+            attributes: BTreeMap::new(),
         })
     }
 
@@ -86,6 +95,8 @@ impl Edge {
             style: EdgeStyle::default(),
             state: EdgeState::default(),
             label,
+            flags: BTreeSet::new(),
+            attributes: BTreeMap::new(),
         })
     }
 
@@ -143,6 +154,279 @@ impl Edge {
     pub fn has_label(&self) -> bool {
         self.label.is_some()
     }
+
+    /// Adds a boolean flag to the edge
+    pub fn add_flag(&mut self, flag: impl Into<String>) {
+        self.flags.insert(flag.into());
+    }
+
+    /// Removes a flag from the edge, returning whether it was present
+    pub fn remove_flag(&mut self, flag: &str) -> bool {
+        self.flags.remove(flag)
+    }
+
+    /// Checks whether the edge carries the given flag
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /// Iterates over the flags attached to the edge
+    pub fn flags(&self) -> impl Iterator<Item = &str> {
+        self.flags.iter().map(String::as_str)
+    }
+
+    /// Sets a key/value attribute on the edge, returning the previous value if any
+    pub fn set_attribute(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Option<String> {
+        self.attributes.insert(key.into(), value.into())
+    }
+
+    /// Returns the value of an attribute, if set
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+
+    /// Removes an attribute, returning its previous value if any
+    pub fn remove_attribute(&mut self, key: &str) -> Option<String> {
+        self.attributes.remove(key)
+    }
+
+    /// Iterates over the key/value attributes attached to the edge
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attributes
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// An owning container for edges that permits parallel edges between the same
+/// pair of nodes as long as their [`EdgeType`] differs.
+///
+/// A `Vec<Edge>` on its own cannot answer "give me every `Dependency` edge
+/// leaving this node" without a full scan. `EdgeGraph` keeps a per-source
+/// adjacency index so those queries run in `O(degree)`, while still rejecting
+/// true self-loops and exact duplicate `(source, target, edge_type)` triples.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "EdgeGraphData", into = "EdgeGraphData")]
+pub struct EdgeGraph {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    /// source `NodeId` string -> indices into `edges`; rebuilt from the edge
+    /// list on deserialize so it is never part of the wire format.
+    by_source: HashMap<String, Vec<usize>>,
+}
+
+/// Serde proxy for [`EdgeGraph`]: only nodes and edges are written; the
+/// adjacency index is rebuilt on load.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct EdgeGraphData {
+    #[serde(default)]
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+impl From<EdgeGraphData> for EdgeGraph {
+    fn from(data: EdgeGraphData) -> Self {
+        let mut by_source: HashMap<String, Vec<usize>> = HashMap::new();
+        for (index, edge) in data.edges.iter().enumerate() {
+            by_source
+                .entry(edge.source().as_str().to_string())
+                .or_default()
+                .push(index);
+        }
+        Self {
+            nodes: data.nodes,
+            edges: data.edges,
+            by_source,
+        }
+    }
+}
+
+impl From<EdgeGraph> for EdgeGraphData {
+    fn from(graph: EdgeGraph) -> Self {
+        Self {
+            nodes: graph.nodes,
+            edges: graph.edges,
+        }
+    }
+}
+
+impl EdgeGraph {
+    /// Creates a new empty edge graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node to the graph
+    pub fn add_node(&mut self, node: Node) {
+        self.nodes.push(node);
+    }
+
+    /// Returns all nodes in the graph
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Returns the number of nodes in the graph
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Adds an edge to the graph.
+    ///
+    /// # Errors
+    /// Returns an error if an edge with the same source, target and
+    /// [`EdgeType`] already exists (an exact parallel duplicate). Parallel
+    /// edges of a *different* type between the same endpoints are allowed.
+    pub fn add_edge(&mut self, edge: Edge) -> Result<(), String> {
+        if self.edges.iter().any(|e| {
+            e.source() == edge.source()
+                && e.target() == edge.target()
+                && e.edge_type() == edge.edge_type()
+        }) {
+            return Err("Duplicate edge of the same type between these nodes".to_string());
+        }
+
+        let index = self.edges.len();
+        self.by_source
+            .entry(edge.source().as_str().to_string())
+            .or_default()
+            .push(index);
+        self.edges.push(edge);
+        Ok(())
+    }
+
+    /// Returns all edges in the graph
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the number of edges in the graph
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns every edge leaving `source`, regardless of type
+    pub fn outgoing_all(&self, source: &NodeId) -> Vec<&Edge> {
+        self.by_source
+            .get(source.as_str())
+            .map(|indices| indices.iter().map(|&i| &self.edges[i]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns every edge of the given [`EdgeType`] leaving `source`
+    pub fn outgoing(&self, source: &NodeId, edge_type: EdgeType) -> Vec<&Edge> {
+        self.by_source
+            .get(source.as_str())
+            .map(|indices| {
+                indices
+                    .iter()
+                    .map(|&i| &self.edges[i])
+                    .filter(|e| e.edge_type() == edge_type)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// A stable key capturing everything that makes an edge structurally
+    /// distinct, used for order-independent comparison.
+    fn edge_key(edge: &Edge) -> (String, String, EdgeType, EdgeStyle, EdgeState, Option<String>) {
+        (
+            edge.source().as_str().to_string(),
+            edge.target().as_str().to_string(),
+            edge.edge_type(),
+            edge.style(),
+            edge.state(),
+            edge.label().map(str::to_string),
+        )
+    }
+}
+
+/// Asserts that two graphs are structurally equivalent, ignoring insertion
+/// order.
+///
+/// Two graphs are equal when they have the same number of nodes and edges,
+/// the same set of node ids and labels, and the same multiset of edges where
+/// each edge is compared on its `(source, target, edge_type, style, state,
+/// label)` endpoints together with its flags and attributes.
+///
+/// # Errors
+/// Returns a human-readable description of the first difference found.
+pub fn assert_graph_eq(left: &EdgeGraph, right: &EdgeGraph) -> Result<(), String> {
+    if left.node_count() != right.node_count() {
+        return Err(format!(
+            "node count mismatch: {} != {}",
+            left.node_count(),
+            right.node_count()
+        ));
+    }
+    if left.edge_count() != right.edge_count() {
+        return Err(format!(
+            "edge count mismatch: {} != {}",
+            left.edge_count(),
+            right.edge_count()
+        ));
+    }
+
+    let mut left_nodes: Vec<(&str, &str)> = left
+        .nodes()
+        .iter()
+        .map(|n| (n.id().as_str(), n.label()))
+        .collect();
+    let mut right_nodes: Vec<(&str, &str)> = right
+        .nodes()
+        .iter()
+        .map(|n| (n.id().as_str(), n.label()))
+        .collect();
+    left_nodes.sort_unstable();
+    right_nodes.sort_unstable();
+    if left_nodes != right_nodes {
+        return Err("node id/label sets differ".to_string());
+    }
+
+    // Compare edge keys as a multiset, independent of order.
+    let mut left_edges: Vec<_> = left.edges().iter().map(EdgeGraph::edge_key).collect();
+    let mut right_edges: Vec<_> = right.edges().iter().map(EdgeGraph::edge_key).collect();
+    left_edges.sort();
+    right_edges.sort();
+    if left_edges != right_edges {
+        return Err("edge sets differ".to_string());
+    }
+
+    // Flags/attributes are not in `edge_key`; verify them by comparing the full
+    // sorted edge vectors, which derive `PartialEq`.
+    let mut left_full: Vec<&Edge> = left.edges().iter().collect();
+    let mut right_full: Vec<&Edge> = right.edges().iter().collect();
+    left_full.sort_by_cached_key(|e| EdgeGraph::edge_key(e));
+    right_full.sort_by_cached_key(|e| EdgeGraph::edge_key(e));
+    if left_full != right_full {
+        return Err("edge flags/attributes differ".to_string());
+    }
+
+    Ok(())
+}
+
+/// Serializes a graph to JSON and reconstructs it, returning the round-tripped
+/// graph.
+///
+/// # Errors
+/// Returns an error if serialization or deserialization fails.
+pub fn roundtrip_json(graph: &EdgeGraph) -> Result<EdgeGraph, String> {
+    let json = serde_json::to_string(graph).map_err(|e| e.to_string())?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+/// Serializes a graph to a compact binary format (bincode) and reconstructs
+/// it, returning the round-tripped graph.
+///
+/// # Errors
+/// Returns an error if serialization or deserialization fails.
+pub fn roundtrip_binary(graph: &EdgeGraph) -> Result<EdgeGraph, String> {
+    let bytes = bincode::serialize(graph).map_err(|e| e.to_string())?;
+    bincode::deserialize(&bytes).map_err(|e| e.to_string())
 }
 
 #[cfg(test)]
@@ -508,4 +792,180 @@ mod tests {
         assert_eq!(err, "Edge cannot reference itself");
         Ok(())
     }
+
+    #[test]
+    fn test_edge_flags() -> Result<(), String> {
+        let source = NodeId::new("n1")?;
+        let target = NodeId::new("n2")?;
+        let mut edge = Edge::new(source, target, EdgeType::Dependency)?;
+
+        assert!(!edge.has_flag("critical"));
+        edge.add_flag("critical");
+        assert!(edge.has_flag("critical"));
+
+        // Adding the same flag twice is idempotent
+        edge.add_flag("critical");
+        assert_eq!(edge.flags().count(), 1);
+
+        assert!(edge.remove_flag("critical"));
+        assert!(!edge.has_flag("critical"));
+        assert!(!edge.remove_flag("critical"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_attributes() -> Result<(), String> {
+        let source = NodeId::new("n1")?;
+        let target = NodeId::new("n2")?;
+        let mut edge = Edge::new(source, target, EdgeType::DataFlow)?;
+
+        assert_eq!(edge.attribute("weight"), None);
+        assert_eq!(edge.set_attribute("weight", "5"), None);
+        assert_eq!(edge.attribute("weight"), Some("5"));
+
+        // Overwriting returns the previous value
+        assert_eq!(edge.set_attribute("weight", "7"), Some("5".to_string()));
+        assert_eq!(edge.attribute("weight"), Some("7"));
+
+        assert_eq!(edge.remove_attribute("weight"), Some("7".to_string()));
+        assert_eq!(edge.attribute("weight"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_flags_and_attributes_roundtrip() -> Result<(), String> {
+        let source = NodeId::new("source")?;
+        let target = NodeId::new("target")?;
+        let mut edge = Edge::new(source, target, EdgeType::Trigger)?;
+        edge.add_flag("async");
+        edge.set_attribute("channel", "events");
+
+        let json = serde_json::to_string(&edge).map_err(|e| e.to_string())?;
+        let deserialized: Edge = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+
+        assert!(deserialized.has_flag("async"));
+        assert_eq!(deserialized.attribute("channel"), Some("events"));
+        assert_eq!(edge, deserialized);
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_graph_allows_parallel_edges_of_differing_type() -> Result<(), String> {
+        let a = NodeId::new("a")?;
+        let b = NodeId::new("b")?;
+        let mut graph = EdgeGraph::new();
+
+        graph.add_edge(Edge::new(a.clone(), b.clone(), EdgeType::Dependency)?)?;
+        graph.add_edge(Edge::new(a.clone(), b.clone(), EdgeType::DataFlow)?)?;
+
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.outgoing_all(&a).len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_graph_rejects_duplicate_of_same_type() -> Result<(), String> {
+        let a = NodeId::new("a")?;
+        let b = NodeId::new("b")?;
+        let mut graph = EdgeGraph::new();
+
+        graph.add_edge(Edge::new(a.clone(), b.clone(), EdgeType::Dependency)?)?;
+        let err = graph
+            .add_edge(Edge::new(a, b, EdgeType::Dependency)?)
+            .err()
+            .ok_or("Expected duplicate edge to be rejected")?;
+        assert!(err.contains("Duplicate edge"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_graph_outgoing_by_type() -> Result<(), String> {
+        let a = NodeId::new("a")?;
+        let b = NodeId::new("b")?;
+        let c = NodeId::new("c")?;
+        let mut graph = EdgeGraph::new();
+
+        graph.add_edge(Edge::new(a.clone(), b.clone(), EdgeType::Dependency)?)?;
+        graph.add_edge(Edge::new(a.clone(), c.clone(), EdgeType::DataFlow)?)?;
+        graph.add_edge(Edge::new(a.clone(), c, EdgeType::Dependency)?)?;
+
+        let deps = graph.outgoing(&a, EdgeType::Dependency);
+        assert_eq!(deps.len(), 2);
+        let flows = graph.outgoing(&a, EdgeType::DataFlow);
+        assert_eq!(flows.len(), 1);
+        assert!(graph.outgoing(&b, EdgeType::Dependency).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_graph_serialization_rebuilds_index() -> Result<(), String> {
+        let a = NodeId::new("a")?;
+        let b = NodeId::new("b")?;
+        let mut graph = EdgeGraph::new();
+        graph.add_edge(Edge::new(a.clone(), b.clone(), EdgeType::Dependency)?)?;
+        graph.add_edge(Edge::new(a.clone(), b, EdgeType::DataFlow)?)?;
+
+        let json = serde_json::to_string(&graph).map_err(|e| e.to_string())?;
+        let restored: EdgeGraph = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+
+        // The index must be rebuilt so queries still work after a round-trip.
+        assert_eq!(restored.edge_count(), 2);
+        assert_eq!(restored.outgoing(&a, EdgeType::DataFlow).len(), 1);
+        Ok(())
+    }
+
+    fn sample_graph() -> Result<EdgeGraph, String> {
+        let a = NodeId::new("a")?;
+        let b = NodeId::new("b")?;
+        let mut graph = EdgeGraph::new();
+        graph.add_node(Node::new("a", "Node A")?);
+        graph.add_node(Node::new("b", "Node B")?);
+        let mut dep = Edge::new(a.clone(), b.clone(), EdgeType::Dependency)?;
+        dep.add_flag("critical");
+        dep.set_attribute("weight", "3");
+        graph.add_edge(dep)?;
+        graph.add_edge(Edge::new(a, b, EdgeType::DataFlow)?)?;
+        Ok(graph)
+    }
+
+    #[test]
+    fn test_assert_graph_eq_ignores_order() -> Result<(), String> {
+        let a = NodeId::new("a")?;
+        let b = NodeId::new("b")?;
+
+        let mut g1 = EdgeGraph::new();
+        g1.add_edge(Edge::new(a.clone(), b.clone(), EdgeType::Dependency)?)?;
+        g1.add_edge(Edge::new(a.clone(), b.clone(), EdgeType::DataFlow)?)?;
+
+        let mut g2 = EdgeGraph::new();
+        g2.add_edge(Edge::new(a.clone(), b.clone(), EdgeType::DataFlow)?)?;
+        g2.add_edge(Edge::new(a, b, EdgeType::Dependency)?)?;
+
+        assert_graph_eq(&g1, &g2)
+    }
+
+    #[test]
+    fn test_assert_graph_eq_detects_difference() -> Result<(), String> {
+        let a = NodeId::new("a")?;
+        let b = NodeId::new("b")?;
+        let mut g1 = EdgeGraph::new();
+        g1.add_edge(Edge::new(a.clone(), b.clone(), EdgeType::Dependency)?)?;
+        let g2 = EdgeGraph::new();
+        assert!(assert_graph_eq(&g1, &g2).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_roundtrip_is_lossless() -> Result<(), String> {
+        let graph = sample_graph()?;
+        let restored = roundtrip_json(&graph)?;
+        assert_graph_eq(&graph, &restored)
+    }
+
+    #[test]
+    fn test_binary_roundtrip_is_lossless() -> Result<(), String> {
+        let graph = sample_graph()?;
+        let restored = roundtrip_binary(&graph)?;
+        assert_graph_eq(&graph, &restored)
+    }
 }
@@ -3,7 +3,7 @@
 //! This module provides realistic sample data for all UI components,
 //! enabling consistent development and testing without backend dependency.
 
-use super::bead::{Bead, BeadPriority, BeadStatus};
+use super::bead::{Bead, BeadError, BeadPriority, BeadStatus};
 use super::task::{Task, TaskPriority, TaskStatus, TaskType};
 use super::{Graph, GraphEdge, GraphNode};
 
@@ -105,6 +105,12 @@ pub fn mock_beads() -> Vec<Bead> {
             .with_status(BeadStatus::Cancelled)
             .with_priority(BeadPriority::Low)
             .with_tags(vec!["backend".into(), "scheduler".into()]),
+        Bead::new("src-yz012", "Sync workspace snapshots")
+            .with_description("Periodic snapshot of workspace state to durable storage")
+            .with_priority(BeadPriority::Medium)
+            .with_dependency("src-def34")
+            .with_tags(vec!["backend".into(), "storage".into()])
+            .with_failure(BeadError::CommandTimeout),
     ]
 }
 
@@ -112,23 +118,24 @@ pub fn mock_beads() -> Vec<Bead> {
 pub fn mock_graph() -> Graph {
     let mut graph = Graph::new();
 
-    // Add nodes representing beads
+    // Add nodes representing beads. Positions are computed below by
+    // `layout_layered`, so only colors are assigned here.
     let nodes = vec![
-        ("src-abc12", "Event Sourcing", 100.0, 50.0, "#10b981"),
-        ("src-def34", "DAG Orchestrator", 250.0, 100.0, "#f59e0b"),
-        ("src-ghi56", "REST API", 250.0, 200.0, "#f59e0b"),
-        ("src-jkl78", "WebSocket", 400.0, 150.0, "#3b82f6"),
-        ("src-mno90", "UI Dashboard", 550.0, 150.0, "#9ca3af"),
-        ("src-pqr12", "Graph Viz", 700.0, 150.0, "#9ca3af"),
-        ("bug-stu34", "Bug Fix", 100.0, 250.0, "#ef4444"),
+        ("src-abc12", "Event Sourcing", "#10b981"),
+        ("src-def34", "DAG Orchestrator", "#f59e0b"),
+        ("src-ghi56", "REST API", "#f59e0b"),
+        ("src-jkl78", "WebSocket", "#3b82f6"),
+        ("src-mno90", "UI Dashboard", "#9ca3af"),
+        ("src-pqr12", "Graph Viz", "#9ca3af"),
+        ("bug-stu34", "Bug Fix", "#ef4444"),
     ];
 
-    for (id, label, x, y, color) in nodes {
+    for (id, label, color) in nodes {
         graph.add_node(GraphNode {
             id: id.to_string(),
             label: label.to_string(),
-            x,
-            y,
+            x: 0.0,
+            y: 0.0,
             color: Some(color.to_string()),
         });
     }
@@ -151,6 +158,7 @@ pub fn mock_graph() -> Graph {
         });
     }
 
+    graph.layout_layered();
     graph
 }
 
@@ -163,6 +171,10 @@ pub struct StatusSummary {
     pub completed: usize,
     pub failed: usize,
     pub cancelled: usize,
+    /// Beads that have failed at least once but still have retries left. This
+    /// overlaps the non-terminal buckets above (a retrying bead is also
+    /// `Pending`), so it is excluded from `total`/`active`/`terminal`.
+    pub retrying: usize,
 }
 
 impl StatusSummary {
@@ -178,6 +190,9 @@ impl StatusSummary {
                 BeadStatus::Failed => summary.failed += 1,
                 BeadStatus::Cancelled => summary.cancelled += 1,
             }
+            if bead.is_retrying() {
+                summary.retrying += 1;
+            }
         }
         summary
     }
@@ -226,6 +241,89 @@ impl TaskSummary {
     }
 }
 
+/// Per-tag status breakdown, for dashboard widgets that split counts by
+/// `"backend"`/`"frontend"`-style tags instead of showing one global total. A
+/// bead with multiple tags is counted under each of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BeadMetrics {
+    pub by_tag: std::collections::BTreeMap<String, StatusSummary>,
+}
+
+impl BeadMetrics {
+    /// Computes the per-tag status breakdown from `beads`.
+    pub fn from_beads(beads: &[Bead]) -> Self {
+        let mut by_tag: std::collections::BTreeMap<String, StatusSummary> =
+            std::collections::BTreeMap::new();
+        for bead in beads {
+            for tag in &bead.tags {
+                let summary = by_tag.entry(tag.clone()).or_default();
+                match bead.status {
+                    BeadStatus::Pending => summary.pending += 1,
+                    BeadStatus::Ready => summary.ready += 1,
+                    BeadStatus::Running => summary.running += 1,
+                    BeadStatus::Completed => summary.completed += 1,
+                    BeadStatus::Failed => summary.failed += 1,
+                    BeadStatus::Cancelled => summary.cancelled += 1,
+                }
+                if bead.is_retrying() {
+                    summary.retrying += 1;
+                }
+            }
+        }
+        Self { by_tag }
+    }
+}
+
+/// Time from a bead's first recorded transition to the one that brought it
+/// to `Completed`, or `None` if it never completed (or has no transitions to
+/// measure from).
+fn time_to_completion(bead: &Bead) -> Option<u64> {
+    let first = bead.transitions.first()?;
+    let completed = bead
+        .transitions
+        .iter()
+        .find(|t| t.to == BeadStatus::Completed)?;
+    Some(completed.at.saturating_sub(first.at))
+}
+
+/// Mean [`time_to_completion`] across `beads` that have completed, or `None`
+/// if none have.
+pub fn mean_time_to_completion(beads: &[Bead]) -> Option<f64> {
+    mean(beads.iter().filter_map(time_to_completion))
+}
+
+/// Time a bead spent in `Running` immediately before transitioning to
+/// `Failed`, or `None` if it never failed directly out of `Running`.
+fn time_running_before_failed(bead: &Bead) -> Option<u64> {
+    let failed_index = bead
+        .transitions
+        .iter()
+        .position(|t| t.from == BeadStatus::Running && t.to == BeadStatus::Failed)?;
+    let failed_at = bead.transitions[failed_index].at;
+    let running_started_at = bead.transitions[..failed_index]
+        .iter()
+        .rev()
+        .find(|t| t.to == BeadStatus::Running)?
+        .at;
+    Some(failed_at.saturating_sub(running_started_at))
+}
+
+/// Mean [`time_running_before_failed`] across `beads` that failed directly
+/// out of `Running`, or `None` if none did. Helps spot beads thrashing
+/// between `Running` and `Failed`.
+pub fn mean_time_running_before_failed(beads: &[Bead]) -> Option<f64> {
+    mean(beads.iter().filter_map(time_running_before_failed))
+}
+
+fn mean(durations: impl Iterator<Item = u64>) -> Option<f64> {
+    let (sum, count) = durations.fold((0u64, 0usize), |(sum, count), d| (sum + d, count + 1));
+    if count == 0 {
+        None
+    } else {
+        Some(sum as f64 / count as f64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -343,4 +441,82 @@ mod tests {
         let bead_ids: std::collections::HashSet<_> = beads.iter().map(|b| &b.id).collect();
         assert_eq!(bead_ids.len(), beads.len(), "Bead IDs must be unique");
     }
+
+    #[test]
+    fn test_bead_metrics_breaks_down_by_tag() {
+        let beads = vec![
+            Bead::new("a", "A")
+                .with_status(BeadStatus::Running)
+                .with_tag("backend"),
+            Bead::new("b", "B")
+                .with_status(BeadStatus::Completed)
+                .with_tag("backend"),
+            Bead::new("c", "C")
+                .with_status(BeadStatus::Pending)
+                .with_tag("frontend"),
+        ];
+
+        let metrics = BeadMetrics::from_beads(&beads);
+
+        let backend = metrics.by_tag.get("backend").expect("backend tag present");
+        assert_eq!(backend.running, 1);
+        assert_eq!(backend.completed, 1);
+
+        let frontend = metrics.by_tag.get("frontend").expect("frontend tag present");
+        assert_eq!(frontend.pending, 1);
+    }
+
+    #[test]
+    fn test_bead_metrics_counts_bead_under_every_tag() {
+        let beads = vec![
+            Bead::new("a", "A")
+                .with_status(BeadStatus::Running)
+                .with_tags(vec!["backend".into(), "urgent".into()]),
+        ];
+
+        let metrics = BeadMetrics::from_beads(&beads);
+        assert_eq!(metrics.by_tag.len(), 2);
+        assert_eq!(metrics.by_tag["backend"].running, 1);
+        assert_eq!(metrics.by_tag["urgent"].running, 1);
+    }
+
+    #[test]
+    fn test_mean_time_to_completion() {
+        let mut completed = Bead::new("a", "A").with_status(BeadStatus::Pending);
+        completed.transition_to(BeadStatus::Running, 0);
+        completed.transition_to(BeadStatus::Completed, 10);
+
+        let mut still_running = Bead::new("b", "B").with_status(BeadStatus::Pending);
+        still_running.transition_to(BeadStatus::Running, 0);
+
+        assert_eq!(mean_time_to_completion(&[completed.clone()]), Some(10.0));
+        assert_eq!(
+            mean_time_to_completion(&[completed, still_running]),
+            Some(10.0)
+        );
+    }
+
+    #[test]
+    fn test_mean_time_to_completion_none_when_nothing_completed() {
+        let bead = Bead::new("a", "A").with_status(BeadStatus::Pending);
+        assert_eq!(mean_time_to_completion(&[bead]), None);
+    }
+
+    #[test]
+    fn test_mean_time_running_before_failed() {
+        let mut bead = Bead::new("a", "A").with_status(BeadStatus::Pending);
+        bead.transition_to(BeadStatus::Running, 0);
+        bead.transition_to(BeadStatus::Failed, 7);
+
+        assert_eq!(mean_time_running_before_failed(&[bead]), Some(7.0));
+    }
+
+    #[test]
+    fn test_mean_time_running_before_failed_ignores_other_failure_paths() {
+        // Fails directly from Pending, never having been Running.
+        let mut bead = Bead::new("a", "A").with_status(BeadStatus::Pending);
+        bead.transition_to(BeadStatus::Failed, 5);
+
+        assert_eq!(mean_time_running_before_failed(&[bead]), None);
+    }
 }
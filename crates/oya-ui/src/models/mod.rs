@@ -1,11 +1,15 @@
 //! Data models for graph visualization
 
 pub mod colors;
+pub mod csr;
 pub mod edge;
 pub mod node;
+pub mod query;
+pub mod scheduler;
 pub mod task;
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Represents a node in the dependency graph
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -50,6 +54,151 @@ impl Graph {
     pub fn add_edge(&mut self, edge: GraphEdge) {
         self.edges.push(edge);
     }
+
+    /// Assign node positions automatically with a Sugiyama-style layered layout.
+    ///
+    /// Positions are derived purely from the edge set, so callers no longer
+    /// need to hardcode coordinates:
+    ///
+    /// 1. Nodes are topologically ordered (Kahn's algorithm). Back-edges that
+    ///    would form a cycle are skipped so layout always terminates.
+    /// 2. Each node's layer is the longest path from any root, giving the `x`
+    ///    coordinate (`layer × horizontal spacing`).
+    /// 3. Within a layer, nodes are spread along `y`, then a few barycenter
+    ///    passes reorder them toward the mean position of their neighbors to
+    ///    reduce edge crossings.
+    ///
+    /// `color` is left untouched.
+    pub fn layout_layered(&mut self) {
+        const HORIZONTAL_SPACING: f64 = 150.0;
+        const VERTICAL_SPACING: f64 = 100.0;
+        const MARGIN: f64 = 50.0;
+        const BARYCENTER_PASSES: usize = 4;
+
+        let n = self.nodes.len();
+        if n == 0 {
+            return;
+        }
+
+        let index: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), i))
+            .collect();
+
+        // Directed adjacency over node indices, skipping dangling edges.
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut indegree = vec![0usize; n];
+        for edge in &self.edges {
+            if let (Some(&u), Some(&v)) =
+                (index.get(edge.source.as_str()), index.get(edge.target.as_str()))
+            {
+                if u == v {
+                    continue;
+                }
+                successors[u].push(v);
+                predecessors[v].push(u);
+                indegree[v] += 1;
+            }
+        }
+
+        // Kahn's algorithm for a topological order; nodes left in a cycle are
+        // appended afterwards with their back-edges effectively broken.
+        let mut remaining = indegree.clone();
+        let mut queue: Vec<usize> = (0..n).filter(|&i| remaining[i] == 0).collect();
+        let mut topo = Vec::with_capacity(n);
+        let mut head = 0;
+        while head < queue.len() {
+            let u = queue[head];
+            head += 1;
+            topo.push(u);
+            for &v in &successors[u] {
+                remaining[v] -= 1;
+                if remaining[v] == 0 {
+                    queue.push(v);
+                }
+            }
+        }
+        if topo.len() < n {
+            let placed: std::collections::HashSet<usize> = topo.iter().copied().collect();
+            for i in 0..n {
+                if !placed.contains(&i) {
+                    topo.push(i);
+                }
+            }
+        }
+
+        // Longest-path layering in topological order.
+        let mut layer = vec![0usize; n];
+        for &u in &topo {
+            for &v in &successors[u] {
+                if layer[u] + 1 > layer[v] {
+                    layer[v] = layer[u] + 1;
+                }
+            }
+        }
+
+        // Group nodes by layer, seeding an initial within-layer order.
+        let max_layer = layer.iter().copied().max().unwrap_or(0);
+        let mut layers: Vec<Vec<usize>> = vec![Vec::new(); max_layer + 1];
+        for i in 0..n {
+            layers[layer[i]].push(i);
+        }
+
+        // Barycenter passes: reorder each layer by the mean row of its
+        // neighbors to pull connected nodes into alignment.
+        let mut row = vec![0f64; n];
+        for (l, nodes_in_layer) in layers.iter().enumerate() {
+            for (row_index, &node) in nodes_in_layer.iter().enumerate() {
+                let _ = l;
+                row[node] = row_index as f64;
+            }
+        }
+        for _ in 0..BARYCENTER_PASSES {
+            for nodes_in_layer in &mut layers {
+                nodes_in_layer.sort_by(|&a, &b| {
+                    let ba = barycenter(a, &predecessors, &successors, &row);
+                    let bb = barycenter(b, &predecessors, &successors, &row);
+                    ba.partial_cmp(&bb).unwrap_or(std::cmp::Ordering::Equal)
+                });
+                for (row_index, &node) in nodes_in_layer.iter().enumerate() {
+                    row[node] = row_index as f64;
+                }
+            }
+        }
+
+        for (l, nodes_in_layer) in layers.iter().enumerate() {
+            let x = MARGIN + (l as f64) * HORIZONTAL_SPACING;
+            for (row_index, &node) in nodes_in_layer.iter().enumerate() {
+                self.nodes[node].x = x;
+                self.nodes[node].y = MARGIN + (row_index as f64) * VERTICAL_SPACING;
+            }
+        }
+    }
+}
+
+/// Mean row of a node's neighbors (both predecessors and successors), used as
+/// the barycenter ordering key; falls back to the node's current row when it
+/// has no neighbors.
+fn barycenter(
+    node: usize,
+    predecessors: &[Vec<usize>],
+    successors: &[Vec<usize>],
+    row: &[f64],
+) -> f64 {
+    let mut sum = 0.0;
+    let mut count = 0.0;
+    for &neighbor in predecessors[node].iter().chain(successors[node].iter()) {
+        sum += row[neighbor];
+        count += 1.0;
+    }
+    if count == 0.0 {
+        row[node]
+    } else {
+        sum / count
+    }
 }
 
 #[cfg(test)]
@@ -88,4 +237,55 @@ mod tests {
         graph.add_edge(edge);
         assert_eq!(graph.edges.len(), 1);
     }
+
+    fn node(id: &str) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            label: id.to_string(),
+            x: 0.0,
+            y: 0.0,
+            color: None,
+        }
+    }
+
+    fn edge(source: &str, target: &str) -> GraphEdge {
+        GraphEdge {
+            source: source.to_string(),
+            target: target.to_string(),
+            weight: None,
+        }
+    }
+
+    #[test]
+    fn test_layout_layered_places_nodes_by_longest_path() {
+        let mut graph = Graph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(node(id));
+        }
+        // a -> b -> d, a -> c -> d. d's layer is the longest path (2).
+        graph.add_edge(edge("a", "b"));
+        graph.add_edge(edge("a", "c"));
+        graph.add_edge(edge("b", "d"));
+        graph.add_edge(edge("c", "d"));
+        graph.layout_layered();
+
+        let x = |id: &str| graph.nodes.iter().find(|n| n.id == id).map(|n| n.x).unwrap();
+        assert!(x("a") < x("b"));
+        assert!(x("b") < x("d"));
+        assert_eq!(x("b"), x("c"));
+    }
+
+    #[test]
+    fn test_layout_layered_terminates_on_cycle() {
+        let mut graph = Graph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_node(node(id));
+        }
+        graph.add_edge(edge("a", "b"));
+        graph.add_edge(edge("b", "c"));
+        graph.add_edge(edge("c", "a")); // back-edge forms a cycle
+        graph.layout_layered();
+        // Every node received a finite position without looping forever.
+        assert!(graph.nodes.iter().all(|n| n.x.is_finite() && n.y.is_finite()));
+    }
 }
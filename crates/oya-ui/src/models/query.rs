@@ -0,0 +1,297 @@
+//! Dependency-aware querying over bead collections
+//!
+//! [`BeadFilters`](super::bead::BeadFilters) answers "does this one bead match?"
+//! for a single status/priority/tag. `BeadQuery` generalizes that to a whole
+//! collection and, crucially, lets a match pull in *related* work by walking the
+//! dependency graph: filtering by `"websocket"` can also surface the upstream
+//! `event-sourcing` bead it depends on.
+
+use super::bead::{Bead, BeadPriority, BeadStatus};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// How a multi-tag filter combines: match beads carrying *any* of the tags, or
+/// only those carrying *all* of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagMatch {
+    /// Match if the bead has at least one of the query tags (default)
+    #[default]
+    Any,
+    /// Match only if the bead has every query tag
+    All,
+}
+
+/// Which direction the dependency expansion walks from each matched bead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Expand {
+    /// Do not expand; the expanded set stays empty
+    None,
+    /// Walk toward dependencies (things a matched bead needs)
+    Ancestors,
+    /// Walk toward dependents (things that need a matched bead)
+    Descendants,
+    /// Walk both directions (default)
+    #[default]
+    Both,
+}
+
+/// The outcome of running a [`BeadQuery`]: the directly-matched beads and the
+/// additional context beads reached by dependency expansion, kept distinct so
+/// the UI can highlight matches versus surrounding context.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryResult {
+    /// Ids of beads that satisfied the status/priority/tag predicates
+    pub matched: Vec<String>,
+    /// Ids pulled in by graph expansion; disjoint from `matched`
+    pub expanded: Vec<String>,
+}
+
+impl QueryResult {
+    /// Every id surfaced by the query, matches first then context.
+    pub fn all(&self) -> Vec<String> {
+        self.matched
+            .iter()
+            .chain(self.expanded.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+/// A composable filter over a bead collection with optional dependency-graph
+/// expansion.
+///
+/// Built fluently, then applied with [`BeadQuery::run`]:
+///
+/// ```ignore
+/// let result = BeadQuery::new()
+///     .with_tags(vec!["websocket".into()])
+///     .with_depth(2)
+///     .run(&beads);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BeadQuery {
+    status: Option<BeadStatus>,
+    priority: Option<BeadPriority>,
+    tags: Vec<String>,
+    tag_match: TagMatch,
+    expand: Expand,
+    depth: usize,
+}
+
+impl BeadQuery {
+    /// Creates an empty query that matches every bead and performs no expansion.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder: restrict to a single status
+    pub fn with_status(mut self, status: BeadStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Builder: restrict to a single priority
+    pub fn with_priority(mut self, priority: BeadPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Builder: require the given tags, combined per [`BeadQuery::with_tag_match`]
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Builder: choose tag-any vs tag-all matching (defaults to [`TagMatch::Any`])
+    pub fn with_tag_match(mut self, tag_match: TagMatch) -> Self {
+        self.tag_match = tag_match;
+        self
+    }
+
+    /// Builder: choose the expansion direction (defaults to [`Expand::Both`])
+    pub fn with_expand(mut self, expand: Expand) -> Self {
+        self.expand = expand;
+        self
+    }
+
+    /// Builder: BFS expansion depth. `0` means exact matches only.
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Returns true if `bead` satisfies the status/priority/tag predicates.
+    pub fn matches(&self, bead: &Bead) -> bool {
+        let status_ok = self.status.map(|s| s == bead.status).unwrap_or(true);
+        let priority_ok = self.priority.map(|p| p == bead.priority).unwrap_or(true);
+        let tags_ok = match self.tag_match {
+            _ if self.tags.is_empty() => true,
+            TagMatch::Any => self.tags.iter().any(|t| bead.tags.contains(t)),
+            TagMatch::All => self.tags.iter().all(|t| bead.tags.contains(t)),
+        };
+        status_ok && priority_ok && tags_ok
+    }
+
+    /// Applies the query to `beads`, returning matches and expanded context.
+    ///
+    /// The match pass collects every bead satisfying the predicates. When
+    /// `depth > 0` and expansion is enabled, a breadth-first search radiates out
+    /// from each match along `dependencies` (ancestors) and/or dependents
+    /// (descendants), up to `depth` hops. Expanded ids never overlap `matched`.
+    pub fn run(&self, beads: &[Bead]) -> QueryResult {
+        let matched: Vec<String> = beads
+            .iter()
+            .filter(|b| self.matches(b))
+            .map(|b| b.id.clone())
+            .collect();
+
+        if self.depth == 0 || self.expand == Expand::None {
+            return QueryResult {
+                matched,
+                expanded: Vec::new(),
+            };
+        }
+
+        // Forward adjacency (bead -> its dependencies) and the reverse.
+        let known: HashSet<&str> = beads.iter().map(|b| b.id.as_str()).collect();
+        let mut ancestors: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut descendants: HashMap<&str, Vec<&str>> = HashMap::new();
+        for bead in beads {
+            for dep in &bead.dependencies {
+                if known.contains(dep.as_str()) {
+                    ancestors.entry(bead.id.as_str()).or_default().push(dep);
+                    descendants
+                        .entry(dep.as_str())
+                        .or_default()
+                        .push(bead.id.as_str());
+                }
+            }
+        }
+
+        let walk_ancestors = matches!(self.expand, Expand::Ancestors | Expand::Both);
+        let walk_descendants = matches!(self.expand, Expand::Descendants | Expand::Both);
+
+        let matched_set: HashSet<&str> = matched.iter().map(String::as_str).collect();
+        let mut visited: HashSet<&str> = matched_set.clone();
+        let mut expanded: Vec<String> = Vec::new();
+        let mut queue: VecDeque<(&str, usize)> =
+            matched.iter().map(|id| (id.as_str(), 0usize)).collect();
+
+        while let Some((id, dist)) = queue.pop_front() {
+            if dist == self.depth {
+                continue;
+            }
+            let mut neighbors: Vec<&str> = Vec::new();
+            if walk_ancestors {
+                neighbors.extend(ancestors.get(id).into_iter().flatten().copied());
+            }
+            if walk_descendants {
+                neighbors.extend(descendants.get(id).into_iter().flatten().copied());
+            }
+            for next in neighbors {
+                if visited.insert(next) {
+                    expanded.push(next.to_string());
+                    queue.push_back((next, dist + 1));
+                }
+            }
+        }
+
+        QueryResult { matched, expanded }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bead(id: &str, tags: &[&str], deps: &[&str]) -> Bead {
+        Bead::new(id, id)
+            .with_tags(tags.iter().map(|t| t.to_string()).collect())
+            .with_dependencies(deps.iter().map(|d| d.to_string()).collect())
+    }
+
+    fn chain() -> Vec<Bead> {
+        // event-sourcing <- api <- websocket (websocket depends on api, api on event)
+        vec![
+            bead("event", &["backend", "events"], &[]),
+            bead("api", &["backend", "api"], &["event"]),
+            bead("websocket", &["backend", "websocket"], &["api"]),
+        ]
+    }
+
+    #[test]
+    fn test_depth_zero_is_exact_matches_only() {
+        let beads = chain();
+        let result = BeadQuery::new()
+            .with_tags(vec!["websocket".into()])
+            .run(&beads);
+        assert_eq!(result.matched, vec!["websocket"]);
+        assert!(result.expanded.is_empty());
+    }
+
+    #[test]
+    fn test_ancestor_expansion_pulls_in_upstream() {
+        let beads = chain();
+        let result = BeadQuery::new()
+            .with_tags(vec!["websocket".into()])
+            .with_expand(Expand::Ancestors)
+            .with_depth(2)
+            .run(&beads);
+        assert_eq!(result.matched, vec!["websocket"]);
+        assert_eq!(result.expanded, vec!["api", "event"]);
+    }
+
+    #[test]
+    fn test_depth_limits_hops() {
+        let beads = chain();
+        let result = BeadQuery::new()
+            .with_tags(vec!["websocket".into()])
+            .with_expand(Expand::Ancestors)
+            .with_depth(1)
+            .run(&beads);
+        assert_eq!(result.expanded, vec!["api"]);
+    }
+
+    #[test]
+    fn test_descendant_expansion_pulls_in_downstream() {
+        let beads = chain();
+        let result = BeadQuery::new()
+            .with_tags(vec!["events".into()])
+            .with_expand(Expand::Descendants)
+            .with_depth(2)
+            .run(&beads);
+        assert_eq!(result.matched, vec!["event"]);
+        assert_eq!(result.expanded, vec!["api", "websocket"]);
+    }
+
+    #[test]
+    fn test_tag_all_requires_every_tag() {
+        let beads = chain();
+        let any = BeadQuery::new()
+            .with_tags(vec!["backend".into(), "api".into()])
+            .run(&beads);
+        assert_eq!(any.matched.len(), 3);
+
+        let all = BeadQuery::new()
+            .with_tags(vec!["backend".into(), "api".into()])
+            .with_tag_match(TagMatch::All)
+            .run(&beads);
+        assert_eq!(all.matched, vec!["api"]);
+    }
+
+    #[test]
+    fn test_status_and_priority_predicates_combine() {
+        let beads = vec![
+            Bead::new("a", "A")
+                .with_status(BeadStatus::Ready)
+                .with_priority(BeadPriority::High),
+            Bead::new("b", "B")
+                .with_status(BeadStatus::Ready)
+                .with_priority(BeadPriority::Low),
+        ];
+        let result = BeadQuery::new()
+            .with_status(BeadStatus::Ready)
+            .with_priority(BeadPriority::High)
+            .run(&beads);
+        assert_eq!(result.matched, vec!["a"]);
+    }
+}
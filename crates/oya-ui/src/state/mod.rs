@@ -17,7 +17,10 @@ pub mod tauri_bridge;
 
 pub use crate::models::BeadEvent;
 pub use tauri_bridge::{
-    TauriConnectionState, TauriError, init_tauri, invoke, is_tauri_available, listen,
+    BackendProvider, HealthMonitorOptions, InvokeOptions, ListenerGuard, ListenerRegistry,
+    MockBackendRegistry, TauriChannel, TauriConnectionState, TauriError, init_tauri, invoke,
+    invoke_coalesced, invoke_with, invoke_with_channel, is_tauri_available, listen, listen_scoped,
+    start_health_monitor,
 };
 
 use leptos::prelude::Set;
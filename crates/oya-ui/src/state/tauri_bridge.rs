@@ -18,12 +18,39 @@
 //! listen("stream-chunk", |event| {
 //!     // Handle streaming data
 //! });
+//!
+//! // Or, for a single streaming call, use a typed channel instead of the
+//! // global event bus:
+//! use crate::state::tauri_bridge::{TauriChannel, invoke_with_channel};
+//! use futures::StreamExt;
+//!
+//! let channel = TauriChannel::<StageEvent>::new()?;
+//! invoke_with_channel("run_stage", &args, "on_event", &channel).await?;
+//! while let Some(event) = channel.next().await {
+//!     // event: TauriResult<StageEvent>
+//! }
+//!
+//! // In browser/mock mode (storybook-style development, unit tests, CI),
+//! // register a handler so `invoke` doesn't just fail with `NotAvailable`:
+//! use crate::state::tauri_bridge::MockBackendRegistry;
+//! use wasm_bindgen::JsValue;
+//!
+//! MockBackendRegistry::register("get_pipeline_stages", |_args: JsValue| async {
+//!     Ok(JsValue::NULL) // or serde_wasm_bindgen::to_value(&fixture)?
+//! });
 //! ```
 
+use futures::Stream;
+use futures::channel::mpsc::{self, UnboundedReceiver};
+use futures::future::{Either, FutureExt, LocalBoxFuture, Shared};
 use serde::{Serialize, de::DeserializeOwned};
 use std::cell::RefCell;
-use std::collections::HashSet;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
 use std::rc::Rc;
+use std::task::{Context, Poll};
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 
@@ -41,6 +68,10 @@ pub enum TauriError {
     SerializationError(String),
     /// Event listener error
     ListenerError(String),
+    /// A single attempt in `invoke_with` exceeded its configured timeout
+    Timeout { duration_ms: u64 },
+    /// The invocation was cancelled via its `AbortHandle`
+    Cancelled,
 }
 
 impl std::fmt::Display for TauriError {
@@ -50,17 +81,16 @@ impl std::fmt::Display for TauriError {
             TauriError::InvocationFailed(msg) => write!(f, "Invocation failed: {msg}"),
             TauriError::SerializationError(msg) => write!(f, "Serialization error: {msg}"),
             TauriError::ListenerError(msg) => write!(f, "Listener error: {msg}"),
+            TauriError::Timeout { duration_ms } => {
+                write!(f, "Invocation timed out after {duration_ms}ms")
+            }
+            TauriError::Cancelled => write!(f, "Invocation cancelled"),
         }
     }
 }
 
 impl std::error::Error for TauriError {}
 
-// Thread-local pending requests for deduplication
-thread_local! {
-    static PENDING_REQUESTS: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
-}
-
 /// Check if Tauri is available
 #[must_use]
 pub fn is_tauri_available() -> bool {
@@ -82,14 +112,10 @@ where
     R: DeserializeOwned,
     A: Serialize + ?Sized,
 {
-    if !is_tauri_available() {
-        return Err(TauriError::NotAvailable);
-    }
-
     let args_js = serde_wasm_bindgen::to_value(args)
         .map_err(|e| TauriError::SerializationError(e.to_string()))?;
 
-    let result = invoke_inner(command, args_js).await?;
+    let result = dispatch(command, args_js).await?;
 
     serde_wasm_bindgen::from_value(result)
         .map_err(|e| TauriError::SerializationError(e.to_string()))
@@ -128,25 +154,356 @@ async fn invoke_inner(command: &str, args: JsValue) -> TauriResult<JsValue> {
     Ok(result)
 }
 
-/// Listen for Tauri events
+/// A registered mock handler for one command in browser/mock mode. Takes
+/// the raw, not-yet-deserialized JS arguments and returns the raw JS
+/// result - mirroring how `invoke_coalesced` already operates at the
+/// `JsValue` layer rather than committing to a single `R` per command.
 ///
-/// # Arguments
-/// - `event`: Event name to listen for
-/// - `callback`: Closure to call when event is received
+/// Implemented automatically for any `Fn(JsValue) -> impl Future<Output =
+/// TauriResult<JsValue>> + 'static` closure, so callers registering a mock
+/// don't need to implement this by hand.
+pub trait BackendProvider {
+    fn call(&self, args: JsValue) -> LocalBoxFuture<'static, TauriResult<JsValue>>;
+}
+
+impl<F, Fut> BackendProvider for F
+where
+    F: Fn(JsValue) -> Fut,
+    Fut: Future<Output = TauriResult<JsValue>> + 'static,
+{
+    fn call(&self, args: JsValue) -> LocalBoxFuture<'static, TauriResult<JsValue>> {
+        Box::pin(self(args))
+    }
+}
+
+thread_local! {
+    static MOCK_BACKENDS: RefCell<HashMap<String, Box<dyn BackendProvider>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Thread-local registry of in-memory command handlers, used when
+/// `is_tauri_available()` is false (storybook-style development, unit
+/// tests, CI) so the app isn't limited to `TauriError::NotAvailable` for
+/// every command. Real Tauri always takes precedence when available -
+/// registering a mock has no effect on a real desktop session.
+pub struct MockBackendRegistry;
+
+impl MockBackendRegistry {
+    /// Registers `provider` to handle `command` in browser/mock mode,
+    /// replacing any handler already registered for that name.
+    pub fn register<P>(command: &str, provider: P)
+    where
+        P: BackendProvider + 'static,
+    {
+        MOCK_BACKENDS.with(|backends| {
+            backends
+                .borrow_mut()
+                .insert(command.to_string(), Box::new(provider));
+        });
+    }
+
+    /// Removes every registered handler.
+    pub fn clear() {
+        MOCK_BACKENDS.with(|backends| backends.borrow_mut().clear());
+    }
+
+    /// Whether a handler is currently registered for `command`.
+    #[must_use]
+    pub fn is_registered(command: &str) -> bool {
+        MOCK_BACKENDS.with(|backends| backends.borrow().contains_key(command))
+    }
+}
+
+/// Resolves one command invocation's raw JS result: real Tauri IPC when
+/// available, otherwise a registered mock handler (see
+/// [`MockBackendRegistry`]), otherwise `TauriError::NotAvailable`.
+async fn dispatch(command: &str, args: JsValue) -> TauriResult<JsValue> {
+    if is_tauri_available() {
+        return invoke_inner(command, args).await;
+    }
+
+    let handler = MOCK_BACKENDS.with(|backends| {
+        backends
+            .borrow()
+            .get(command)
+            .map(|provider| provider.call(args))
+    });
+
+    match handler {
+        Some(future) => future.await,
+        None => Err(TauriError::NotAvailable),
+    }
+}
+
+/// Resolves after `duration`, built on `gloo_timers`'s callback-based timer
+/// (the same primitive the canvas resize debouncer uses) bridged to a
+/// `futures` oneshot channel, rather than depending on `gloo-timers`'s
+/// separate `futures`-feature-gated timer type.
+fn sleep(duration: Duration) -> impl Future<Output = ()> {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+    let millis = u32::try_from(duration.as_millis()).unwrap_or(u32::MAX);
+    let timeout = gloo_timers::callback::Timeout::new(millis, move || {
+        let _ = sender.send(());
+    });
+    timeout.forget();
+    async move {
+        let _ = receiver.await;
+    }
+}
+
+/// Options controlling `invoke_with`'s per-attempt timeout and its retry
+/// backoff.
+#[derive(Debug, Clone)]
+pub struct InvokeOptions {
+    /// How long a single attempt may run before it's treated as timed out.
+    pub timeout: Duration,
+    /// Maximum number of retries after the first attempt.
+    pub max_retries: u32,
+    /// Base delay for exponential backoff between retries.
+    pub base_backoff: Duration,
+    /// Upper bound on the backoff delay, regardless of attempt number.
+    pub max_backoff: Duration,
+}
+
+impl Default for InvokeOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            max_retries: 2,
+            base_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Doubles `base` per `attempt` (0-indexed), capped at `max`. Shared by
+/// `InvokeOptions`'s retry backoff and `HealthMonitorOptions`'s
+/// reconnect backoff.
+fn exponential_backoff(base: Duration, max: Duration, attempt: u32) -> Duration {
+    base.saturating_mul(2u32.saturating_pow(attempt)).min(max)
+}
+
+impl InvokeOptions {
+    /// Only transient failures are worth retrying - a timeout or a raw
+    /// invocation failure (the promise itself rejecting, e.g. on a dropped
+    /// connection) may well succeed on a fresh attempt. Every other variant
+    /// is a precondition failure (`NotAvailable`) or already final
+    /// (`SerializationError`, `Cancelled`) that retrying can't fix.
+    fn is_retryable(error: &TauriError) -> bool {
+        matches!(
+            error,
+            TauriError::InvocationFailed(_) | TauriError::Timeout { .. }
+        )
+    }
+
+    /// Exponential backoff before jitter, capped at `max_backoff`.
+    fn base_delay(&self, attempt: u32) -> Duration {
+        exponential_backoff(self.base_backoff, self.max_backoff, attempt)
+    }
+
+    /// `base_delay` plus up to 10% jitter, to avoid synchronized retries
+    /// across multiple callers. Uses `Math.random()` rather than the
+    /// `rand` crate, since nothing else in this wasm-target crate depends
+    /// on `rand`.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let capped = self.base_delay(attempt);
+        let jitter_ms = (capped.as_millis() as f64 * 0.1 * js_sys::Math::random()) as u64;
+        capped.saturating_add(Duration::from_millis(jitter_ms))
+    }
+}
+
+/// Invoke a command with a per-attempt timeout and retry-with-backoff,
+/// returning both the resulting future and an `AbortHandle` that cancels
+/// it - useful for e.g. cancelling a long-running `run_pipeline` call when
+/// the user navigates away before it resolves.
 ///
-/// # Returns
-/// An unlisten function that can be called to stop listening
+/// # Errors
+/// The returned future resolves to `Err` if Tauri is not available,
+/// argument serialization fails, every attempt times out or fails, or the
+/// result doesn't deserialize to `R`; it resolves to
+/// `Err(TauriError::Cancelled)` if the `AbortHandle` is used before then.
+pub fn invoke_with<R, A>(
+    command: &str,
+    args: &A,
+    opts: InvokeOptions,
+) -> TauriResult<(impl Future<Output = TauriResult<R>>, futures::future::AbortHandle)>
+where
+    R: DeserializeOwned + 'static,
+    A: Serialize + ?Sized,
+{
+    let args_json =
+        serde_json::to_string(args).map_err(|e| TauriError::SerializationError(e.to_string()))?;
+    let command = command.to_string();
+
+    let retry_future = async move {
+        let mut attempt: u32 = 0;
+        loop {
+            let args_js = match js_sys::JSON::parse(&args_json) {
+                Ok(value) => value,
+                Err(e) => return Err(TauriError::SerializationError(format!("{e:?}"))),
+            };
+
+            let attempt_future = dispatch(&command, args_js);
+            let timeout_future = sleep(opts.timeout);
+            futures::pin_mut!(attempt_future);
+            futures::pin_mut!(timeout_future);
+
+            let outcome = match futures::future::select(attempt_future, timeout_future).await {
+                Either::Left((result, _)) => result,
+                Either::Right(((), _)) => Err(TauriError::Timeout {
+                    duration_ms: opts.timeout.as_millis() as u64,
+                }),
+            };
+
+            match outcome {
+                Ok(value) => {
+                    return serde_wasm_bindgen::from_value(value)
+                        .map_err(|e| TauriError::SerializationError(e.to_string()));
+                }
+                Err(err) if attempt < opts.max_retries && InvokeOptions::is_retryable(&err) => {
+                    sleep(opts.backoff_delay(attempt)).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    };
+
+    let (abortable, abort_handle) = futures::future::abortable(retry_future);
+    let future = async move { abortable.await.unwrap_or(Err(TauriError::Cancelled)) };
+
+    Ok((future, abort_handle))
+}
+
+/// Drop guard that keeps a channel's `onmessage` closure alive for as long
+/// as its `TauriChannel` is in scope, and detaches the closure on drop so a
+/// message that arrives after the stream has been dropped can't fire into
+/// a sender nobody is listening to anymore.
+struct ChannelGuard {
+    channel: JsValue,
+    _onmessage: Closure<dyn FnMut(JsValue)>,
+}
+
+impl Drop for ChannelGuard {
+    fn drop(&mut self) {
+        let _ = js_sys::Reflect::set(&self.channel, &JsValue::from_str("onmessage"), &JsValue::NULL);
+    }
+}
+
+/// A typed stream over a single Tauri IPC channel. Incoming messages are
+/// buffered in an unbounded queue until polled; a slow consumer does not
+/// slow the backend down.
+///
+/// Mirrors `window.__TAURI__.core.Channel`: the backend writes each message
+/// to this channel by its numeric id instead of broadcasting it through the
+/// global event bus that [`listen`] uses. Pass [`TauriChannel::raw`] (or use
+/// [`invoke_with_channel`]) as a command argument so the backend knows
+/// where to write.
+pub struct TauriChannel<T> {
+    raw: JsValue,
+    receiver: UnboundedReceiver<TauriResult<T>>,
+    _guard: ChannelGuard,
+}
+
+impl<T> TauriChannel<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    /// Allocates a new channel from `window.__TAURI__.core.Channel` and
+    /// installs its `onmessage` handler.
+    ///
+    /// # Errors
+    /// Returns `TauriError::NotAvailable` if Tauri is not available or the
+    /// JS `Channel` constructor can't be found.
+    pub fn new() -> TauriResult<Self> {
+        let window = web_sys::window().ok_or(TauriError::NotAvailable)?;
+
+        let tauri = js_sys::Reflect::get(&window, &JsValue::from_str("__TAURI__"))
+            .map_err(|_| TauriError::NotAvailable)?;
+
+        let core = js_sys::Reflect::get(&tauri, &JsValue::from_str("core"))
+            .map_err(|_| TauriError::NotAvailable)?;
+
+        let channel_ctor = js_sys::Reflect::get(&core, &JsValue::from_str("Channel"))
+            .map_err(|_| TauriError::NotAvailable)?;
+
+        let channel_ctor = channel_ctor
+            .dyn_ref::<js_sys::Function>()
+            .ok_or(TauriError::NotAvailable)?;
+
+        let raw = js_sys::Reflect::construct(channel_ctor, &js_sys::Array::new())
+            .map_err(|e| TauriError::ListenerError(format!("{e:?}")))?;
+
+        let (sender, receiver) = mpsc::unbounded::<TauriResult<T>>();
+        let onmessage = Closure::wrap(Box::new(move |payload: JsValue| {
+            let message = serde_wasm_bindgen::from_value(payload)
+                .map_err(|e| TauriError::SerializationError(e.to_string()));
+            let _ = sender.unbounded_send(message);
+        }) as Box<dyn FnMut(JsValue)>);
+
+        js_sys::Reflect::set(&raw, &JsValue::from_str("onmessage"), onmessage.as_ref())
+            .map_err(|e| TauriError::ListenerError(format!("{e:?}")))?;
+
+        Ok(Self {
+            raw: raw.clone(),
+            receiver,
+            _guard: ChannelGuard {
+                channel: raw,
+                _onmessage: onmessage,
+            },
+        })
+    }
+
+    /// The underlying JS `Channel` object, for embedding into an `invoke`
+    /// command's arguments.
+    #[must_use]
+    pub fn raw(&self) -> &JsValue {
+        &self.raw
+    }
+}
+
+impl<T> Stream for TauriChannel<T> {
+    type Item = TauriResult<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+/// Invoke a command that streams its output back through `channel` instead
+/// of returning a single value. `field` is the argument name the backend
+/// command expects the channel under (Tauri's convention is whatever name
+/// the `#[tauri::command]` parameter itself uses, e.g. `on_event`).
 ///
 /// # Errors
-/// Returns `TauriError` if Tauri is not available or listener setup fails
-pub fn listen<F>(event: &str, callback: F) -> TauriResult<js_sys::Function>
+/// Returns `TauriError` if Tauri is not available, argument serialization
+/// fails, or the command invocation itself fails.
+pub async fn invoke_with_channel<A, T>(
+    command: &str,
+    args: &A,
+    field: &str,
+    channel: &TauriChannel<T>,
+) -> TauriResult<()>
 where
-    F: Fn(JsValue) + 'static,
+    A: Serialize + ?Sized,
+    T: DeserializeOwned + 'static,
 {
     if !is_tauri_available() {
         return Err(TauriError::NotAvailable);
     }
 
+    let args_js = serde_wasm_bindgen::to_value(args)
+        .map_err(|e| TauriError::SerializationError(e.to_string()))?;
+    js_sys::Reflect::set(&args_js, &JsValue::from_str(field), channel.raw())
+        .map_err(|e| TauriError::SerializationError(format!("{e:?}")))?;
+
+    invoke_inner(command, args_js).await?;
+    Ok(())
+}
+
+/// Resolves `window.__TAURI__.event.listen`, shared by `listen` and
+/// `listen_scoped`.
+fn resolve_listen_fn() -> TauriResult<js_sys::Function> {
     let window = web_sys::window().ok_or(TauriError::NotAvailable)?;
 
     let tauri = js_sys::Reflect::get(&window, &JsValue::from_str("__TAURI__"))
@@ -158,10 +515,36 @@ where
     let listen_fn = js_sys::Reflect::get(&event_module, &JsValue::from_str("listen"))
         .map_err(|_| TauriError::NotAvailable)?;
 
-    let listen_fn = listen_fn
-        .dyn_ref::<js_sys::Function>()
-        .ok_or(TauriError::NotAvailable)?;
+    listen_fn
+        .dyn_into::<js_sys::Function>()
+        .map_err(|_| TauriError::NotAvailable)
+}
+
+/// Listen for Tauri events
+///
+/// # Arguments
+/// - `event`: Event name to listen for
+/// - `callback`: Closure to call when event is received
+///
+/// # Returns
+/// An unlisten function that can be called to stop listening
+///
+/// # Errors
+/// Returns `TauriError` if Tauri is not available or listener setup fails
+///
+/// Note: this leaks `callback`'s closure for the app's lifetime, since
+/// nothing owns it to drop it later. Prefer [`listen_scoped`] for any
+/// listener that should be torn down before the app exits (e.g. on a
+/// component's unmount).
+pub fn listen<F>(event: &str, callback: F) -> TauriResult<js_sys::Function>
+where
+    F: Fn(JsValue) + 'static,
+{
+    if !is_tauri_available() {
+        return Err(TauriError::NotAvailable);
+    }
 
+    let listen_fn = resolve_listen_fn()?;
     let closure = Closure::wrap(Box::new(callback) as Box<dyn Fn(JsValue)>);
 
     let result = listen_fn
@@ -177,35 +560,171 @@ where
         .map_err(|_| TauriError::ListenerError("Failed to get unlisten function".to_string()))
 }
 
-/// Batched bead fetch with request deduplication
+/// Owns a listener's closure together with its unlisten function, and
+/// tears both down on drop - unlike `listen`, which leaks the closure for
+/// the app's lifetime because nothing holds onto it.
+pub struct ListenerGuard {
+    unlisten: js_sys::Function,
+    _closure: Closure<dyn Fn(JsValue)>,
+}
+
+impl Drop for ListenerGuard {
+    fn drop(&mut self) {
+        let _ = self.unlisten.call0(&JsValue::NULL);
+    }
+}
+
+/// Listen for Tauri events, returning a guard that calls the unlisten
+/// function and releases the closure when dropped.
 ///
-/// Fetches multiple beads in a single IPC call, deduplicating against
-/// pending requests to avoid redundant fetches.
-pub async fn fetch_beads_batched(ids: Vec<String>) -> TauriResult<Vec<crate::models::bead::Bead>> {
-    // Filter out IDs that are already being fetched
-    let unique_ids: Vec<String> = PENDING_REQUESTS.with(|pending| {
-        let mut pending = pending.borrow_mut();
-        ids.into_iter()
-            .filter(|id| pending.insert(id.clone()))
-            .collect()
-    });
+/// # Arguments
+/// - `event`: Event name to listen for
+/// - `callback`: Closure to call when event is received
+///
+/// # Errors
+/// Returns `TauriError` if Tauri is not available or listener setup fails
+pub fn listen_scoped<F>(event: &str, callback: F) -> TauriResult<ListenerGuard>
+where
+    F: Fn(JsValue) + 'static,
+{
+    if !is_tauri_available() {
+        return Err(TauriError::NotAvailable);
+    }
+
+    let listen_fn = resolve_listen_fn()?;
+    let closure = Closure::wrap(Box::new(callback) as Box<dyn Fn(JsValue)>);
+
+    let result = listen_fn
+        .call2(&JsValue::NULL, &JsValue::from_str(event), closure.as_ref())
+        .map_err(|e| TauriError::ListenerError(format!("{e:?}")))?;
+
+    let unlisten = result
+        .dyn_into::<js_sys::Function>()
+        .map_err(|_| TauriError::ListenerError("Failed to get unlisten function".to_string()))?;
 
-    if unique_ids.is_empty() {
-        return Ok(vec![]);
+    Ok(ListenerGuard {
+        unlisten,
+        _closure: closure,
+    })
+}
+
+// Thread-local store of active listener guards, for a component that
+// registers several listeners over its lifetime and wants to tear all of
+// them down (unlistening and releasing every closure) in one call on
+// unmount, rather than threading each guard through its own signal/ref.
+// Keyed by a caller-supplied scope id (e.g. a component name) so one
+// component's `teardown` only drops the guards it registered itself.
+thread_local! {
+    static LISTENER_REGISTRY: RefCell<HashMap<String, Vec<ListenerGuard>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Thread-local registry of `ListenerGuard`s, partitioned by scope id so
+/// independent components sharing this thread don't tear down each other's
+/// listeners.
+pub struct ListenerRegistry;
+
+impl ListenerRegistry {
+    /// Registers a guard under `scope`, released by a later `teardown(scope)`
+    /// call. Does not affect guards registered under a different scope.
+    pub fn register(scope: &str, guard: ListenerGuard) {
+        LISTENER_REGISTRY.with(|registry| {
+            registry
+                .borrow_mut()
+                .entry(scope.to_string())
+                .or_default()
+                .push(guard);
+        });
     }
 
-    // Make the batched call
-    let result = invoke::<Vec<crate::models::bead::Bead>, _>("get_beads_batch", &unique_ids).await;
+    /// Drops every guard registered under `scope`, unlistening and releasing
+    /// each closure. Guards registered under other scopes are untouched.
+    pub fn teardown(scope: &str) {
+        LISTENER_REGISTRY.with(|registry| {
+            registry.borrow_mut().remove(scope);
+        });
+    }
 
-    // Clear pending requests
-    PENDING_REQUESTS.with(|pending| {
-        let mut pending = pending.borrow_mut();
-        for id in &unique_ids {
-            pending.remove(id);
-        }
+    /// Number of guards currently registered under `scope`.
+    #[must_use]
+    pub fn len(scope: &str) -> usize {
+        LISTENER_REGISTRY.with(|registry| registry.borrow().get(scope).map_or(0, Vec::len))
+    }
+}
+
+/// A single in-flight IPC call, shared by every caller coalesced onto it.
+/// Resolves to the raw JS result rather than a typed `R` so that callers
+/// requesting different `R` for the same `(command, args)` - unusual, but
+/// not ruled out by the type signature - still share one round trip.
+type SharedInvoke = Shared<LocalBoxFuture<'static, Result<JsValue, TauriError>>>;
+
+thread_local! {
+    static IN_FLIGHT: RefCell<HashMap<String, SharedInvoke>> = RefCell::new(HashMap::new());
+}
+
+/// The single-flight map key for a command call: identical `(command,
+/// args)` pairs must serialize to the same key so they coalesce, and
+/// different ones must not collide.
+fn coalesce_key(command: &str, args_json: &str) -> String {
+    format!("{command}:{args_json}")
+}
+
+/// Invoke a command, coalescing concurrent calls with identical `command`
+/// and serialized `args` onto a single underlying IPC round trip.
+///
+/// Unlike the old `PENDING_REQUESTS`-based dedup (which dropped a
+/// duplicate caller's request on the floor and returned it an empty
+/// result), every caller - the first and any duplicates that arrive while
+/// it's in flight - receives its own deserialized copy of the same
+/// response. The entry is evicted once the call resolves, so the next
+/// call with the same key starts a fresh round trip.
+///
+/// # Errors
+/// Returns `TauriError` if Tauri is not available, argument serialization
+/// fails, the command invocation fails, or the result doesn't deserialize
+/// to `R`.
+pub async fn invoke_coalesced<R, A>(command: &str, args: &A) -> TauriResult<R>
+where
+    R: DeserializeOwned,
+    A: Serialize + ?Sized,
+{
+    let args_json =
+        serde_json::to_string(args).map_err(|e| TauriError::SerializationError(e.to_string()))?;
+    let key = coalesce_key(command, &args_json);
+
+    let shared = IN_FLIGHT.with(|in_flight| {
+        in_flight
+            .borrow_mut()
+            .entry(key.clone())
+            .or_insert_with(|| {
+                let command = command.to_string();
+                let args_json = args_json.clone();
+                let fut: LocalBoxFuture<'static, Result<JsValue, TauriError>> =
+                    Box::pin(async move {
+                        let args_js = js_sys::JSON::parse(&args_json)
+                            .map_err(|e| TauriError::SerializationError(format!("{e:?}")))?;
+                        dispatch(&command, args_js).await
+                    });
+                fut.shared()
+            })
+            .clone()
     });
 
-    result
+    let result = shared.await;
+    IN_FLIGHT.with(|in_flight| in_flight.borrow_mut().remove(&key));
+
+    result.and_then(|value| {
+        serde_wasm_bindgen::from_value(value).map_err(|e| TauriError::SerializationError(e.to_string()))
+    })
+}
+
+/// Batched bead fetch with single-flight IPC coalescing
+///
+/// Fetches multiple beads in one IPC call. If an identical batch is
+/// already in flight, awaits and shares that result instead of issuing a
+/// redundant call (or, as before, silently dropping the duplicate).
+pub async fn fetch_beads_batched(ids: Vec<String>) -> TauriResult<Vec<crate::models::bead::Bead>> {
+    invoke_coalesced("get_beads_batch", &ids).await
 }
 
 /// Connection state for Tauri backend
@@ -246,34 +765,103 @@ pub async fn health_check() -> TauriResult<HealthStatus> {
     invoke("health_check", &EmptyArgs {}).await
 }
 
-/// Initialize Tauri connection and return state signal
+/// Options controlling `start_health_monitor`'s poll interval and its
+/// backoff while the backend is unreachable.
+#[derive(Debug, Clone)]
+pub struct HealthMonitorOptions {
+    /// How often to poll `health_check` while connected.
+    pub interval: Duration,
+    /// Upper bound on the backoff delay while in `Error` state, regardless
+    /// of how many consecutive failures have occurred.
+    pub max_backoff: Duration,
+}
+
+impl Default for HealthMonitorOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(5),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl HealthMonitorOptions {
+    /// Exponential backoff keyed off `interval`, capped at `max_backoff` -
+    /// so a backend that's slow to restart gets polled less and less often
+    /// instead of every `interval` regardless of how long it's been down.
+    fn backoff_delay(&self, consecutive_failures: u32) -> Duration {
+        exponential_backoff(self.interval, self.max_backoff, consecutive_failures)
+    }
+}
+
+/// Spawns a local task that polls `health_check` on `opts.interval`,
+/// driving `state_signal` and `health` through `Connected`/`Error`
+/// transitions for as long as the app runs - modeled on how Tauri's own
+/// JS runtime spawns a background updater loop rather than checking once
+/// and stopping.
 ///
-/// This should be called once when the app initializes.
-/// If Tauri is not available, falls back to browser mode.
-pub fn init_tauri() -> (
-    leptos::prelude::ReadSignal<TauriConnectionState>,
-    Rc<RefCell<Option<HealthStatus>>>,
+/// While in `Error`, the poll interval backs off exponentially (capped at
+/// `opts.max_backoff`) instead of hammering a backend that may still be
+/// restarting. `on_reconnect` fires once each time the state transitions
+/// back to `Connected` from anything else, so a caller can re-subscribe
+/// listeners that may have gone stale while the backend was unavailable.
+pub fn start_health_monitor(
+    opts: HealthMonitorOptions,
+    state_signal: leptos::prelude::WriteSignal<TauriConnectionState>,
+    health: Rc<RefCell<Option<HealthStatus>>>,
+    mut on_reconnect: impl FnMut() + 'static,
 ) {
-    use leptos::prelude::*;
+    use leptos::prelude::Set;
 
-    let (state, set_state) = signal(TauriConnectionState::NotAvailable);
-    let health = Rc::new(RefCell::new(None));
-    let health_clone = health.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        let mut consecutive_failures: u32 = 0;
+        let mut was_connected = false;
 
-    if is_tauri_available() {
-        wasm_bindgen_futures::spawn_local(async move {
+        loop {
             match health_check().await {
                 Ok(status) => {
-                    *health_clone.borrow_mut() = Some(status);
-                    set_state.set(TauriConnectionState::Connected);
-                    web_sys::console::log_1(&"Tauri backend connected".into());
+                    *health.borrow_mut() = Some(status);
+                    state_signal.set(TauriConnectionState::Connected);
+                    if !was_connected {
+                        web_sys::console::log_1(&"Tauri backend connected".into());
+                        on_reconnect();
+                    }
+                    was_connected = true;
+                    consecutive_failures = 0;
+                    sleep(opts.interval).await;
                 }
                 Err(e) => {
-                    set_state.set(TauriConnectionState::Error);
                     web_sys::console::error_1(&format!("Tauri health check failed: {e}").into());
+                    state_signal.set(TauriConnectionState::Error);
+                    was_connected = false;
+                    let delay = opts.backoff_delay(consecutive_failures);
+                    consecutive_failures = consecutive_failures.saturating_add(1);
+                    sleep(delay).await;
                 }
             }
-        });
+        }
+    });
+}
+
+/// Initialize Tauri connection and return state signal
+///
+/// This should be called once when the app initializes. If Tauri is not
+/// available, falls back to browser mode. Otherwise starts a continuous
+/// health monitor (see [`start_health_monitor`]) rather than checking
+/// once at startup, so a backend that restarts or was briefly down is
+/// picked back up automatically instead of leaving the state stuck in
+/// `Error`.
+pub fn init_tauri() -> (
+    leptos::prelude::ReadSignal<TauriConnectionState>,
+    Rc<RefCell<Option<HealthStatus>>>,
+) {
+    use leptos::prelude::signal;
+
+    let (state, set_state) = signal(TauriConnectionState::NotAvailable);
+    let health = Rc::new(RefCell::new(None));
+
+    if is_tauri_available() {
+        start_health_monitor(HealthMonitorOptions::default(), set_state, health.clone(), || {});
     } else {
         web_sys::console::log_1(&"Tauri not available, running in browser mode".into());
     }
@@ -362,6 +950,110 @@ mod tests {
         assert_eq!(TauriConnectionState::Error.to_string(), "Error");
     }
 
+    #[test]
+    fn test_invoke_options_retries_invocation_failures_and_timeouts() {
+        assert!(InvokeOptions::is_retryable(&TauriError::InvocationFailed(
+            "boom".to_string()
+        )));
+        assert!(InvokeOptions::is_retryable(&TauriError::Timeout {
+            duration_ms: 5_000
+        }));
+    }
+
+    #[test]
+    fn test_invoke_options_does_not_retry_terminal_errors() {
+        assert!(!InvokeOptions::is_retryable(&TauriError::NotAvailable));
+        assert!(!InvokeOptions::is_retryable(&TauriError::SerializationError(
+            "bad payload".to_string()
+        )));
+        assert!(!InvokeOptions::is_retryable(&TauriError::Cancelled));
+    }
+
+    #[test]
+    fn test_invoke_options_base_delay_grows_and_is_capped() {
+        // Exercises the pure backoff math directly; `backoff_delay` itself
+        // adds jitter via `Math.random()`, which needs a wasm/browser
+        // runtime this native test suite doesn't have.
+        let opts = InvokeOptions {
+            timeout: Duration::from_secs(1),
+            max_retries: 5,
+            base_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(300),
+        };
+
+        assert_eq!(opts.base_delay(0), Duration::from_millis(100));
+        assert_eq!(opts.base_delay(1), Duration::from_millis(200));
+        assert_eq!(opts.base_delay(10), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_health_monitor_options_backoff_grows_and_is_capped() {
+        let opts = HealthMonitorOptions {
+            interval: Duration::from_secs(5),
+            max_backoff: Duration::from_secs(20),
+        };
+
+        assert_eq!(opts.backoff_delay(0), Duration::from_secs(5));
+        assert_eq!(opts.backoff_delay(1), Duration::from_secs(10));
+        assert_eq!(opts.backoff_delay(2), Duration::from_secs(20));
+        assert_eq!(opts.backoff_delay(10), Duration::from_secs(20));
+    }
+
+    #[test]
+    fn test_timeout_and_cancelled_error_display() {
+        let err = TauriError::Timeout { duration_ms: 2_000 };
+        assert_eq!(err.to_string(), "Invocation timed out after 2000ms");
+
+        assert_eq!(TauriError::Cancelled.to_string(), "Invocation cancelled");
+    }
+
+    #[test]
+    fn test_coalesce_key_distinguishes_command_and_args() {
+        let a = coalesce_key("get_beads_batch", "[\"id1\"]");
+        let b = coalesce_key("get_beads_batch", "[\"id2\"]");
+        let c = coalesce_key("other_command", "[\"id1\"]");
+
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a, coalesce_key("get_beads_batch", "[\"id1\"]"));
+    }
+
+    #[test]
+    fn test_mock_backend_registry_is_registered_on_empty_registry() {
+        // No live wasm/JsValue context in this test, so we can't register a
+        // real provider here - just confirm lookups on an unregistered
+        // command are well-behaved.
+        assert!(!MockBackendRegistry::is_registered(
+            "definitely_not_registered"
+        ));
+        MockBackendRegistry::clear();
+        assert!(!MockBackendRegistry::is_registered(
+            "definitely_not_registered"
+        ));
+    }
+
+    #[test]
+    fn test_listener_registry_teardown_on_empty_scope_is_idempotent() {
+        // No live Tauri context in this test, so we can't construct a real
+        // `ListenerGuard` - just confirm teardown is safe and idempotent on
+        // an empty scope.
+        assert_eq!(ListenerRegistry::len("test_component"), 0);
+        ListenerRegistry::teardown("test_component");
+        assert_eq!(ListenerRegistry::len("test_component"), 0);
+    }
+
+    #[test]
+    fn test_listener_registry_teardown_is_scoped() {
+        // Tearing down one scope must not be observable from another - this
+        // only exercises the empty-scope bookkeeping since no live Tauri
+        // context is available to register a real guard here.
+        assert_eq!(ListenerRegistry::len("component_a"), 0);
+        assert_eq!(ListenerRegistry::len("component_b"), 0);
+        ListenerRegistry::teardown("component_a");
+        assert_eq!(ListenerRegistry::len("component_a"), 0);
+        assert_eq!(ListenerRegistry::len("component_b"), 0);
+    }
+
     // Note: Full Tauri tests require a running Tauri context
     // These are integration tests that would run in the actual app
 }
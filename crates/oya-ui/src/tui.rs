@@ -0,0 +1,230 @@
+//! Terminal (TUI) dashboard renderer, gated behind the `tui` feature
+//!
+//! Mirrors `pages::dashboard` for headless/CI and SSH contexts: the same
+//! [`Task`]/[`Bead`] mock data and [`TaskSummary`]/[`StatusSummary`]
+//! aggregates feed a ratatui widget layout instead of a browser DOM, so
+//! orchestrator state can be inspected without Trunk or a browser. Left out
+//! of the default (WASM) build because ratatui and crossterm pull in
+//! terminal I/O that has no meaning under `wasm32-unknown-unknown`.
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table};
+
+use crate::models::Graph;
+use crate::models::bead::{Bead, BeadStatus};
+use crate::models::mock::{StatusSummary, TaskSummary};
+use crate::models::task::Task;
+
+/// Draws the full dashboard — status gauges, a bead table, and an ASCII
+/// dependency graph — into `frame`, deriving summaries from `tasks` and
+/// `beads` the same way [`crate::pages::dashboard::Dashboard`] does.
+pub fn draw(frame: &mut Frame, tasks: &[Task], beads: &[Bead], graph: &Graph) {
+    let area = frame.area();
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(6),
+            Constraint::Length(10),
+        ])
+        .split(area);
+
+    render_header(frame, rows[0], &TaskSummary::from_tasks(tasks), &StatusSummary::from_beads(beads));
+    frame.render_widget(bead_table(beads), rows[1]);
+    frame.render_widget(graph_panel(graph), rows[2]);
+}
+
+/// Renders one gauge per bead status, each showing that status's share of
+/// the total bead count.
+fn render_header(frame: &mut Frame, area: Rect, tasks: &TaskSummary, beads: &StatusSummary) {
+    let gauges: [(&str, usize, Color); 6] = [
+        ("Pending", beads.pending, Color::Gray),
+        ("Ready", beads.ready, Color::Blue),
+        ("Running", beads.running, Color::Yellow),
+        ("Completed", beads.completed, Color::Green),
+        ("Failed", beads.failed, Color::Red),
+        ("Cancelled", beads.cancelled, Color::DarkGray),
+    ];
+
+    let total = beads.total().max(1) as f64;
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(gauges.iter().map(|_| Constraint::Ratio(1, gauges.len() as u32)))
+        .split(area);
+
+    for ((label, count, color), column) in gauges.iter().zip(columns.iter()) {
+        let gauge = Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title(*label))
+            .gauge_style(Style::default().fg(*color))
+            .ratio(*count as f64 / total)
+            .label(format!("{count}"));
+        frame.render_widget(gauge, *column);
+    }
+
+    let _ = tasks; // task counts surface in the title bar via `title_line`
+}
+
+/// Builds the title bar line summarizing task counts, shown above the gauges
+/// by callers that wrap [`draw`] in their own outer block.
+#[must_use]
+pub fn title_line(tasks: &TaskSummary) -> Line<'static> {
+    Line::from(vec![
+        Span::styled("Tasks", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(
+            "  open:{}  in_progress:{}  done:{}",
+            tasks.open, tasks.in_progress, tasks.done
+        )),
+    ])
+}
+
+/// Builds the bead table (id, status, priority, tags).
+fn bead_table(beads: &[Bead]) -> Table<'_> {
+    let header = Row::new(vec!["ID", "Status", "Priority", "Tags"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows = beads.iter().map(|bead| {
+        let color = status_color(bead.status);
+        Row::new(vec![
+            Cell::from(bead.id.clone()),
+            Cell::from(bead.status.label()).style(Style::default().fg(color)),
+            Cell::from(bead.priority.label()),
+            Cell::from(bead.tags.join(", ")),
+        ])
+    });
+
+    Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(11),
+            Constraint::Length(9),
+            Constraint::Min(10),
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title("Beads"))
+}
+
+fn status_color(status: BeadStatus) -> Color {
+    match status {
+        BeadStatus::Pending => Color::Gray,
+        BeadStatus::Ready => Color::Blue,
+        BeadStatus::Running => Color::Yellow,
+        BeadStatus::Completed => Color::Green,
+        BeadStatus::Failed => Color::Red,
+        BeadStatus::Cancelled => Color::DarkGray,
+    }
+}
+
+/// Wraps [`render_graph_ascii`]'s text in a bordered panel.
+fn graph_panel(graph: &Graph) -> Paragraph<'static> {
+    Paragraph::new(render_graph_ascii(graph))
+        .block(Block::default().borders(Borders::ALL).title("Dependency Graph"))
+}
+
+/// Renders `graph`'s already-computed layered layout (see
+/// [`Graph::layout_layered`]) as plain text: one column per distinct `x`
+/// (layer), nodes within a layer stacked top-to-bottom in `y` order, followed
+/// by a flat list of `source -> target` edges. This is a text projection of
+/// the same layout the canvas renderer draws, not a fresh layout pass.
+#[must_use]
+pub fn render_graph_ascii(graph: &Graph) -> String {
+    const COLUMN_WIDTH: usize = 14;
+
+    if graph.nodes.is_empty() {
+        return "(empty graph)".to_string();
+    }
+
+    let mut xs: Vec<f64> = graph.nodes.iter().map(|n| n.x).collect();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    xs.dedup();
+
+    let mut layers: Vec<Vec<&str>> = vec![Vec::new(); xs.len()];
+    for node in &graph.nodes {
+        if let Some(layer) = xs.iter().position(|x| (*x - node.x).abs() < f64::EPSILON) {
+            layers[layer].push(node.id.as_str());
+        }
+    }
+    for layer in &mut layers {
+        layer.sort_by(|a, b| {
+            let ya = graph.nodes.iter().find(|n| n.id == *a).map_or(0.0, |n| n.y);
+            let yb = graph.nodes.iter().find(|n| n.id == *b).map_or(0.0, |n| n.y);
+            ya.partial_cmp(&yb).unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    let max_rows = layers.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines = Vec::with_capacity(max_rows + graph.edges.len());
+    for row in 0..max_rows {
+        let mut line = String::new();
+        for layer in &layers {
+            let cell = layer.get(row).map(|id| format!("[{id}]")).unwrap_or_default();
+            line.push_str(&format!("{cell:<width$}", width = COLUMN_WIDTH));
+        }
+        lines.push(line.trim_end().to_string());
+    }
+
+    for edge in &graph.edges {
+        lines.push(format!("{} -> {}", edge.source, edge.target));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{GraphEdge, GraphNode};
+
+    fn node(id: &str, x: f64, y: f64) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            label: id.to_string(),
+            x,
+            y,
+            color: None,
+        }
+    }
+
+    #[test]
+    fn test_render_graph_ascii_empty() {
+        assert_eq!(render_graph_ascii(&Graph::new()), "(empty graph)");
+    }
+
+    #[test]
+    fn test_render_graph_ascii_orders_by_layer_and_row() {
+        let mut graph = Graph::new();
+        graph.add_node(node("a", 0.0, 0.0));
+        graph.add_node(node("b", 150.0, 0.0));
+        graph.add_node(node("c", 150.0, 100.0));
+        graph.add_edge(GraphEdge {
+            source: "a".to_string(),
+            target: "b".to_string(),
+            weight: None,
+        });
+
+        let rendered = render_graph_ascii(&graph);
+        let first_line = rendered.lines().next().unwrap_or_default();
+        assert!(first_line.contains("[a]"));
+        assert!(first_line.contains("[b]"));
+        assert!(rendered.contains("[c]"));
+        assert!(rendered.contains("a -> b"));
+    }
+
+    #[test]
+    fn test_title_line_reports_task_counts() {
+        let tasks = TaskSummary {
+            open: 1,
+            in_progress: 2,
+            done: 3,
+        };
+        let line = title_line(&tasks);
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.contains("open:1"));
+        assert!(text.contains("in_progress:2"));
+        assert!(text.contains("done:3"));
+    }
+}
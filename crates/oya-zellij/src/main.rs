@@ -71,6 +71,8 @@
 mod command_pane;
 mod graph;
 mod log_stream;
+mod plugin_metrics;
+mod sim;
 mod ui;
 
 use im::{HashMap, Vector};
@@ -82,6 +84,35 @@ use zellij_tile::prelude::*;
 const CACHE_TTL: Duration = Duration::from_secs(5);
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
 const AGENT_EVENT_LIMIT: usize = 50;
+const LOG_ENTRY_LIMIT: usize = 200;
+
+// Exponential backoff applied to a request type after it times out, so a
+// slow endpoint is retried with increasing patience instead of every timer
+// tick. `request_failures` across ALL request types must reach this count
+// before `api_connected` flips to false - one flaky endpoint shouldn't sink
+// the whole connection indicator.
+const BACKOFF_BASE: Duration = Duration::from_secs(1);
+const BACKOFF_MAX: Duration = Duration::from_secs(60);
+const REQUEST_FAILURE_THRESHOLD: u32 = 3;
+
+// A 5xx response is retried automatically with a faster, tighter backoff
+// than a plain timeout - the server already answered, so we don't need to
+// wait as long before trying again. `request_failures` doubles as the
+// give-up counter here too: once a request type's failures pass
+// MAX_RETRY_ATTEMPTS, `is_retry_due` stops scheduling further attempts until
+// the user manually refreshes.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(8);
+const MAX_RETRY_ATTEMPTS: u32 = 5;
+
+// How long a request can sit in flight before `render_header` flags it as
+// slow, well ahead of its actual per-type timeout - makes a hung backend
+// visible instead of letting the dashboard stall silently.
+const STALE_REQUEST_WARNING: Duration = Duration::from_secs(5);
+
+// A view's cache is discarded once it's gone unvisited for this long, so an
+// idle view doesn't keep its (potentially large) working data resident.
+const CACHE_DISCARD_THRESHOLD: Duration = Duration::from_secs(30);
 
 // Log streaming backpressure constants
 #[allow(dead_code)]
@@ -96,6 +127,128 @@ const CTX_PIPELINE: &str = "pipeline";
 const CTX_BEAD_ID: &str = "bead_id";
 const CTX_AGENTS_LIST: &str = "agents_list";
 const CTX_GRAPH: &str = "graph";
+const CTX_SUBSCRIPTION: &str = "subscription";
+const CTX_BATCH: &str = "batch";
+
+// The kind of a web request in flight, used to track per-request-type
+// timeouts/backoff instead of a single shared timeout for every endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum RequestType {
+    Beads,
+    Pipeline,
+    Agents,
+    Graph,
+    Subscription,
+}
+
+impl RequestType {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Beads => "beads",
+            Self::Pipeline => "pipeline",
+            Self::Agents => "agents",
+            Self::Graph => "graph",
+            Self::Subscription => "subscription",
+        }
+    }
+
+    fn from_context_key(key: &str) -> Option<Self> {
+        match key {
+            CTX_BEADS_LIST => Some(Self::Beads),
+            CTX_PIPELINE => Some(Self::Pipeline),
+            CTX_AGENTS_LIST => Some(Self::Agents),
+            CTX_GRAPH => Some(Self::Graph),
+            CTX_SUBSCRIPTION => Some(Self::Subscription),
+            _ => None,
+        }
+    }
+
+    // The wire-format counterpart of `from_context_key` - used as the
+    // `type` tag for a batch sub-request/sub-result, so routing a batch
+    // response back to the right `parse_*_response` doesn't need a second,
+    // divergent string mapping.
+    fn context_key(&self) -> &'static str {
+        match self {
+            Self::Beads => CTX_BEADS_LIST,
+            Self::Pipeline => CTX_PIPELINE,
+            Self::Agents => CTX_AGENTS_LIST,
+            Self::Graph => CTX_GRAPH,
+            Self::Subscription => CTX_SUBSCRIPTION,
+        }
+    }
+}
+
+// One sub-request inside a coalesced `/api/batch` call - the same
+// information an individual `load_*` would otherwise turn into its own GET.
+#[derive(Clone, Debug)]
+struct RequestSpec {
+    kind: RequestType,
+    bead_id: Option<String>,
+}
+
+// A classified dashboard error, replacing a bare `Option<String>` so the UI
+// and retry logic can key off *what kind* of failure occurred (a transient
+// 503, a client error, a dropped connection, a malformed response) instead
+// of re-parsing a status number out of a formatted string. Modeled on
+// pict-rs's `ErrorCode` / Garage's common-error design: each variant carries
+// a stable `code()` for diagnostics plus a human-readable `message()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum DashboardError {
+    ServerError { status: u16, detail: String },
+    ClientError { status: u16, detail: String },
+    Transport { detail: String },
+    Parse { detail: String },
+    InvalidUtf8,
+}
+
+impl DashboardError {
+    // Whether the dashboard will keep retrying this on its own, vs. needing
+    // a manual refresh (or the underlying bug being fixed).
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::ServerError { .. } | Self::Transport { .. })
+    }
+
+    // A short, stable identifier for this error's category, independent of
+    // its `detail` text, so it can be surfaced as a diagnosable code rather
+    // than forcing callers to pattern-match a formatted message.
+    fn code(&self) -> &'static str {
+        match self {
+            Self::ServerError { .. } => "server_error",
+            Self::ClientError { .. } => "client_error",
+            Self::Transport { .. } => "transport",
+            Self::Parse { .. } => "parse_error",
+            Self::InvalidUtf8 => "invalid_utf8",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::ServerError { detail, .. }
+            | Self::ClientError { detail, .. }
+            | Self::Transport { detail }
+            | Self::Parse { detail } => detail.clone(),
+            Self::InvalidUtf8 => "invalid UTF-8 in response".to_string(),
+        }
+    }
+}
+
+fn exponential_delay(attempt: u32, base: Duration, max: Duration) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(6);
+    base.saturating_mul(1u32 << exponent).min(max)
+}
+
+// Exponential backoff for a request type's `consecutive_failures`-th retry,
+// clamped to `BACKOFF_MAX` so a persistently down endpoint is still polled
+// occasionally rather than abandoned forever.
+fn backoff_for(consecutive_failures: u32) -> Duration {
+    exponential_delay(consecutive_failures, BACKOFF_BASE, BACKOFF_MAX)
+}
+
+// Exponential backoff for the `attempt`-th automatic retry of a request that
+// failed with a 5xx response, clamped to `RETRY_MAX_DELAY`.
+fn retry_delay_for(attempt: u32) -> Duration {
+    exponential_delay(attempt, RETRY_BASE_DELAY, RETRY_MAX_DELAY)
+}
 
 #[derive(Clone, Debug)]
 #[allow(dead_code)]
@@ -104,6 +257,10 @@ struct GraphNode {
     label: String,
     is_on_critical_path: bool,
     state: NodeState,
+    // CPM weight for `compute_critical_path` - `None` is treated as a unit
+    // weight of 1 rather than 0, so an unweighted graph still produces a
+    // meaningful (longest-path-by-node-count) critical path.
+    duration_ms: Option<u64>,
 }
 
 #[derive(Clone, Debug)]
@@ -113,6 +270,15 @@ struct GraphEdge {
     is_on_critical_path: bool,
 }
 
+/// Result of `State::compute_critical_path` - the node ids and `(from, to)`
+/// edge pairs with zero slack, derived locally via CPM rather than trusted
+/// from the server's `is_on_critical_path` flags.
+#[derive(Clone, Debug, Default)]
+struct CriticalPath {
+    nodes: HashSet<String>,
+    edges: HashSet<(String, String)>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum NodeState {
     Idle,
@@ -154,6 +320,27 @@ impl NodeState {
     }
 }
 
+// A cache entry that can be reclaimed once its owning view has been inactive
+// for too long, instead of staying resident for the lifetime of the plugin.
+// `Discarded` is distinct from "never loaded" (`None`) so a view landing on
+// discarded data can show a "reloading" placeholder instead of treating it
+// like genuinely empty data.
+#[derive(Clone, Debug)]
+enum Cached<T> {
+    Live(T, Instant),
+    Discarded,
+}
+
+impl<T> Cached<T> {
+    fn fresh(value: T) -> Self {
+        Self::Live(value, Instant::now())
+    }
+
+    fn is_discarded(&self) -> bool {
+        matches!(self, Self::Discarded)
+    }
+}
+
 // Plugin state
 #[derive(Clone)]
 struct State {
@@ -163,23 +350,64 @@ struct State {
     // API connection
     server_url: String,
     api_connected: bool,
-    last_error: Option<String>,
+    last_error: Option<DashboardError>,
     pending_requests: u8,
 
+    // True once `load_persisted_snapshot` has pre-populated the caches from
+    // disk and no live response has landed yet - cleared the moment any
+    // request succeeds, so the header can distinguish "this is what we saw
+    // last time" from "this is current".
+    showing_persisted_snapshot: bool,
+
     // Cache with TTL (Using im types for structural sharing)
-    beads_cache: Option<(Vector<BeadInfo>, Instant)>,
-    agents_cache: Option<(Vector<AgentInfo>, Instant)>,
-    pipeline_caches: HashMap<String, (Vector<StageInfo>, Instant)>,
+    beads_cache: Option<Cached<Vector<BeadInfo>>>,
+    agents_cache: Option<Cached<Vector<AgentInfo>>>,
+    pipeline_caches: HashMap<String, Cached<Vector<StageInfo>>>,
     #[allow(clippy::type_complexity)]
-    graph_cache: Option<(
-        Vector<GraphNode>,
-        Vector<GraphEdge>,
-        Vector<String>,
-        Instant,
-    )>,
-
-    // Tracking for timeouts
-    last_request_sent: Option<Instant>,
+    graph_cache: Option<Cached<(Vector<GraphNode>, Vector<GraphEdge>, Vector<String>)>>,
+
+    // Last time each view was the active one, used to sweep caches of views
+    // that have been idle longer than `CACHE_DISCARD_THRESHOLD`.
+    view_last_active: HashMap<ViewMode, Instant>,
+
+    // Per-request-type timeout/backoff tracking (replaces a single shared
+    // REQUEST_TIMEOUT/last_request_sent so one slow endpoint can't mask
+    // timeouts - or trigger backoff - on the others).
+    request_sent_at: HashMap<RequestType, Instant>,
+    request_timeouts: HashMap<RequestType, Duration>,
+    request_failures: HashMap<RequestType, u32>,
+    retry_after: HashMap<RequestType, Instant>,
+
+    // Self-instrumentation for the SystemHealth view: per-endpoint latency,
+    // cache hit/miss counters, and a pipeline stage pass/fail tally, all
+    // about the plugin's own API layer rather than what the backend reports.
+    endpoint_latency: HashMap<RequestType, plugin_metrics::LatencyWindow>,
+    cache_counters: HashMap<RequestType, plugin_metrics::CacheCounters>,
+    stage_tally: plugin_metrics::StageTally,
+
+    // Self-instrumentation for the Metrics view: total requests issued,
+    // broken down by endpoint, plus how many of each `DashboardError` code
+    // have been seen, so an operator can spot a noisy endpoint or a spike in
+    // a particular failure mode without attaching an external profiler.
+    total_requests: u64,
+    requests_by_type: HashMap<RequestType, u64>,
+    error_counts: HashMap<&'static str, u64>,
+
+    // EMA-smoothed fleet health, refreshed every time the agent list changes.
+    // Unlike the instrumentation above this tracks the fleet itself (idle/
+    // working/unhealthy counts, mean health score, cumulative workload)
+    // rather than the plugin's own API layer, so transient per-refresh noise
+    // doesn't flicker the SystemHealth view.
+    fleet_metrics: plugin_metrics::FleetMetrics,
+
+    // Push-based delta stream (modeled on a dataspace-style assert/retract
+    // feed), used in place of the TTL-polling `load_*` methods when the
+    // server supports it. `None` means "not yet tried", `Some(false)` means
+    // the server rejected/doesn't support it and polling is the permanent
+    // fallback for this session, `Some(true)` means deltas are flowing and
+    // polling is suppressed.
+    subscription_supported: Option<bool>,
+    subscription_cursor: Option<String>,
 
     // Bead data
     beads: Vector<BeadInfo>,
@@ -193,6 +421,17 @@ struct State {
     agents: Vector<AgentInfo>,
     agent_events: VecDeque<AgentEvent>,
 
+    // LogAggregator view: a bounded, newest-last feed aggregated across
+    // sources (mirrors `agent_events`, plus a `source` field), with
+    // interactive filter state. `log_query_draft` is `Some(partial)` only
+    // while the user is actively typing a query after pressing '/' -
+    // `None` means normal key handling applies; `log_query` is the last
+    // confirmed filter.
+    log_entries: VecDeque<LogEntry>,
+    log_level_floor: EventLevel,
+    log_query: Option<String>,
+    log_query_draft: Option<String>,
+
     // Graph data
     graph_nodes: Vector<GraphNode>,
     graph_edges: Vector<GraphEdge>,
@@ -204,6 +443,17 @@ struct State {
     // Log streaming with backpressure
     #[allow(dead_code)]
     log_buffer: log_stream::LogBuffer,
+
+    // Offline simulation / record-replay, for exercising every view without
+    // a live server. At most one of `simulator`/`replayer` is active at a
+    // time - `handle_timer_event` prefers the simulator. `recording_path` is
+    // independent and can tee real fetches to disk while talking to a live
+    // server.
+    simulator: Option<sim::Simulator>,
+    replayer: Option<sim::Replayer>,
+    replay_started_at: Option<Instant>,
+    replay_speed: f64,
+    recording_path: Option<String>,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -215,27 +465,50 @@ impl Default for State {
             api_connected: false,
             last_error: None,
             pending_requests: 0,
+            showing_persisted_snapshot: false,
             beads_cache: None,
             agents_cache: None,
             pipeline_caches: HashMap::new(),
             graph_cache: None,
-            last_request_sent: None,
+            view_last_active: HashMap::new(),
+            request_sent_at: HashMap::new(),
+            request_timeouts: HashMap::new(),
+            request_failures: HashMap::new(),
+            retry_after: HashMap::new(),
+            endpoint_latency: HashMap::new(),
+            cache_counters: HashMap::new(),
+            stage_tally: plugin_metrics::StageTally::default(),
+            total_requests: 0,
+            requests_by_type: HashMap::new(),
+            error_counts: HashMap::new(),
+            fleet_metrics: plugin_metrics::FleetMetrics::default(),
+            subscription_supported: None,
+            subscription_cursor: None,
             beads: Vector::new(),
             selected_index: 0,
             pipeline_stages: Vector::new(),
             selected_stage_index: 0,
             agents: Vector::new(),
             agent_events: VecDeque::new(),
+            log_entries: VecDeque::new(),
+            log_level_floor: EventLevel::Info,
+            log_query: None,
+            log_query_draft: None,
             graph_nodes: Vector::new(),
             graph_edges: Vector::new(),
             critical_path: Vector::new(),
             command_panes: HashMap::new(),
             log_buffer: log_stream::LogBuffer::new(),
+            simulator: None,
+            replayer: None,
+            replay_started_at: None,
+            replay_speed: 1.0,
+            recording_path: None,
         }
     }
 }
 
-#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
 enum ViewMode {
     #[default]
     BeadList,
@@ -245,6 +518,7 @@ enum ViewMode {
     GraphView,
     SystemHealth,
     LogAggregator,
+    Metrics,
 }
 
 #[derive(Clone, Debug)]
@@ -338,7 +612,21 @@ struct AgentEvent {
     occurred_at: Instant,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A `LogAggregator` entry - like `AgentEvent`, but tagged with the source
+/// (agent id) it came from, since the log view aggregates across sources
+/// rather than being scoped to one agent's timeline.
+#[derive(Clone, Debug)]
+struct LogEntry {
+    source: String,
+    level: EventLevel,
+    message: String,
+    occurred_at: Instant,
+}
+
+// Derives `Ord` in variant-declaration order (Info < Warning < Error), so a
+// `log_level_floor` of `Warning` can be compared directly against an entry's
+// level to decide whether it passes the filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum EventLevel {
     Info,
     Warning,
@@ -361,6 +649,14 @@ impl EventLevel {
             Self::Error => "x",
         }
     }
+
+    fn label(&self) -> &str {
+        match self {
+            Self::Info => "Info",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -436,6 +732,247 @@ impl StageStatus {
     }
 }
 
+// On-disk snapshot of the dashboard's last-known state, used to pre-populate
+// the UI at startup before the first live response arrives - the pict-rs
+// local-repo pattern: reads serve last-known-good data immediately, writes
+// happen on every fresh server response. Deliberately thinner than the live
+// `BeadInfo`/`AgentInfo` types: it drops bead history and per-agent
+// capabilities/workload detail, since a startup placeholder only needs
+// enough to render the list views, not their drill-down panels.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+struct PersistedSnapshot {
+    #[serde(default)]
+    beads: Vec<PersistedBead>,
+    #[serde(default)]
+    agents: Vec<PersistedAgent>,
+    #[serde(default)]
+    graph_nodes: Vec<PersistedGraphNode>,
+    #[serde(default)]
+    graph_edges: Vec<PersistedGraphEdge>,
+    #[serde(default)]
+    critical_path: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct PersistedBead {
+    id: String,
+    title: String,
+    status: String,
+    #[serde(default)]
+    current_stage: Option<String>,
+    #[serde(default)]
+    progress: f32,
+}
+
+impl PersistedBead {
+    fn from_bead(bead: &BeadInfo) -> Self {
+        Self {
+            id: bead.id.clone(),
+            title: bead.title.clone(),
+            status: bead.status.as_str().to_string(),
+            current_stage: bead.current_stage.clone(),
+            progress: bead.progress,
+        }
+    }
+
+    fn into_bead(self) -> BeadInfo {
+        BeadInfo {
+            id: self.id,
+            title: self.title,
+            status: match self.status.as_str() {
+                "in_progress" => BeadStatus::InProgress,
+                "completed" | "closed" => BeadStatus::Completed,
+                "failed" => BeadStatus::Failed,
+                _ => BeadStatus::Pending,
+            },
+            current_stage: self.current_stage,
+            progress: self.progress,
+            history: Vector::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct PersistedAgent {
+    id: String,
+    state: String,
+    #[serde(default)]
+    current_bead: Option<String>,
+    health_score: f64,
+    uptime_secs: u64,
+}
+
+impl PersistedAgent {
+    fn from_agent(agent: &AgentInfo) -> Self {
+        Self {
+            id: agent.id.clone(),
+            state: agent.state.as_str().to_string(),
+            current_bead: agent.current_bead.clone(),
+            health_score: agent.health_score,
+            uptime_secs: agent.uptime_secs,
+        }
+    }
+
+    fn into_agent(self) -> AgentInfo {
+        AgentInfo {
+            id: self.id,
+            state: match self.state.as_str() {
+                "working" => AgentState::Working,
+                "unhealthy" => AgentState::Unhealthy,
+                "shutting_down" => AgentState::ShuttingDown,
+                "terminated" => AgentState::Terminated,
+                _ => AgentState::Idle,
+            },
+            current_bead: self.current_bead,
+            health_score: self.health_score,
+            uptime_secs: self.uptime_secs,
+            capabilities: Vector::new(),
+            workload_history: WorkloadHistory::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct PersistedGraphNode {
+    id: String,
+    label: String,
+    is_on_critical_path: bool,
+    state: String,
+    #[serde(default)]
+    duration_ms: Option<u64>,
+}
+
+impl PersistedGraphNode {
+    fn from_node(node: &GraphNode) -> Self {
+        Self {
+            id: node.id.clone(),
+            label: node.label.clone(),
+            is_on_critical_path: node.is_on_critical_path,
+            state: node.state.as_str().to_string(),
+            duration_ms: node.duration_ms,
+        }
+    }
+
+    fn into_node(self) -> GraphNode {
+        GraphNode {
+            id: self.id,
+            label: self.label,
+            is_on_critical_path: self.is_on_critical_path,
+            state: match self.state.as_str() {
+                "running" => NodeState::Running,
+                "blocked" => NodeState::Blocked,
+                "completed" => NodeState::Completed,
+                "failed" => NodeState::Failed,
+                _ => NodeState::Idle,
+            },
+            duration_ms: self.duration_ms,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct PersistedGraphEdge {
+    from: String,
+    to: String,
+    is_on_critical_path: bool,
+}
+
+impl PersistedGraphEdge {
+    fn from_edge(edge: &GraphEdge) -> Self {
+        Self {
+            from: edge.from.clone(),
+            to: edge.to.clone(),
+            is_on_critical_path: edge.is_on_critical_path,
+        }
+    }
+
+    fn into_edge(self) -> GraphEdge {
+        GraphEdge {
+            from: self.from,
+            to: self.to,
+            is_on_critical_path: self.is_on_critical_path,
+        }
+    }
+}
+
+// Conversions between `sim`'s plain, serializable snapshot shapes and this
+// plugin's own `AgentInfo`/`GraphNode`/`GraphEdge` types. `sim` doesn't know
+// about those types, so it's the plugin's job to bridge them - routed
+// through `Persisted*` since the snapshot shapes are identical to the
+// on-disk format already used for `PersistedSnapshot`.
+fn agent_snapshot_to_agent_info(snapshot: sim::AgentSnapshot) -> AgentInfo {
+    PersistedAgent {
+        id: snapshot.id,
+        state: snapshot.state,
+        current_bead: snapshot.current_bead,
+        health_score: snapshot.health_score,
+        uptime_secs: snapshot.uptime_secs,
+    }
+    .into_agent()
+}
+
+fn agent_info_to_snapshot(agent: &AgentInfo) -> sim::AgentSnapshot {
+    let persisted = PersistedAgent::from_agent(agent);
+    sim::AgentSnapshot {
+        id: persisted.id,
+        state: persisted.state,
+        current_bead: persisted.current_bead,
+        health_score: persisted.health_score,
+        uptime_secs: persisted.uptime_secs,
+    }
+}
+
+fn graph_node_snapshot_to_node(snapshot: sim::GraphNodeSnapshot) -> GraphNode {
+    PersistedGraphNode {
+        id: snapshot.id,
+        label: snapshot.label,
+        is_on_critical_path: snapshot.is_on_critical_path,
+        state: snapshot.state,
+        duration_ms: snapshot.duration_ms,
+    }
+    .into_node()
+}
+
+fn graph_node_to_snapshot(node: &GraphNode) -> sim::GraphNodeSnapshot {
+    let persisted = PersistedGraphNode::from_node(node);
+    sim::GraphNodeSnapshot {
+        id: persisted.id,
+        label: persisted.label,
+        is_on_critical_path: persisted.is_on_critical_path,
+        state: persisted.state,
+        duration_ms: persisted.duration_ms,
+    }
+}
+
+fn graph_edge_snapshot_to_edge(snapshot: sim::GraphEdgeSnapshot) -> GraphEdge {
+    PersistedGraphEdge {
+        from: snapshot.from,
+        to: snapshot.to,
+        is_on_critical_path: snapshot.is_on_critical_path,
+    }
+    .into_edge()
+}
+
+fn graph_edge_to_snapshot(edge: &GraphEdge) -> sim::GraphEdgeSnapshot {
+    let persisted = PersistedGraphEdge::from_edge(edge);
+    sim::GraphEdgeSnapshot {
+        from: persisted.from,
+        to: persisted.to,
+        is_on_critical_path: persisted.is_on_critical_path,
+    }
+}
+
+// The snapshot file is scoped to `server_url` (sanitized into a filesystem-
+// safe slug) so pointing the same plugin at a different backend doesn't show
+// a stale snapshot from an unrelated server.
+fn snapshot_path(server_url: &str) -> String {
+    let slug: String = server_url
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("./oya_dashboard_snapshot_{}.json", slug)
+}
+
 register_plugin!(State);
 
 impl ZellijPlugin for State {
@@ -446,8 +983,30 @@ impl ZellijPlugin for State {
             .map(|s| s.to_string())
             .unwrap_or_else(|| "http://localhost:3000".to_string());
 
+        let request_timeouts = [
+            (RequestType::Beads, "request_timeout_beads"),
+            (RequestType::Pipeline, "request_timeout_pipeline"),
+            (RequestType::Agents, "request_timeout_agents"),
+            (RequestType::Graph, "request_timeout_graph"),
+            (RequestType::Subscription, "request_timeout_subscription"),
+        ]
+        .into_iter()
+        .map(|(kind, key)| {
+            let timeout = configuration
+                .get(key)
+                .and_then(|value| value.parse::<u64>().ok())
+                .map_or(REQUEST_TIMEOUT, Duration::from_secs);
+            (kind, timeout)
+        })
+        .collect::<HashMap<_, _>>();
+
         // Create new state with configuration loaded
-        let new_state = self.clone().with_config(server_url);
+        let new_state = self
+            .clone()
+            .with_config(server_url)
+            .with_request_timeouts(request_timeouts)
+            .load_persisted_snapshot()
+            .with_offline_modes(&configuration);
         *self = new_state;
 
         // Request permissions (WebAccess required for HTTP calls)
@@ -491,6 +1050,7 @@ impl ZellijPlugin for State {
             ViewMode::GraphView => self.render_graph_view(content_rows, cols),
             ViewMode::SystemHealth => self.render_system_health(content_rows, cols),
             ViewMode::LogAggregator => self.render_log_aggregator(content_rows, cols),
+            ViewMode::Metrics => self.render_metrics(content_rows, cols),
         }
         self.render_footer(rows, cols);
     }
@@ -502,6 +1062,290 @@ impl State {
         self
     }
 
+    fn with_request_timeouts(mut self, request_timeouts: HashMap<RequestType, Duration>) -> Self {
+        self.request_timeouts = request_timeouts;
+        self
+    }
+
+    // Reads the simulation/replay/recording config keys and arms whichever
+    // offline mode was asked for, so a developer can exercise every view
+    // without standing up the backend:
+    //   - `sim_agents`: agent count - starts a `Simulator` with the default
+    //     fleet scenario, ticked every timer refresh instead of polling.
+    //   - `replay_path` (+ optional `replay_speed`, default 1.0): loads a
+    //     recording and plays it back instead of polling.
+    //   - `record_path`: tees every successful live agents/graph fetch
+    //     to this file as a timestamped recording, independent of sim/replay.
+    fn with_offline_modes(mut self, configuration: &BTreeMap<String, String>) -> Self {
+        if let Some(count) = configuration
+            .get("sim_agents")
+            .and_then(|value| value.parse::<usize>().ok())
+        {
+            self.simulator = Some(sim::Simulator::new(sim::SimScenario::default_fleet(count)));
+        }
+
+        if let Some(path) = configuration.get("replay_path") {
+            if let Ok(replayer) = sim::Replayer::load(path) {
+                self.replay_speed = configuration
+                    .get("replay_speed")
+                    .and_then(|value| value.parse::<f64>().ok())
+                    .unwrap_or(1.0);
+                self.replayer = Some(replayer);
+                self.replay_started_at = Some(Instant::now());
+            }
+        }
+
+        self.recording_path = configuration.get("record_path").cloned();
+
+        self
+    }
+
+    // Pre-populate the dashboard from its last on-disk snapshot (if any)
+    // before permissions are even granted, so the screen isn't empty while
+    // the first request is in flight. A missing or corrupt snapshot file is
+    // silently treated as "nothing to show yet" - there's no prior state to
+    // recover.
+    fn load_persisted_snapshot(mut self) -> Self {
+        let Ok(bytes) = std::fs::read(snapshot_path(&self.server_url)) else {
+            return self;
+        };
+        let Ok(snapshot) = serde_json::from_slice::<PersistedSnapshot>(&bytes) else {
+            return self;
+        };
+
+        self.beads = snapshot
+            .beads
+            .into_iter()
+            .map(PersistedBead::into_bead)
+            .collect();
+        self.agents = snapshot
+            .agents
+            .into_iter()
+            .map(PersistedAgent::into_agent)
+            .collect();
+        self.graph_nodes = snapshot
+            .graph_nodes
+            .into_iter()
+            .map(PersistedGraphNode::into_node)
+            .collect();
+        self.graph_edges = snapshot
+            .graph_edges
+            .into_iter()
+            .map(PersistedGraphEdge::into_edge)
+            .collect();
+        self.critical_path = snapshot.critical_path.into_iter().collect();
+        self.showing_persisted_snapshot =
+            !self.beads.is_empty() || !self.agents.is_empty() || !self.graph_nodes.is_empty();
+        self
+    }
+
+    // Snapshot the current caches to disk so the next launch (or a briefly
+    // down backend) has something to pre-populate from. Called after every
+    // successful beads, agents, graph, subscription or batch response
+    // (pipeline responses are not snapshotted) - a write failure (read-only filesystem,
+    // full disk) is swallowed, since losing the snapshot is not worse than
+    // not having one.
+    fn persist_snapshot(&self) {
+        let snapshot = PersistedSnapshot {
+            beads: self.beads.iter().map(PersistedBead::from_bead).collect(),
+            agents: self.agents.iter().map(PersistedAgent::from_agent).collect(),
+            graph_nodes: self
+                .graph_nodes
+                .iter()
+                .map(PersistedGraphNode::from_node)
+                .collect(),
+            graph_edges: self
+                .graph_edges
+                .iter()
+                .map(PersistedGraphEdge::from_edge)
+                .collect(),
+            critical_path: self.critical_path.iter().cloned().collect(),
+        };
+        if let Ok(json) = serde_json::to_vec(&snapshot) {
+            let _ = std::fs::write(snapshot_path(&self.server_url), json);
+        }
+    }
+
+    // Appends the current agent/graph snapshot to the active recording (if
+    // `record_path` was configured) - called alongside `persist_snapshot`
+    // after every successful live agents/graph fetch, so a session can be
+    // replayed later. A write failure is swallowed for the same reason
+    // `persist_snapshot`'s is: losing the recording isn't worse than not
+    // having started one.
+    fn record_snapshot(&self) {
+        let Some(path) = &self.recording_path else {
+            return;
+        };
+        let frame = sim::RecordedFrame {
+            captured_at_ms: sim::now_ms(),
+            agents: self.agents.iter().map(agent_info_to_snapshot).collect(),
+            graph_nodes: self
+                .graph_nodes
+                .iter()
+                .map(graph_node_to_snapshot)
+                .collect(),
+            graph_edges: self
+                .graph_edges
+                .iter()
+                .map(graph_edge_to_snapshot)
+                .collect(),
+        };
+        let _ = sim::Recorder::new(path.clone()).record(&frame);
+    }
+
+    // Advances the offline simulator by one tick and feeds the resulting
+    // population through the same `update_agent_events`/
+    // `update_fleet_metrics` pipeline a live fetch would, so every view
+    // renders identically whether the data came from the simulator or a
+    // real server.
+    fn tick_simulation(mut self) -> Self {
+        let Some(simulator) = self.simulator.as_mut() else {
+            return self;
+        };
+        let next_agents: Vector<AgentInfo> = simulator
+            .tick()
+            .iter()
+            .cloned()
+            .map(agent_snapshot_to_agent_info)
+            .collect();
+
+        self = self.update_agent_events(&next_agents);
+        self = self.update_fleet_metrics(&next_agents);
+        self.agents = next_agents;
+        self
+    }
+
+    // Advances replay by however much wall-clock time has passed since it
+    // started, at `replay_speed`x, and applies whichever recorded frame
+    // that lands on - same downstream pipeline as `tick_simulation`, plus
+    // the recorded graph snapshot.
+    fn tick_replay(mut self) -> Self {
+        let (Some(replayer), Some(started_at)) = (self.replayer.as_ref(), self.replay_started_at)
+        else {
+            return self;
+        };
+        let elapsed_ms = started_at.elapsed().as_millis() as u64;
+        let Some(frame) = replayer.frame_for_elapsed(elapsed_ms, self.replay_speed) else {
+            return self;
+        };
+
+        let next_agents: Vector<AgentInfo> = frame
+            .agents
+            .iter()
+            .cloned()
+            .map(agent_snapshot_to_agent_info)
+            .collect();
+        let next_graph_nodes: Vector<GraphNode> = frame
+            .graph_nodes
+            .iter()
+            .cloned()
+            .map(graph_node_snapshot_to_node)
+            .collect();
+        let next_graph_edges: Vector<GraphEdge> = frame
+            .graph_edges
+            .iter()
+            .cloned()
+            .map(graph_edge_snapshot_to_edge)
+            .collect();
+
+        self = self.update_agent_events(&next_agents);
+        self = self.update_fleet_metrics(&next_agents);
+        self.agents = next_agents;
+        self.graph_nodes = next_graph_nodes;
+        self.graph_edges = next_graph_edges;
+        self
+    }
+
+    fn request_timeout_for(&self, kind: RequestType) -> Duration {
+        self.request_timeouts
+            .get(&kind)
+            .copied()
+            .unwrap_or(REQUEST_TIMEOUT)
+    }
+
+    // True once `kind`'s backoff window (if any) has elapsed and a fresh
+    // attempt is allowed. Once `kind` has exhausted MAX_RETRY_ATTEMPTS it
+    // stops being retried automatically - a manual refresh (which resets
+    // `request_failures`) is required to try again.
+    fn is_retry_due(&self, kind: RequestType) -> bool {
+        if self.request_failures.get(&kind).copied().unwrap_or(0) > MAX_RETRY_ATTEMPTS {
+            return false;
+        }
+        match self.retry_after.get(&kind) {
+            Some(at) => Instant::now() >= *at,
+            None => true,
+        }
+    }
+
+    // True if any in-flight request has been pending long enough to warrant
+    // a "still waiting" warning, well before it actually times out.
+    fn has_stale_request(&self) -> bool {
+        self.request_sent_at
+            .values()
+            .any(|sent_at| sent_at.elapsed() >= STALE_REQUEST_WARNING)
+    }
+
+    fn record_cache_hit(&mut self, kind: RequestType) {
+        let mut counters = self.cache_counters.get(&kind).copied().unwrap_or_default();
+        counters.record_hit();
+        self.cache_counters.insert(kind, counters);
+    }
+
+    fn record_cache_miss(&mut self, kind: RequestType) {
+        let mut counters = self.cache_counters.get(&kind).copied().unwrap_or_default();
+        counters.record_miss();
+        self.cache_counters.insert(kind, counters);
+    }
+
+    // Tally an outgoing `web_request` for the Metrics view - called once per
+    // request type actually sent, i.e. after the cache-hit/retry-due early
+    // returns, so it only counts requests that really hit the wire.
+    fn record_request_sent(&mut self, kind: RequestType) {
+        self.total_requests = self.total_requests.saturating_add(1);
+        let count = self.requests_by_type.get(&kind).copied().unwrap_or(0);
+        self.requests_by_type.insert(kind, count.saturating_add(1));
+    }
+
+    // Classify an error into the Metrics view's per-code tally, then store it
+    // as `last_error` - the single place every error path funnels through so
+    // the tally can't drift out of sync with what's actually shown in the
+    // header.
+    fn record_error(mut self, err: DashboardError) -> Self {
+        let count = self.error_counts.get(err.code()).copied().unwrap_or(0);
+        self.error_counts.insert(err.code(), count.saturating_add(1));
+        self.last_error = Some(err);
+        self
+    }
+
+    // Tally pipeline stage transitions into terminal states (Passed/Failed)
+    // seen between two snapshots of `pipeline_stages`, keyed by stage name so
+    // a stage already counted once doesn't get counted again every time the
+    // same terminal status is re-fetched.
+    fn record_stage_transitions(mut self, previous: &Vector<StageInfo>, next: &Vector<StageInfo>) -> Self {
+        let previous_by_name: BTreeMap<&str, StageStatus> = previous
+            .iter()
+            .map(|stage| (stage.name.as_str(), stage.status))
+            .collect();
+
+        for stage in next.iter() {
+            let was_terminal = matches!(
+                previous_by_name.get(stage.name.as_str()),
+                Some(StageStatus::Passed) | Some(StageStatus::Failed)
+            );
+            if was_terminal {
+                continue;
+            }
+
+            match stage.status {
+                StageStatus::Passed => self.stage_tally.record_pass(),
+                StageStatus::Failed => self.stage_tally.record_fail(),
+                _ => {}
+            }
+        }
+
+        self
+    }
+
     // Functional event handler - returns new state
     fn handle_event(self, event: Event) -> (Self, bool) {
         match event {
@@ -524,7 +1368,59 @@ impl State {
         }
     }
 
-    fn handle_key_event(self, key_with_mod: KeyWithModifier) -> (Self, bool) {
+    fn handle_key_event(mut self, key_with_mod: KeyWithModifier) -> (Self, bool) {
+        // While a log search query is being typed, every key feeds the
+        // draft instead of the normal shortcuts - including 'q'/Esc, which
+        // would otherwise close the plugin's focus instead of cancelling it.
+        if let Some(mut draft) = self.log_query_draft.take() {
+            return match key_with_mod.bare_key {
+                BareKey::Enter => {
+                    self.log_query = if draft.is_empty() { None } else { Some(draft) };
+                    (self, true)
+                }
+                BareKey::Esc => (self, true),
+                BareKey::Backspace => {
+                    draft.pop();
+                    self.log_query_draft = Some(draft);
+                    (self, true)
+                }
+                BareKey::Char(c) => {
+                    draft.push(c);
+                    self.log_query_draft = Some(draft);
+                    (self, true)
+                }
+                _ => {
+                    self.log_query_draft = Some(draft);
+                    (self, false)
+                }
+            };
+        }
+
+        // In LogAggregator, number keys set the minimum level shown instead
+        // of switching views, and '/' starts a search query - the general
+        // view-switch/shortcut handling below is skipped for these keys.
+        if self.mode == ViewMode::LogAggregator {
+            match key_with_mod.bare_key {
+                BareKey::Char('/') => {
+                    self.log_query_draft = Some(String::new());
+                    return (self, true);
+                }
+                BareKey::Char('1') => {
+                    self.log_level_floor = EventLevel::Info;
+                    return (self, true);
+                }
+                BareKey::Char('2') => {
+                    self.log_level_floor = EventLevel::Warning;
+                    return (self, true);
+                }
+                BareKey::Char('3') => {
+                    self.log_level_floor = EventLevel::Error;
+                    return (self, true);
+                }
+                _ => {}
+            }
+        }
+
         // Handle Ctrl-d (page down) and Ctrl-u (page up) first
         if key_with_mod.key_modifiers.contains(&KeyModifier::Ctrl) {
             const PAGE_SIZE: usize = 20;
@@ -578,6 +1474,7 @@ impl State {
             BareKey::Char('5') => self.switch_to_graph_view(),
             BareKey::Char('6') => self.switch_to_system_health_view(),
             BareKey::Char('7') => self.switch_to_log_aggregator_view(),
+            BareKey::Char('8') => self.switch_to_metrics_view(),
             BareKey::Enter => self.handle_enter_key(),
             BareKey::Char('r') => self.handle_refresh(),
             _ => (self, false),
@@ -585,27 +1482,44 @@ impl State {
     }
 
     fn handle_timer_event(mut self) -> (Self, bool) {
-        // Check for network timeouts
-        let has_timeout = self.pending_requests > 0
-            && self
-                .last_request_sent
-                .is_some_and(|last| last.elapsed() > REQUEST_TIMEOUT);
-
-        if has_timeout {
-            self.api_connected = false;
-            self.last_error = Some("Network timeout".to_string());
-            self.pending_requests = 0;
-            self.last_request_sent = None;
+        self.mark_view_active(self.mode);
+
+        // Offline modes replace live polling entirely - the simulator or a
+        // replayed recording drives `agents`/`graph_*` instead, so the rest
+        // of the plugin (event stream, health bands, every render) behaves
+        // exactly as it would against a real server.
+        if self.simulator.is_some() {
+            self = self.tick_simulation();
+            set_timeout(2.0);
+            return (self, true);
+        }
+        if self.replayer.is_some() {
+            self = self.tick_replay();
+            set_timeout(2.0);
+            return (self, true);
         }
 
-        // Trigger data loads - each returns updated state
-        self = self.trigger_beads_load();
+        // Check for per-request-type timeouts and back off the offenders
+        self = self.check_request_timeouts();
 
-        if self.mode == ViewMode::AgentView {
-            self = self.trigger_agents_load();
-        }
-        if self.mode == ViewMode::GraphView {
-            self = self.trigger_graph_load();
+        // Keep the push-based subscription alive - this is a no-op once a
+        // request for it is already in flight, so it's safe to call on
+        // every tick while we're waiting on the current long-poll to
+        // resolve and re-arm the next one.
+        self = self.trigger_subscription_load();
+
+        // Fall back to TTL polling for whatever the subscription isn't
+        // covering yet: while it's still being tried for the first time, or
+        // once the server has told us it isn't supported.
+        if self.subscription_supported != Some(true) {
+            self = self.trigger_beads_load();
+
+            if self.mode == ViewMode::AgentView {
+                self = self.trigger_agents_load();
+            }
+            if self.mode == ViewMode::GraphView {
+                self = self.trigger_graph_load();
+            }
         }
         if self.mode == ViewMode::SystemHealth {
             self = self.trigger_system_health_load();
@@ -614,18 +1528,33 @@ impl State {
             self = self.trigger_log_aggregator_load();
         }
 
+        self = self.sweep_idle_caches();
+
         set_timeout(2.0);
         (self, true)
     }
 
     fn handle_permission_result(mut self) -> (Self, bool) {
-        self = self.trigger_beads_load();
-
-        if should_fetch_agents_on_view_load(self.mode) {
-            self = self.trigger_agents_load();
-        }
-        if should_fetch_graph_on_view_load(self.mode) {
-            self = self.trigger_graph_load();
+        self = self.trigger_subscription_load();
+
+        if self.subscription_supported != Some(true) {
+            let mut requests = vec![RequestSpec {
+                kind: RequestType::Beads,
+                bead_id: None,
+            }];
+            if should_fetch_agents_on_view_load(self.mode) {
+                requests.push(RequestSpec {
+                    kind: RequestType::Agents,
+                    bead_id: None,
+                });
+            }
+            if should_fetch_graph_on_view_load(self.mode) {
+                requests.push(RequestSpec {
+                    kind: RequestType::Graph,
+                    bead_id: None,
+                });
+            }
+            self = self.load_batch(requests).0;
         }
         if should_fetch_system_health_on_view_load(self.mode) {
             self = self.trigger_system_health_load();
@@ -671,6 +1600,7 @@ impl State {
 
     fn switch_to_pipeline_view(mut self) -> (Self, bool) {
         self.mode = ViewMode::PipelineView;
+        self.mark_view_active(ViewMode::PipelineView);
         self.selected_stage_index = 0;
         self = self.trigger_pipeline_load();
         (self, true)
@@ -678,28 +1608,40 @@ impl State {
 
     fn switch_to_agent_view(mut self) -> (Self, bool) {
         self.mode = ViewMode::AgentView;
+        self.mark_view_active(ViewMode::AgentView);
         self = self.trigger_agents_load();
         (self, true)
     }
 
     fn switch_to_graph_view(mut self) -> (Self, bool) {
         self.mode = ViewMode::GraphView;
+        self.mark_view_active(ViewMode::GraphView);
         self = self.trigger_graph_load();
         (self, true)
     }
 
     fn switch_to_system_health_view(mut self) -> (Self, bool) {
         self.mode = ViewMode::SystemHealth;
+        self.mark_view_active(ViewMode::SystemHealth);
         self = self.trigger_system_health_load();
         (self, true)
     }
 
     fn switch_to_log_aggregator_view(mut self) -> (Self, bool) {
         self.mode = ViewMode::LogAggregator;
+        self.mark_view_active(ViewMode::LogAggregator);
         self = self.trigger_log_aggregator_load();
         (self, true)
     }
 
+    // Metrics is purely local instrumentation - nothing to fetch, so unlike
+    // the other view switches there's no trigger_*_load to kick off.
+    fn switch_to_metrics_view(mut self) -> (Self, bool) {
+        self.mode = ViewMode::Metrics;
+        self.mark_view_active(ViewMode::Metrics);
+        (self, true)
+    }
+
     fn handle_enter_key(mut self) -> (Self, bool) {
         if self.mode == ViewMode::PipelineView {
             // In PipelineView: open command pane to rerun selected stage
@@ -734,14 +1676,31 @@ impl State {
         self.beads_cache = None;
         self.agents_cache = None;
         self.pipeline_caches = HashMap::new();
-        self = self.trigger_beads_load();
-
+        // A manual refresh resets give-up/backoff state too, so a request
+        // type that exhausted MAX_RETRY_ATTEMPTS gets a fresh run of attempts.
+        self.request_failures = HashMap::new();
+        self.retry_after = HashMap::new();
+
+        let mut requests = vec![RequestSpec {
+            kind: RequestType::Beads,
+            bead_id: None,
+        }];
         if self.mode == ViewMode::PipelineView {
-            self = self.trigger_pipeline_load();
+            if let Some(bead) = self.beads.get(self.selected_index) {
+                requests.push(RequestSpec {
+                    kind: RequestType::Pipeline,
+                    bead_id: Some(bead.id.clone()),
+                });
+            }
         }
         if self.mode == ViewMode::GraphView {
-            self = self.trigger_graph_load();
+            requests.push(RequestSpec {
+                kind: RequestType::Graph,
+                bead_id: None,
+            });
         }
+        self = self.load_batch(requests).0;
+
         if self.mode == ViewMode::SystemHealth {
             self = self.trigger_system_health_load();
         }
@@ -755,9 +1714,203 @@ impl State {
     // State update helpers
     fn with_mode(mut self, mode: ViewMode) -> Self {
         self.mode = mode;
+        self.mark_view_active(mode);
+
+        let beads_discarded = matches!(&self.beads_cache, Some(cached) if cached.is_discarded());
+        if matches!(mode, ViewMode::BeadList | ViewMode::BeadDetail) && beads_discarded {
+            self = self.trigger_beads_load();
+        }
+
+        self
+    }
+
+    // Record that `mode` is (or just became) the active view, so idle caches
+    // belonging to other views can be measured from this point.
+    fn mark_view_active(&mut self, mode: ViewMode) {
+        self.view_last_active.insert(mode, Instant::now());
+    }
+
+    // True once every view that depends on a cache has been inactive for
+    // longer than `CACHE_DISCARD_THRESHOLD` (or was never visited at all).
+    // The currently active view is never considered idle.
+    fn is_owner_idle(&self, owners: &[ViewMode]) -> bool {
+        if owners.contains(&self.mode) {
+            return false;
+        }
+
+        let last_active = owners.iter().filter_map(|mode| self.view_last_active.get(mode)).max();
+
+        match last_active {
+            Some(instant) => instant.elapsed() > CACHE_DISCARD_THRESHOLD,
+            None => true,
+        }
+    }
+
+    // Reclaim the working data of any view that's been idle too long,
+    // replacing its cache with `Cached::Discarded` so the next visit shows a
+    // "reloading" placeholder and kicks off a fresh load instead of
+    // rendering stale or empty content.
+    fn sweep_idle_caches(mut self) -> Self {
+        const BEADS_OWNERS: [ViewMode; 3] =
+            [ViewMode::BeadList, ViewMode::BeadDetail, ViewMode::PipelineView];
+        const PIPELINE_OWNERS: [ViewMode; 1] = [ViewMode::PipelineView];
+        const AGENTS_OWNERS: [ViewMode; 1] = [ViewMode::AgentView];
+        const GRAPH_OWNERS: [ViewMode; 1] = [ViewMode::GraphView];
+
+        if self.is_owner_idle(&BEADS_OWNERS)
+            && matches!(&self.beads_cache, Some(Cached::Live(..)))
+        {
+            self.beads_cache = Some(Cached::Discarded);
+            self.beads = Vector::new();
+            self.selected_index = self.selected_index.min(self.beads.len().saturating_sub(1));
+        }
+
+        if self.is_owner_idle(&PIPELINE_OWNERS)
+            && self.pipeline_caches.values().any(|cached| matches!(cached, Cached::Live(..)))
+        {
+            self.pipeline_caches = self
+                .pipeline_caches
+                .keys()
+                .map(|bead_id| (bead_id.clone(), Cached::Discarded))
+                .collect();
+            self.pipeline_stages = Vector::new();
+            self.selected_stage_index = self
+                .selected_stage_index
+                .min(self.pipeline_stages.len().saturating_sub(1));
+        }
+
+        if self.is_owner_idle(&AGENTS_OWNERS)
+            && matches!(&self.agents_cache, Some(Cached::Live(..)))
+        {
+            self.agents_cache = Some(Cached::Discarded);
+            self.agents = Vector::new();
+        }
+
+        if self.is_owner_idle(&GRAPH_OWNERS)
+            && matches!(&self.graph_cache, Some(Cached::Live(..)))
+        {
+            self.graph_cache = Some(Cached::Discarded);
+            self.graph_nodes = Vector::new();
+            self.graph_edges = Vector::new();
+            self.critical_path = Vector::new();
+        }
+
+        self
+    }
+
+    // Give up on any in-flight request that has exceeded its per-type
+    // timeout, bumping that type's consecutive-failure count and scheduling
+    // its next attempt behind an exponential backoff instead of clearing
+    // everything and retrying immediately. `api_connected` only flips to
+    // false once failures have piled up across request types, so one slow
+    // endpoint doesn't flip the connection indicator for the rest.
+    fn check_request_timeouts(mut self) -> Self {
+        let now = Instant::now();
+        let timed_out: Vec<RequestType> = self
+            .request_sent_at
+            .iter()
+            .filter(|(kind, sent_at)| {
+                now.duration_since(**sent_at) > self.request_timeout_for(**kind)
+            })
+            .map(|(kind, _)| *kind)
+            .collect();
+
+        if timed_out.is_empty() {
+            return self;
+        }
+
+        let mut worst: Option<(RequestType, Duration)> = None;
+        for kind in timed_out {
+            self.request_sent_at.remove(&kind);
+            self.pending_requests = self.pending_requests.saturating_sub(1);
+
+            let failures = self
+                .request_failures
+                .get(&kind)
+                .copied()
+                .unwrap_or(0)
+                .saturating_add(1);
+            self.request_failures.insert(kind, failures);
+
+            let backoff = backoff_for(failures);
+            self.retry_after.insert(kind, now + backoff);
+
+            let is_worse = match worst {
+                None => true,
+                Some((_, current)) => backoff > current,
+            };
+            if is_worse {
+                worst = Some((kind, backoff));
+            }
+        }
+
+        if let Some((kind, backoff)) = worst {
+            self = self.record_error(DashboardError::Transport {
+                detail: format!(
+                    "{} request timed out, retrying in {}s",
+                    kind.label(),
+                    backoff.as_secs()
+                ),
+            });
+        }
+
+        let total_failures: u32 = self.request_failures.values().sum();
+        if total_failures >= REQUEST_FAILURE_THRESHOLD {
+            self.api_connected = false;
+        }
+
         self
     }
 
+    // Retry a failed (5xx) request with a fast exponential backoff, up to
+    // MAX_RETRY_ATTEMPTS, mirroring the job-retry behavior in the queue
+    // crate's own backoff scheduler. Once exhausted, stop retrying
+    // automatically and leave `last_error` pointing at a manual refresh.
+    fn handle_server_error(mut self, kind: Option<RequestType>, status: u16) -> Self {
+        self.api_connected = false;
+
+        let Some(kind) = kind else {
+            return self.record_error(DashboardError::ServerError {
+                status,
+                detail: format!("Server Error: HTTP {}", status),
+            });
+        };
+
+        let attempt = self
+            .request_failures
+            .get(&kind)
+            .copied()
+            .unwrap_or(0)
+            .saturating_add(1);
+        self.request_failures.insert(kind, attempt);
+
+        if attempt > MAX_RETRY_ATTEMPTS {
+            return self.record_error(DashboardError::ServerError {
+                status,
+                detail: format!(
+                    "{} request failed with HTTP {} after {} attempts, giving up (press 'r' to retry)",
+                    kind.label(),
+                    status,
+                    MAX_RETRY_ATTEMPTS
+                ),
+            });
+        }
+
+        let delay = retry_delay_for(attempt);
+        self.retry_after.insert(kind, Instant::now() + delay);
+        self.record_error(DashboardError::ServerError {
+            status,
+            detail: format!(
+                "{} request failed with HTTP {} (attempt {}/{}), retrying in {}ms",
+                kind.label(),
+                status,
+                attempt,
+                MAX_RETRY_ATTEMPTS,
+                delay.as_millis()
+            ),
+        })
+    }
+
     fn with_selected_index(mut self, index: usize) -> Self {
         self.selected_index = index;
         self
@@ -772,9 +1925,11 @@ impl State {
     #[allow(dead_code)]
     fn with_network_timeout(mut self) -> Self {
         self.api_connected = false;
-        self.last_error = Some("Network timeout".to_string());
+        self = self.record_error(DashboardError::Transport {
+            detail: "Network timeout".to_string(),
+        });
         self.pending_requests = 0;
-        self.last_request_sent = None;
+        self.request_sent_at = HashMap::new();
         self
     }
 
@@ -819,6 +1974,11 @@ impl State {
         self.load_log_aggregator().0
     }
 
+    #[must_use]
+    fn trigger_subscription_load(self) -> Self {
+        self.load_subscription().0
+    }
+
     fn handle_web_response(
         mut self,
         status: u16,
@@ -827,49 +1987,95 @@ impl State {
         context: BTreeMap<String, String>,
     ) -> (Self, bool) {
         self.pending_requests = self.pending_requests.saturating_sub(1);
-        if self.pending_requests == 0 {
-            self.last_request_sent = None;
+        let kind = context
+            .get(CTX_REQUEST_TYPE)
+            .and_then(|key| RequestType::from_context_key(key));
+        if let Some(kind) = kind {
+            if let Some(sent_at) = self.request_sent_at.get(&kind).copied() {
+                let mut window = self.endpoint_latency.get(&kind).cloned().unwrap_or_default();
+                window.record(sent_at.elapsed());
+                self.endpoint_latency.insert(kind, window);
+            }
+            self.request_sent_at.remove(&kind);
         }
 
         if !(200..300).contains(&status) {
-            self.api_connected = false;
-            self.last_error = Some(if (500..600).contains(&status) {
-                format!("Server Error: HTTP {}", status)
+            // A 404/501 on the subscription endpoint means the server just
+            // doesn't support push-based updates - fall back to polling
+            // permanently for this session instead of treating it as an
+            // error to retry.
+            if kind == Some(RequestType::Subscription) && matches!(status, 404 | 501) {
+                self.subscription_supported = Some(false);
+                self.request_failures.remove(&RequestType::Subscription);
+                self.retry_after.remove(&RequestType::Subscription);
+                return (self, true);
+            }
+
+            self = if (500..600).contains(&status) {
+                self.handle_server_error(kind, status)
             } else {
-                format!("HTTP {}", status)
-            });
+                self.api_connected = false;
+                self.record_error(DashboardError::ClientError {
+                    status,
+                    detail: format!("HTTP {}", status),
+                })
+            };
             return (self, true);
         }
 
+        if let Some(kind) = kind {
+            self.request_failures.remove(&kind);
+            self.retry_after.remove(&kind);
+        }
+
         self.api_connected = true;
         self.last_error = None;
 
         match context.get(CTX_REQUEST_TYPE).map(|s| s.as_str()) {
             Some(CTX_BEADS_LIST) => {
                 self = self.parse_beads_response(&body);
-                self.beads_cache = Some((self.beads.clone(), Instant::now()));
+                self.beads_cache = Some(Cached::fresh(self.beads.clone()));
+                self.showing_persisted_snapshot = false;
+                self.persist_snapshot();
             }
             Some(CTX_PIPELINE) => {
                 self = self.parse_pipeline_response(&body);
                 if let Some(bead_id) = context.get(CTX_BEAD_ID) {
-                    self.pipeline_caches.insert(
-                        bead_id.clone(),
-                        (self.pipeline_stages.clone(), Instant::now()),
-                    );
+                    self.pipeline_caches
+                        .insert(bead_id.clone(), Cached::fresh(self.pipeline_stages.clone()));
                 }
             }
             Some(CTX_AGENTS_LIST) => {
                 self = self.parse_agents_response(&body);
-                self.agents_cache = Some((self.agents.clone(), Instant::now()));
+                self.agents_cache = Some(Cached::fresh(self.agents.clone()));
+                self.showing_persisted_snapshot = false;
+                self.persist_snapshot();
+                self.record_snapshot();
             }
             Some(CTX_GRAPH) => {
                 self = self.parse_graph_response(&body);
-                self.graph_cache = Some((
+                self.graph_cache = Some(Cached::fresh((
                     self.graph_nodes.clone(),
                     self.graph_edges.clone(),
                     self.critical_path.clone(),
-                    Instant::now(),
-                ));
+                )));
+                self.showing_persisted_snapshot = false;
+                self.persist_snapshot();
+                self.record_snapshot();
+            }
+            Some(CTX_SUBSCRIPTION) => {
+                self.subscription_supported = Some(true);
+                self = self.parse_subscription_response(&body);
+                self.showing_persisted_snapshot = false;
+                self.persist_snapshot();
+                // Immediately re-arm the long-poll for the next batch of deltas.
+                self = self.trigger_subscription_load();
+            }
+            Some(CTX_BATCH) => {
+                self = self.apply_batch_response(&body);
+                self.showing_persisted_snapshot = false;
+                self.persist_snapshot();
+                self.record_snapshot();
             }
             _ => (),
         }
@@ -900,38 +2106,44 @@ impl State {
 
         // Track command pane completion
         let pane_id_str = _pane_id.to_string();
-        if let Some(pane) = self.command_panes.get_mut(&pane_id_str) {
+        let stage_run = self.command_panes.get_mut(&pane_id_str).and_then(|pane| {
             let code = exit_code.map_or(-1, |c| c);
             pane.mark_completed(code);
 
-            // Update the pipeline stage status if this was a stage run
             if pane.action == "run_stage" {
-                if let Some(stage_name) = pane.stage_name.clone() {
-                    let _bead_id = pane.bead_id.clone();
-                    // Update stage status functionally
-                    let new_status = if code == 0 {
-                        StageStatus::Passed
+                pane.stage_name.clone().map(|stage_name| (stage_name, code))
+            } else {
+                None
+            }
+        });
+
+        // Update the pipeline stage status if this was a stage run
+        if let Some((stage_name, code)) = stage_run {
+            let new_status = if code == 0 {
+                StageStatus::Passed
+            } else {
+                StageStatus::Failed
+            };
+            let previous_stages = self.pipeline_stages.clone();
+
+            self.pipeline_stages = self
+                .pipeline_stages
+                .iter()
+                .map(|stage| {
+                    if stage.name == stage_name {
+                        StageInfo {
+                            status: new_status,
+                            exit_code: Some(code),
+                            ..stage.clone()
+                        }
                     } else {
-                        StageStatus::Failed
-                    };
+                        stage.clone()
+                    }
+                })
+                .collect();
 
-                    self.pipeline_stages = self
-                        .pipeline_stages
-                        .iter()
-                        .map(|stage| {
-                            if stage.name == stage_name {
-                                StageInfo {
-                                    status: new_status,
-                                    exit_code: Some(code),
-                                    ..stage.clone()
-                                }
-                            } else {
-                                stage.clone()
-                            }
-                        })
-                        .collect();
-                }
-            }
+            let next_stages = self.pipeline_stages.clone();
+            self = self.record_stage_transitions(&previous_stages, &next_stages);
         }
 
         (self, true)
@@ -957,18 +2169,25 @@ impl State {
     // Note: web_request() is a side effect (I/O), but state transformation is pure.
     // This bridges the gap between functional state management and Zellij's I/O requirements.
     fn load_beads(mut self) -> (Self, Result<()>) {
-        if let Some((cached_beads, timestamp)) = &self.beads_cache {
+        if let Some(Cached::Live(cached_beads, timestamp)) = &self.beads_cache {
             if timestamp.elapsed() < CACHE_TTL {
                 self.beads = cached_beads.clone();
+                self.record_cache_hit(RequestType::Beads);
                 return (self, Ok(()));
             }
         }
+        self.record_cache_miss(RequestType::Beads);
+
+        if !self.is_retry_due(RequestType::Beads) {
+            return (self, Ok(()));
+        }
 
         let url = format!("{}/api/beads", self.server_url);
         let mut context = BTreeMap::new();
         context.insert(CTX_REQUEST_TYPE.to_string(), CTX_BEADS_LIST.to_string());
         self.pending_requests = self.pending_requests.saturating_add(1);
-        self.last_request_sent = Some(Instant::now());
+        self.request_sent_at.insert(RequestType::Beads, Instant::now());
+        self.record_request_sent(RequestType::Beads);
         web_request(&url, HttpVerb::Get, BTreeMap::new(), vec![], context);
         (self, Ok(()))
     }
@@ -978,55 +2197,153 @@ impl State {
             return (self, Ok(()));
         };
 
-        if let Some((cached_stages, timestamp)) = self.pipeline_caches.get(&bead.id) {
+        if let Some(Cached::Live(cached_stages, timestamp)) = self.pipeline_caches.get(&bead.id) {
             if timestamp.elapsed() < CACHE_TTL {
                 self.pipeline_stages = cached_stages.clone();
+                self.record_cache_hit(RequestType::Pipeline);
                 return (self, Ok(()));
             }
         }
+        self.record_cache_miss(RequestType::Pipeline);
+
+        if !self.is_retry_due(RequestType::Pipeline) {
+            return (self, Ok(()));
+        }
 
         let url = format!("{}/api/beads/{}/pipeline", self.server_url, bead.id);
         let mut context = BTreeMap::new();
         context.insert(CTX_REQUEST_TYPE.to_string(), CTX_PIPELINE.to_string());
         context.insert(CTX_BEAD_ID.to_string(), bead.id.clone());
         self.pending_requests = self.pending_requests.saturating_add(1);
-        self.last_request_sent = Some(Instant::now());
+        self.request_sent_at.insert(RequestType::Pipeline, Instant::now());
+        self.record_request_sent(RequestType::Pipeline);
         web_request(&url, HttpVerb::Get, BTreeMap::new(), vec![], context);
         (self, Ok(()))
     }
 
     fn load_agents(mut self) -> (Self, Result<()>) {
-        if let Some((cached_agents, timestamp)) = &self.agents_cache {
+        if let Some(Cached::Live(cached_agents, timestamp)) = &self.agents_cache {
             if timestamp.elapsed() < CACHE_TTL {
                 self.agents = cached_agents.clone();
+                self.record_cache_hit(RequestType::Agents);
                 return (self, Ok(()));
             }
         }
+        self.record_cache_miss(RequestType::Agents);
+
+        if !self.is_retry_due(RequestType::Agents) {
+            return (self, Ok(()));
+        }
 
         let url = format!("{}/api/agents", self.server_url);
         let mut context = BTreeMap::new();
         context.insert(CTX_REQUEST_TYPE.to_string(), CTX_AGENTS_LIST.to_string());
         self.pending_requests = self.pending_requests.saturating_add(1);
-        self.last_request_sent = Some(Instant::now());
+        self.request_sent_at.insert(RequestType::Agents, Instant::now());
+        self.record_request_sent(RequestType::Agents);
         web_request(&url, HttpVerb::Get, BTreeMap::new(), vec![], context);
         (self, Ok(()))
     }
 
     fn load_graph(mut self) -> (Self, Result<()>) {
-        if let Some((cached_nodes, cached_edges, cached_path, timestamp)) = &self.graph_cache {
+        if let Some(Cached::Live((cached_nodes, cached_edges, cached_path), timestamp)) =
+            &self.graph_cache
+        {
             if timestamp.elapsed() < CACHE_TTL {
                 self.graph_nodes = cached_nodes.clone();
                 self.graph_edges = cached_edges.clone();
                 self.critical_path = cached_path.clone();
+                self.record_cache_hit(RequestType::Graph);
                 return (self, Ok(()));
             }
         }
+        self.record_cache_miss(RequestType::Graph);
+
+        if !self.is_retry_due(RequestType::Graph) {
+            return (self, Ok(()));
+        }
 
         let url = format!("{}/api/graph", self.server_url);
         let mut context = BTreeMap::new();
         context.insert(CTX_REQUEST_TYPE.to_string(), CTX_GRAPH.to_string());
         self.pending_requests = self.pending_requests.saturating_add(1);
-        self.last_request_sent = Some(Instant::now());
+        self.request_sent_at.insert(RequestType::Graph, Instant::now());
+        self.record_request_sent(RequestType::Graph);
+        web_request(&url, HttpVerb::Get, BTreeMap::new(), vec![], context);
+        (self, Ok(()))
+    }
+
+    // Coalesce several independent fan-out requests (e.g. the initial load,
+    // or a manual refresh, which would otherwise issue one GET per endpoint)
+    // into a single `POST /api/batch`, following Garage's K2V batch API: the
+    // body is a JSON array of sub-requests and the response is a JSON array
+    // of sub-results tagged with the same `type`. Each included request type
+    // is still timed/backed-off individually via `request_sent_at` - only
+    // the wire round trip is shared.
+    fn load_batch(mut self, requests: Vec<RequestSpec>) -> (Self, Result<()>) {
+        if requests.is_empty() {
+            return (self, Ok(()));
+        }
+
+        #[derive(serde::Serialize)]
+        struct BatchSubRequest<'a> {
+            #[serde(rename = "type")]
+            kind: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            bead_id: Option<&'a str>,
+        }
+
+        let payload: Vec<BatchSubRequest> = requests
+            .iter()
+            .map(|r| BatchSubRequest {
+                kind: r.kind.context_key(),
+                bead_id: r.bead_id.as_deref(),
+            })
+            .collect();
+        let Ok(body) = serde_json::to_vec(&payload) else {
+            return (self, Ok(()));
+        };
+
+        let url = format!("{}/api/batch", self.server_url);
+        let mut context = BTreeMap::new();
+        context.insert(CTX_REQUEST_TYPE.to_string(), CTX_BATCH.to_string());
+        let mut headers = BTreeMap::new();
+        headers.insert("Content-Type".to_string(), "application/json".to_string());
+        self.pending_requests = self.pending_requests.saturating_add(1);
+        for request in &requests {
+            self.request_sent_at.insert(request.kind, Instant::now());
+            self.record_request_sent(request.kind);
+        }
+        web_request(&url, HttpVerb::Post, headers, body, context);
+        (self, Ok(()))
+    }
+
+    // Open (or keep alive) the push-based delta stream. Modeled on the
+    // dataspace/relay approach in syndicate-rs: this is the client's
+    // "interest" assertion - the server holds the request open and answers
+    // with a batch of matching deltas (or a 404/501 if it doesn't support
+    // subscriptions at all, handled in `handle_web_response`).
+    fn load_subscription(mut self) -> (Self, Result<()>) {
+        if self.subscription_supported == Some(false) {
+            return (self, Ok(()));
+        }
+        if self.request_sent_at.contains_key(&RequestType::Subscription) {
+            return (self, Ok(()));
+        }
+        if !self.is_retry_due(RequestType::Subscription) {
+            return (self, Ok(()));
+        }
+
+        let url = match &self.subscription_cursor {
+            Some(cursor) => format!("{}/api/subscribe?since={}", self.server_url, cursor),
+            None => format!("{}/api/subscribe", self.server_url),
+        };
+        let mut context = BTreeMap::new();
+        context.insert(CTX_REQUEST_TYPE.to_string(), CTX_SUBSCRIPTION.to_string());
+        self.pending_requests = self.pending_requests.saturating_add(1);
+        self.request_sent_at
+            .insert(RequestType::Subscription, Instant::now());
+        self.record_request_sent(RequestType::Subscription);
         web_request(&url, HttpVerb::Get, BTreeMap::new(), vec![], context);
         (self, Ok(()))
     }
@@ -1071,10 +2388,10 @@ impl State {
         }
 
         let parsed = std::str::from_utf8(body)
-            .map_err(|_| "Invalid UTF-8 in response".to_string())
+            .map_err(|_| DashboardError::InvalidUtf8)
             .and_then(|body_str| {
                 serde_json::from_str::<Vec<ApiBeadInfo>>(body_str)
-                    .map_err(|e| format!("Parse error: {}", e))
+                    .map_err(|e| DashboardError::Parse { detail: e.to_string() })
             });
 
         match parsed {
@@ -1100,10 +2417,7 @@ impl State {
                 }
                 self
             }
-            Err(e) => {
-                self.last_error = Some(e);
-                self
-            }
+            Err(e) => self.record_error(e),
         }
     }
 
@@ -1119,14 +2433,15 @@ impl State {
         }
 
         let parsed = std::str::from_utf8(body)
-            .map_err(|_| "Invalid UTF-8 in response".to_string())
+            .map_err(|_| DashboardError::InvalidUtf8)
             .and_then(|body_str| {
                 serde_json::from_str::<Vec<ApiStageInfo>>(body_str)
-                    .map_err(|e| format!("Parse error: {}", e))
+                    .map_err(|e| DashboardError::Parse { detail: e.to_string() })
             });
 
         match parsed {
             Ok(api_stages) => {
+                let previous_stages = self.pipeline_stages.clone();
                 self.pipeline_stages = api_stages
                     .into_iter()
                     .map(|s| StageInfo {
@@ -1142,12 +2457,13 @@ impl State {
                         exit_code: s.exit_code,
                     })
                     .collect::<Vector<_>>();
-                self
-            }
-            Err(e) => {
-                self.last_error = Some(e);
-                self
+                self.selected_stage_index = self
+                    .selected_stage_index
+                    .min(self.pipeline_stages.len().saturating_sub(1));
+                let next_stages = self.pipeline_stages.clone();
+                self.record_stage_transitions(&previous_stages, &next_stages)
             }
+            Err(e) => self.record_error(e),
         }
     }
 
@@ -1177,12 +2493,12 @@ impl State {
         }
 
         let parsed = std::str::from_utf8(body)
-            .map_err(|_| "Invalid UTF-8 in response".to_string())
+            .map_err(|_| DashboardError::InvalidUtf8)
             .and_then(|body_str| {
                 serde_json::from_str::<ApiAgentsResponse>(body_str)
                     .map(|response| response.agents)
                     .or_else(|_| serde_json::from_str::<Vec<ApiAgentInfo>>(body_str))
-                    .map_err(|e| format!("Parse error: {}", e))
+                    .map_err(|e| DashboardError::Parse { detail: e.to_string() })
             });
 
         match parsed {
@@ -1210,13 +2526,11 @@ impl State {
                     })
                     .collect::<Vector<_>>();
                 self = self.update_agent_events(&next_agents);
+                self = self.update_fleet_metrics(&next_agents);
                 self.agents = next_agents;
                 self
             }
-            Err(e) => {
-                self.last_error = Some(e);
-                self
-            }
+            Err(e) => self.record_error(e),
         }
     }
 
@@ -1226,6 +2540,8 @@ impl State {
             id: String,
             label: String,
             state: String,
+            #[serde(default)]
+            duration_ms: Option<u64>,
         }
 
         #[derive(serde::Deserialize)]
@@ -1242,10 +2558,10 @@ impl State {
         }
 
         let parsed = std::str::from_utf8(body)
-            .map_err(|_| "Invalid UTF-8 in response".to_string())
+            .map_err(|_| DashboardError::InvalidUtf8)
             .and_then(|body_str| {
                 serde_json::from_str::<ApiGraphResponse>(body_str)
-                    .map_err(|e| format!("Parse error: {}", e))
+                    .map_err(|e| DashboardError::Parse { detail: e.to_string() })
             });
 
         match parsed {
@@ -1267,6 +2583,7 @@ impl State {
                             "failed" => NodeState::Failed,
                             _ => NodeState::Idle,
                         },
+                        duration_ms: n.duration_ms,
                     })
                     .collect::<Vector<_>>();
 
@@ -1287,62 +2604,541 @@ impl State {
                 self.critical_path = critical_path_set.into_iter().collect::<Vector<_>>();
                 self
             }
+            Err(e) => self.record_error(e),
+        }
+    }
+
+    // Route each sub-result of a `/api/batch` response to the same
+    // `parse_*_response` + cache-population logic the individual GETs would
+    // have used, plus the bookkeeping `handle_web_response` normally does up
+    // front for a single-type response (latency sample, clearing
+    // `request_sent_at`/backoff state) - batching folds several request
+    // types into one wire round trip, so each type's bookkeeping has to
+    // happen per sub-result instead of once for the whole response.
+    fn apply_batch_response(mut self, body: &[u8]) -> Self {
+        #[derive(serde::Deserialize)]
+        struct BatchSubResult {
+            #[serde(rename = "type")]
+            kind: String,
+            #[serde(default)]
+            bead_id: Option<String>,
+            #[serde(default)]
+            body: serde_json::Value,
+        }
+
+        let parsed = std::str::from_utf8(body)
+            .map_err(|_| DashboardError::InvalidUtf8)
+            .and_then(|body_str| {
+                serde_json::from_str::<Vec<BatchSubResult>>(body_str)
+                    .map_err(|e| DashboardError::Parse { detail: e.to_string() })
+            });
+
+        let results = match parsed {
+            Ok(results) => results,
             Err(e) => {
-                self.last_error = Some(e);
-                self
+                return self.record_error(e);
+            }
+        };
+
+        for result in results {
+            let Some(kind) = RequestType::from_context_key(&result.kind) else {
+                continue;
+            };
+
+            if let Some(sent_at) = self.request_sent_at.get(&kind).copied() {
+                let mut window = self.endpoint_latency.get(&kind).cloned().unwrap_or_default();
+                window.record(sent_at.elapsed());
+                self.endpoint_latency.insert(kind, window);
             }
+            self.request_sent_at.remove(&kind);
+            self.request_failures.remove(&kind);
+            self.retry_after.remove(&kind);
+
+            let sub_body = serde_json::to_vec(&result.body).unwrap_or_default();
+            self = match kind {
+                RequestType::Beads => {
+                    self = self.parse_beads_response(&sub_body);
+                    self.beads_cache = Some(Cached::fresh(self.beads.clone()));
+                    self
+                }
+                RequestType::Pipeline => {
+                    self = self.parse_pipeline_response(&sub_body);
+                    if let Some(bead_id) = result.bead_id {
+                        self.pipeline_caches
+                            .insert(bead_id, Cached::fresh(self.pipeline_stages.clone()));
+                    }
+                    self
+                }
+                RequestType::Agents => {
+                    self = self.parse_agents_response(&sub_body);
+                    self.agents_cache = Some(Cached::fresh(self.agents.clone()));
+                    self
+                }
+                RequestType::Graph => {
+                    self = self.parse_graph_response(&sub_body);
+                    self.graph_cache = Some(Cached::fresh((
+                        self.graph_nodes.clone(),
+                        self.graph_edges.clone(),
+                        self.critical_path.clone(),
+                    )));
+                    self
+                }
+                RequestType::Subscription => self,
+            };
         }
+
+        self
     }
 
-    fn render_header(&self, cols: usize) {
-        let title = "OYA Pipeline Dashboard";
-        let status_symbol = if self.api_connected { "●" } else { "○" };
-        let status_color = if self.api_connected {
-            "\x1b[32m"
-        } else {
-            "\x1b[31m"
+    // Apply a batch of incremental add/update/remove deltas pushed by the
+    // subscription stream in place, instead of replacing `self.beads`,
+    // `self.pipeline_stages`, `self.agents`, or `self.graph_nodes` wholesale
+    // the way the TTL-polling `parse_*_response` methods do.
+    fn parse_subscription_response(mut self, body: &[u8]) -> Self {
+        #[derive(serde::Deserialize)]
+        struct ApiSubscriptionResponse {
+            #[serde(default)]
+            cursor: Option<String>,
+            #[serde(default)]
+            events: Vec<ApiSubscriptionEvent>,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(tag = "kind", rename_all = "snake_case")]
+        enum ApiSubscriptionEvent {
+            Bead {
+                op: String,
+                id: String,
+                #[serde(default)]
+                title: Option<String>,
+                #[serde(default)]
+                status: Option<String>,
+                #[serde(default)]
+                current_stage: Option<String>,
+                #[serde(default)]
+                progress: Option<f32>,
+            },
+            Stage {
+                op: String,
+                bead_id: String,
+                name: String,
+                #[serde(default)]
+                status: Option<String>,
+                #[serde(default)]
+                duration_ms: Option<u64>,
+                #[serde(default)]
+                exit_code: Option<i32>,
+            },
+            Agent {
+                op: String,
+                id: String,
+                #[serde(default)]
+                state: Option<String>,
+                #[serde(default)]
+                current_bead: Option<String>,
+                #[serde(default)]
+                health_score: Option<f64>,
+                #[serde(default)]
+                uptime_secs: Option<u64>,
+            },
+            GraphNode {
+                op: String,
+                id: String,
+                #[serde(default)]
+                label: Option<String>,
+                #[serde(default)]
+                state: Option<String>,
+            },
+        }
+
+        let parsed = std::str::from_utf8(body)
+            .map_err(|_| DashboardError::InvalidUtf8)
+            .and_then(|body_str| {
+                serde_json::from_str::<ApiSubscriptionResponse>(body_str)
+                    .map_err(|e| DashboardError::Parse { detail: e.to_string() })
+            });
+
+        let response = match parsed {
+            Ok(response) => response,
+            Err(e) => {
+                return self.record_error(e);
+            }
         };
 
-        println!(
-            "\x1b[1m{}\x1b[0m{}{}{}\x1b[0m",
-            title,
-            " ".repeat(cols.saturating_sub(title.len().saturating_add(3))),
-            status_color,
-            status_symbol
-        );
-        println!("{}", "─".repeat(cols));
+        if let Some(cursor) = response.cursor {
+            self.subscription_cursor = Some(cursor);
+        }
+
+        let selected_bead_id = self.beads.get(self.selected_index).map(|b| b.id.clone());
+
+        for event in response.events {
+            self = match event {
+                ApiSubscriptionEvent::Bead {
+                    op,
+                    id,
+                    title,
+                    status,
+                    current_stage,
+                    progress,
+                } => self.apply_bead_delta(&op, &id, title, status, current_stage, progress),
+                ApiSubscriptionEvent::Stage {
+                    op,
+                    bead_id,
+                    name,
+                    status,
+                    duration_ms,
+                    exit_code,
+                } => {
+                    if selected_bead_id.as_deref() == Some(bead_id.as_str()) {
+                        self.apply_stage_delta(&op, &name, status, duration_ms, exit_code)
+                    } else {
+                        self
+                    }
+                }
+                ApiSubscriptionEvent::Agent {
+                    op,
+                    id,
+                    state,
+                    current_bead,
+                    health_score,
+                    uptime_secs,
+                } => self.apply_agent_delta(&op, &id, state, current_bead, health_score, uptime_secs),
+                ApiSubscriptionEvent::GraphNode {
+                    op,
+                    id,
+                    label,
+                    state,
+                } => self.apply_graph_node_delta(&op, &id, label, state),
+            };
+        }
+
+        self
     }
 
-    fn render_bead_list(&self, rows: usize, cols: usize) {
-        if self.beads.is_empty() {
-            println!("\n  \x1b[2mNo beads found. Create one with: oya new -s <slug>\x1b[0m");
-            return;
+    fn apply_bead_delta(
+        mut self,
+        op: &str,
+        id: &str,
+        title: Option<String>,
+        status: Option<String>,
+        current_stage: Option<String>,
+        progress: Option<f32>,
+    ) -> Self {
+        if op == "remove" {
+            self.beads = self.beads.iter().filter(|b| b.id != id).cloned().collect();
+            return self;
         }
 
-        println!(
-            "\n  \x1b[1m{:<12} {:<45} {:<12} {:<15} Progress\x1b[0m",
-            "ID", "Title", "Status", "Stage"
-        );
-        println!("  {}", "─".repeat(cols.saturating_sub(2)));
+        let status = status.map(|s| match s.as_str() {
+            "in_progress" => BeadStatus::InProgress,
+            "completed" | "closed" => BeadStatus::Completed,
+            "failed" => BeadStatus::Failed,
+            _ => BeadStatus::Pending,
+        });
 
-        self.beads
+        let mut found = false;
+        let mut next: Vector<BeadInfo> = self
+            .beads
             .iter()
-            .take(rows.saturating_sub(3))
-            .enumerate()
-            .for_each(|(idx, bead)| {
-                let selected = idx == self.selected_index;
-                let prefix = if selected { "\x1b[7m> " } else { "  " };
-                let suffix = if selected { "\x1b[0m" } else { "" };
+            .map(|bead| {
+                if bead.id == id {
+                    found = true;
+                    BeadInfo {
+                        title: title.clone().unwrap_or_else(|| bead.title.clone()),
+                        status: status.unwrap_or(bead.status),
+                        current_stage: current_stage.clone().or_else(|| bead.current_stage.clone()),
+                        progress: progress.unwrap_or(bead.progress),
+                        ..bead.clone()
+                    }
+                } else {
+                    bead.clone()
+                }
+            })
+            .collect();
 
-                let title = truncate(&bead.title, 45);
-                let stage = bead.current_stage.as_deref().map_or("-", |s| s);
-                let progress_bar = render_progress_bar(bead.progress, 15);
+        if !found {
+            next.push_back(BeadInfo {
+                id: id.to_string(),
+                title: title.unwrap_or_default(),
+                status: status.unwrap_or(BeadStatus::Pending),
+                current_stage,
+                progress: progress.unwrap_or(0.0),
+                history: Vector::new(),
+            });
+        }
 
-                println!(
-                    "{}{:<12} {:<45} {}{:<12}\x1b[0m {:<15} {}{}",
-                    prefix,
-                    bead.id,
-                    title,
+        self.beads = next;
+        self.selected_index = self.selected_index.min(self.beads.len().saturating_sub(1));
+        self
+    }
+
+    fn apply_stage_delta(
+        mut self,
+        op: &str,
+        name: &str,
+        status: Option<String>,
+        duration_ms: Option<u64>,
+        exit_code: Option<i32>,
+    ) -> Self {
+        let previous_stages = self.pipeline_stages.clone();
+
+        if op == "remove" {
+            self.pipeline_stages = self
+                .pipeline_stages
+                .iter()
+                .filter(|s| s.name != name)
+                .cloned()
+                .collect();
+            return self;
+        }
+
+        let status = status.map(|s| match s.as_str() {
+            "running" => StageStatus::Running,
+            "passed" => StageStatus::Passed,
+            "failed" => StageStatus::Failed,
+            "skipped" => StageStatus::Skipped,
+            _ => StageStatus::Pending,
+        });
+
+        let mut found = false;
+        let mut next: Vector<StageInfo> = self
+            .pipeline_stages
+            .iter()
+            .map(|stage| {
+                if stage.name == name {
+                    found = true;
+                    StageInfo {
+                        status: status.unwrap_or(stage.status),
+                        duration_ms: duration_ms.or(stage.duration_ms),
+                        exit_code: exit_code.or(stage.exit_code),
+                        ..stage.clone()
+                    }
+                } else {
+                    stage.clone()
+                }
+            })
+            .collect();
+
+        if !found {
+            next.push_back(StageInfo {
+                name: name.to_string(),
+                status: status.unwrap_or(StageStatus::Pending),
+                duration_ms,
+                exit_code,
+            });
+        }
+
+        self.pipeline_stages = next;
+        self.selected_stage_index = self
+            .selected_stage_index
+            .min(self.pipeline_stages.len().saturating_sub(1));
+
+        let next_stages = self.pipeline_stages.clone();
+        self.record_stage_transitions(&previous_stages, &next_stages)
+    }
+
+    fn apply_agent_delta(
+        mut self,
+        op: &str,
+        id: &str,
+        state: Option<String>,
+        current_bead: Option<String>,
+        health_score: Option<f64>,
+        uptime_secs: Option<u64>,
+    ) -> Self {
+        if op == "remove" {
+            let next_agents: Vector<AgentInfo> =
+                self.agents.iter().filter(|a| a.id != id).cloned().collect();
+            self = self.update_agent_events(&next_agents);
+            self = self.update_fleet_metrics(&next_agents);
+            self.agents = next_agents;
+            return self;
+        }
+
+        let state = state.map(|s| match s.as_str() {
+            "working" => AgentState::Working,
+            "unhealthy" => AgentState::Unhealthy,
+            "shutting_down" => AgentState::ShuttingDown,
+            "terminated" => AgentState::Terminated,
+            _ => AgentState::Idle,
+        });
+
+        let mut found = false;
+        let mut next_agents: Vector<AgentInfo> = self
+            .agents
+            .iter()
+            .map(|agent| {
+                if agent.id == id {
+                    found = true;
+                    AgentInfo {
+                        state: state.unwrap_or(agent.state),
+                        current_bead: current_bead.clone().or_else(|| agent.current_bead.clone()),
+                        health_score: health_score.unwrap_or(agent.health_score),
+                        uptime_secs: uptime_secs.unwrap_or(agent.uptime_secs),
+                        ..agent.clone()
+                    }
+                } else {
+                    agent.clone()
+                }
+            })
+            .collect();
+
+        if !found {
+            next_agents.push_back(AgentInfo {
+                id: id.to_string(),
+                state: state.unwrap_or(AgentState::Idle),
+                current_bead,
+                health_score: health_score.unwrap_or(0.0),
+                uptime_secs: uptime_secs.unwrap_or(0),
+                capabilities: Vector::new(),
+                workload_history: WorkloadHistory::default(),
+            });
+        }
+
+        self = self.update_agent_events(&next_agents);
+        self = self.update_fleet_metrics(&next_agents);
+        self.agents = next_agents;
+        self
+    }
+
+    fn apply_graph_node_delta(
+        mut self,
+        op: &str,
+        id: &str,
+        label: Option<String>,
+        state: Option<String>,
+    ) -> Self {
+        if op == "remove" {
+            self.graph_nodes = self
+                .graph_nodes
+                .iter()
+                .filter(|n| n.id != id)
+                .cloned()
+                .collect();
+            return self;
+        }
+
+        let state = state.map(|s| match s.as_str() {
+            "running" => NodeState::Running,
+            "blocked" => NodeState::Blocked,
+            "completed" => NodeState::Completed,
+            "failed" => NodeState::Failed,
+            _ => NodeState::Idle,
+        });
+
+        let is_on_critical_path = self
+            .graph_nodes
+            .iter()
+            .any(|n| n.id == id && n.is_on_critical_path);
+
+        let mut found = false;
+        let mut next: Vector<GraphNode> = self
+            .graph_nodes
+            .iter()
+            .map(|node| {
+                if node.id == id {
+                    found = true;
+                    GraphNode {
+                        label: label.clone().unwrap_or_else(|| node.label.clone()),
+                        state: state.unwrap_or(node.state),
+                        ..node.clone()
+                    }
+                } else {
+                    node.clone()
+                }
+            })
+            .collect();
+
+        if !found {
+            next.push_back(GraphNode {
+                id: id.to_string(),
+                label: label.unwrap_or_default(),
+                is_on_critical_path,
+                state: state.unwrap_or(NodeState::Idle),
+                duration_ms: None,
+            });
+        }
+
+        self.graph_nodes = next;
+        self
+    }
+
+    fn render_header(&self, cols: usize) {
+        let title = "OYA Pipeline Dashboard";
+        let status_symbol = if self.api_connected { "●" } else { "○" };
+        let status_color = if self.api_connected {
+            "\x1b[32m"
+        } else {
+            "\x1b[31m"
+        };
+
+        println!(
+            "\x1b[1m{}\x1b[0m{}{}{}\x1b[0m",
+            title,
+            " ".repeat(cols.saturating_sub(title.len().saturating_add(3))),
+            status_color,
+            status_symbol
+        );
+
+        if self.has_stale_request() {
+            println!("\x1b[33m  ⚠ request slow, still waiting on server...\x1b[0m");
+        }
+
+        if self.showing_persisted_snapshot {
+            println!("\x1b[2m  ⟳ showing last-known data from disk, waiting for first response...\x1b[0m");
+        }
+
+        if let Some(err) = &self.last_error {
+            // Retryable errors (the dashboard will keep trying on its own)
+            // get a less alarming color than fatal ones (parse bugs,
+            // malformed responses) that need a code change or a restart.
+            let color = if err.is_retryable() { "\x1b[33m" } else { "\x1b[31m" };
+            println!(
+                "{}  ✗ [{}] {}\x1b[0m",
+                color,
+                err.code(),
+                truncate(&err.message(), cols.saturating_sub(14))
+            );
+        }
+
+        println!("{}", "─".repeat(cols));
+    }
+
+    fn render_bead_list(&self, rows: usize, cols: usize) {
+        if matches!(&self.beads_cache, Some(cached) if cached.is_discarded()) {
+            println!("\n  \x1b[2mReloading beads...\x1b[0m");
+            return;
+        }
+
+        if self.beads.is_empty() {
+            println!("\n  \x1b[2mNo beads found. Create one with: oya new -s <slug>\x1b[0m");
+            return;
+        }
+
+        println!(
+            "\n  \x1b[1m{:<12} {:<45} {:<12} {:<15} Progress\x1b[0m",
+            "ID", "Title", "Status", "Stage"
+        );
+        println!("  {}", "─".repeat(cols.saturating_sub(2)));
+
+        self.beads
+            .iter()
+            .take(rows.saturating_sub(3))
+            .enumerate()
+            .for_each(|(idx, bead)| {
+                let selected = idx == self.selected_index;
+                let prefix = if selected { "\x1b[7m> " } else { "  " };
+                let suffix = if selected { "\x1b[0m" } else { "" };
+
+                let title = truncate(&bead.title, 45);
+                let stage = bead.current_stage.as_deref().map_or("-", |s| s);
+                let progress_bar = render_progress_bar(bead.progress, 15);
+
+                println!(
+                    "{}{:<12} {:<45} {}{:<12}\x1b[0m {:<15} {}{}",
+                    prefix,
+                    bead.id,
+                    title,
                     bead.status.color(),
                     bead.status.as_str(),
                     stage,
@@ -1375,6 +3171,11 @@ impl State {
     }
 
     fn render_bead_detail(&self, rows: usize, cols: usize) {
+        if matches!(&self.beads_cache, Some(cached) if cached.is_discarded()) {
+            println!("\n  \x1b[2mReloading bead detail...\x1b[0m");
+            return;
+        }
+
         let Some(bead) = self.beads.get(self.selected_index) else {
             println!("\n  \x1b[2mNo bead selected\x1b[0m");
             return;
@@ -1414,6 +3215,11 @@ impl State {
         println!("  {}", "─".repeat(cols.saturating_sub(2)));
         println!();
 
+        if matches!(self.pipeline_caches.get(&bead.id), Some(cached) if cached.is_discarded()) {
+            println!("  \x1b[2mReloading pipeline...\x1b[0m");
+            return;
+        }
+
         if self.pipeline_stages.is_empty() {
             println!("  \x1b[2mNo pipeline stages yet\x1b[0m");
             return;
@@ -1477,6 +3283,11 @@ impl State {
     }
 
     fn render_agent_list(&self, rows: usize, cols: usize) {
+        if matches!(&self.agents_cache, Some(cached) if cached.is_discarded()) {
+            println!("\n  \x1b[2mReloading agents...\x1b[0m");
+            return;
+        }
+
         if self.agents.is_empty() {
             println!("\n  \x1b[2mNo agents found\x1b[0m");
             return;
@@ -1565,7 +3376,184 @@ impl State {
         }
     }
 
+    // Locally-derived critical path: the node ids and (from, to) edge pairs
+    // with zero slack, as computed by `compute_critical_path` rather than
+    // trusted from the server's `is_on_critical_path` flags.
+    fn compute_critical_path(&self) -> Option<CriticalPath> {
+        fn weight_of(node: &GraphNode) -> u64 {
+            node.duration_ms.unwrap_or(1)
+        }
+
+        let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+        let mut in_degree: HashMap<String, usize> = HashMap::new();
+        for node in self.graph_nodes.iter() {
+            in_degree.insert(node.id.clone(), 0);
+        }
+        for edge in self.graph_edges.iter() {
+            let mut successors = adjacency.get(&edge.from).cloned().unwrap_or_default();
+            successors.push(edge.to.clone());
+            adjacency.insert(edge.from.clone(), successors);
+            let degree = in_degree.get(&edge.to).copied().unwrap_or(0);
+            in_degree.insert(edge.to.clone(), degree.saturating_add(1));
+        }
+
+        // Kahn's algorithm: nodes with no remaining predecessors go in the
+        // queue. The forward CPM pass (earliest-finish) rides along with it
+        // - by the time a node is dequeued, every predecessor's
+        // earliest-finish has already been folded into `pred_earliest_finish`.
+        let mut queue: VecDeque<String> = self
+            .graph_nodes
+            .iter()
+            .filter(|n| in_degree.get(&n.id).copied().unwrap_or(0) == 0)
+            .map(|n| n.id.clone())
+            .collect();
+        let mut order: Vec<String> = Vec::new();
+        let mut pred_earliest_finish: HashMap<String, u64> = HashMap::new();
+        let mut earliest_finish: HashMap<String, u64> = HashMap::new();
+        let weight_by_id: BTreeMap<&str, u64> = self
+            .graph_nodes
+            .iter()
+            .map(|n| (n.id.as_str(), weight_of(n)))
+            .collect();
+
+        while let Some(node_id) = queue.pop_front() {
+            let incoming = pred_earliest_finish.get(&node_id).copied().unwrap_or(0);
+            let finish = incoming + weight_by_id.get(node_id.as_str()).copied().unwrap_or(1);
+            earliest_finish.insert(node_id.clone(), finish);
+            order.push(node_id.clone());
+
+            if let Some(successors) = adjacency.get(&node_id) {
+                for succ in successors {
+                    let candidate = pred_earliest_finish.get(succ).copied().unwrap_or(0).max(finish);
+                    pred_earliest_finish.insert(succ.clone(), candidate);
+
+                    let degree = in_degree.get(succ).copied().unwrap_or(0).saturating_sub(1);
+                    in_degree.insert(succ.clone(), degree);
+                    if degree == 0 {
+                        queue.push_back(succ.clone());
+                    }
+                }
+            }
+        }
+
+        // Leftover nodes mean a cycle - CPM is undefined, fall back to the
+        // server flags.
+        if order.len() != self.graph_nodes.len() {
+            return None;
+        }
+
+        let project_finish = order
+            .iter()
+            .map(|id| earliest_finish.get(id).copied().unwrap_or(0))
+            .max()
+            .unwrap_or(0);
+        let mut latest_finish: HashMap<String, u64> = order
+            .iter()
+            .map(|id| (id.clone(), project_finish))
+            .collect();
+        for node_id in order.iter().rev() {
+            if let Some(successors) = adjacency.get(node_id) {
+                for succ in successors {
+                    let succ_weight = weight_by_id.get(succ.as_str()).copied().unwrap_or(1);
+                    let succ_latest_finish = latest_finish.get(succ).copied().unwrap_or(project_finish);
+                    let latest_start = succ_latest_finish.saturating_sub(succ_weight);
+                    let current = latest_finish.get(node_id).copied().unwrap_or(project_finish);
+                    if latest_start < current {
+                        latest_finish.insert(node_id.clone(), latest_start);
+                    }
+                }
+            }
+        }
+
+        let critical_nodes: HashSet<String> = order
+            .iter()
+            .filter(|id| earliest_finish.get(*id) == latest_finish.get(*id))
+            .cloned()
+            .collect();
+
+        let critical_edges: HashSet<(String, String)> = self
+            .graph_edges
+            .iter()
+            .filter(|edge| {
+                if !critical_nodes.contains(&edge.from) || !critical_nodes.contains(&edge.to) {
+                    return false;
+                }
+                let from_finish = earliest_finish.get(&edge.from).copied().unwrap_or(0);
+                let to_weight = weight_by_id.get(edge.to.as_str()).copied().unwrap_or(1);
+                let to_latest_start = latest_finish.get(&edge.to).copied().unwrap_or(0).saturating_sub(to_weight);
+                from_finish == to_latest_start
+            })
+            .map(|edge| (edge.from.clone(), edge.to.clone()))
+            .collect();
+
+        Some(CriticalPath {
+            nodes: critical_nodes,
+            edges: critical_edges,
+        })
+    }
+
+    /// Longest-dependency-chain rank for each node, via a Kahn-style
+    /// topological pass over `graph_edges` - unweighted, since this drives
+    /// the layered *layout* rather than CPM timing. Sources (no
+    /// predecessors) start at rank 0; every other node's rank is
+    /// `1 + max(rank of predecessors)`. `None` on a cycle, same as
+    /// `compute_critical_path`.
+    fn compute_node_ranks(&self) -> Option<HashMap<String, usize>> {
+        let mut adjacency: HashMap<String, Vec<String>> = HashMap::new();
+        let mut in_degree: HashMap<String, usize> = HashMap::new();
+        for node in self.graph_nodes.iter() {
+            in_degree.insert(node.id.clone(), 0);
+        }
+        for edge in self.graph_edges.iter() {
+            let mut successors = adjacency.get(&edge.from).cloned().unwrap_or_default();
+            successors.push(edge.to.clone());
+            adjacency.insert(edge.from.clone(), successors);
+            let degree = in_degree.get(&edge.to).copied().unwrap_or(0);
+            in_degree.insert(edge.to.clone(), degree.saturating_add(1));
+        }
+
+        let mut queue: VecDeque<String> = self
+            .graph_nodes
+            .iter()
+            .filter(|n| in_degree.get(&n.id).copied().unwrap_or(0) == 0)
+            .map(|n| n.id.clone())
+            .collect();
+        let mut order_len = 0usize;
+        let mut pred_rank: HashMap<String, usize> = HashMap::new();
+        let mut rank: HashMap<String, usize> = HashMap::new();
+
+        while let Some(node_id) = queue.pop_front() {
+            let node_rank = pred_rank.get(&node_id).copied().unwrap_or(0);
+            rank.insert(node_id.clone(), node_rank);
+            order_len += 1;
+
+            if let Some(successors) = adjacency.get(&node_id) {
+                for succ in successors {
+                    let candidate = pred_rank.get(succ).copied().unwrap_or(0).max(node_rank + 1);
+                    pred_rank.insert(succ.clone(), candidate);
+
+                    let degree = in_degree.get(succ).copied().unwrap_or(0).saturating_sub(1);
+                    in_degree.insert(succ.clone(), degree);
+                    if degree == 0 {
+                        queue.push_back(succ.clone());
+                    }
+                }
+            }
+        }
+
+        if order_len != self.graph_nodes.len() {
+            return None;
+        }
+
+        Some(rank)
+    }
+
     fn render_graph_view(&self, rows: usize, cols: usize) {
+        if matches!(&self.graph_cache, Some(cached) if cached.is_discarded()) {
+            println!("\n  \x1b[2mReloading graph...\x1b[0m");
+            return;
+        }
+
         if self.graph_nodes.is_empty() {
             println!("\n  \x1b[2mNo graph data available\x1b[0m");
             println!("  \x1b[2mPress 'r' to refresh from server\x1b[0m");
@@ -1576,13 +3564,30 @@ impl State {
         println!("  {}", "─".repeat(cols.saturating_sub(2)));
         println!();
 
-        // Count critical path items
-        let critical_count = self
-            .graph_nodes
-            .iter()
-            .filter(|n| n.is_on_critical_path)
-            .count();
+        // Prefer the locally-derived critical path over the server's stored
+        // flags, which go stale whenever the server recomputes without
+        // telling us. Fall back to the stored flags (with a warning) only
+        // when a cycle makes CPM undefined.
+        let (critical_nodes, critical_edges, used_fallback) = match self.compute_critical_path() {
+            Some(cp) => (cp.nodes, cp.edges, false),
+            None => {
+                let nodes = self
+                    .graph_nodes
+                    .iter()
+                    .filter(|n| n.is_on_critical_path)
+                    .map(|n| n.id.clone())
+                    .collect();
+                let edges = self
+                    .graph_edges
+                    .iter()
+                    .filter(|e| e.is_on_critical_path)
+                    .map(|e| (e.from.clone(), e.to.clone()))
+                    .collect();
+                (nodes, edges, true)
+            }
+        };
 
+        let critical_count = critical_nodes.len();
         let total_nodes = self.graph_nodes.len();
         let total_edges = self.graph_edges.len();
 
@@ -1595,18 +3600,125 @@ impl State {
             total_nodes, critical_count
         );
         println!("  \x1b[1mEdges:\x1b[0m {} total", total_edges);
+        if used_fallback {
+            println!(
+                "  \x1b[31mcycle detected in dependency graph - falling back to server-reported critical path\x1b[0m"
+            );
+        }
         println!();
 
-        // Display nodes with critical path highlighting
         let max_rows = rows.saturating_sub(12);
+
+        // Layer nodes by rank so the graph reads top-to-bottom as an actual
+        // DAG instead of two independent flat lists. Ranks are undefined on
+        // a cycle (same condition that forces the critical-path fallback
+        // above), so fall back to the old flat listings in that case too.
+        match self.compute_node_ranks() {
+            Some(ranks) => self.render_graph_layers(&ranks, &critical_nodes, max_rows),
+            None => self.render_graph_flat_lists(&critical_nodes, &critical_edges, max_rows),
+        }
+    }
+
+    /// Renders nodes grouped by rank (longest dependency chain reaching
+    /// them), indented proportionally, with ASCII connectors to each node's
+    /// direct dependents. Deep ranks beyond `max_rows` collapse into a
+    /// single "... N deeper nodes" summary rather than being listed.
+    fn render_graph_layers(
+        &self,
+        ranks: &HashMap<String, usize>,
+        critical_nodes: &HashSet<String>,
+        max_rows: usize,
+    ) {
+        let mut by_rank: BTreeMap<usize, Vec<&GraphNode>> = BTreeMap::new();
+        for node in self.graph_nodes.iter() {
+            let rank = ranks.get(&node.id).copied().unwrap_or(0);
+            by_rank.entry(rank).or_default().push(node);
+        }
+
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in self.graph_edges.iter() {
+            dependents
+                .entry(edge.from.as_str())
+                .or_default()
+                .push(edge.to.as_str());
+        }
+        let label_by_id: HashMap<&str, &str> = self
+            .graph_nodes
+            .iter()
+            .map(|n| (n.id.as_str(), n.label.as_str()))
+            .collect();
+
+        println!("  \x1b[1mNodes (layered by rank):\x1b[0m");
+        let mut rendered = 0usize;
+        let mut deeper = 0usize;
+        for nodes in by_rank.values() {
+            if rendered >= max_rows {
+                deeper += nodes.len();
+                continue;
+            }
+
+            let rank = ranks.get(&nodes[0].id).copied().unwrap_or(0);
+            let indent = "  ".repeat(rank);
+            for (i, node) in nodes.iter().enumerate() {
+                if rendered >= max_rows {
+                    deeper += nodes.len() - i;
+                    break;
+                }
+
+                let is_critical = critical_nodes.contains(&node.id);
+                let marker = if is_critical {
+                    "\x1b[33m★\x1b[0m"
+                } else {
+                    "\x1b[90m○\x1b[0m"
+                };
+                let connector = if i + 1 == nodes.len() { "└─" } else { "├─" };
+
+                println!(
+                    "  {}{} {} {}{}\x1b[0m {} {}{}",
+                    indent,
+                    connector,
+                    marker,
+                    node.state.color(),
+                    node.state.symbol(),
+                    truncate(&node.label, 30),
+                    node.state.color(),
+                    node.state.as_str()
+                );
+                rendered += 1;
+
+                if let Some(deps) = dependents.get(node.id.as_str()) {
+                    for (j, dep_id) in deps.iter().enumerate() {
+                        let dep_label = label_by_id.get(dep_id).copied().unwrap_or(*dep_id);
+                        let dep_connector = if j + 1 == deps.len() { "└─→" } else { "├─→" };
+                        println!("  {}│  {} {}", indent, dep_connector, truncate(dep_label, 30));
+                    }
+                }
+            }
+        }
+
+        if deeper > 0 {
+            println!("  \x1b[2m... and {} deeper nodes\x1b[0m", deeper);
+        }
+    }
+
+    /// Pre-layering fallback: two independent flat node/edge lists, used
+    /// only when `compute_node_ranks` can't produce a layout (a cycle in
+    /// `graph_edges`).
+    fn render_graph_flat_lists(
+        &self,
+        critical_nodes: &HashSet<String>,
+        critical_edges: &HashSet<(String, String)>,
+        max_rows: usize,
+    ) {
         println!("  \x1b[1mNodes:\x1b[0m");
         self.graph_nodes.iter().take(max_rows).for_each(|node| {
-            let critical_marker = if node.is_on_critical_path {
+            let is_critical = critical_nodes.contains(&node.id);
+            let critical_marker = if is_critical {
                 "\x1b[33m★\x1b[0m"
             } else {
                 "\x1b[90m○\x1b[0m"
             };
-            let node_color = if node.is_on_critical_path {
+            let node_color = if is_critical {
                 "\x1b[33m" // Yellow for critical path
             } else {
                 "\x1b[90m" // Gray for normal
@@ -1639,17 +3751,14 @@ impl State {
             .iter()
             .take(edge_max_rows)
             .for_each(|edge| {
-                let edge_color = if edge.is_on_critical_path {
+                let is_critical = critical_edges.contains(&(edge.from.clone(), edge.to.clone()));
+                let edge_color = if is_critical {
                     "\x1b[33m" // Yellow for critical path
                 } else {
                     "\x1b[90m" // Gray for normal
                 };
 
-                let critical_marker = if edge.is_on_critical_path {
-                    "★"
-                } else {
-                    "○"
-                };
+                let critical_marker = if is_critical { "★" } else { "○" };
 
                 println!(
                     "  {} {}{} → {}\x1b[0m",
@@ -1668,50 +3777,296 @@ impl State {
         }
     }
 
-    fn render_system_health(&self, _rows: usize, _cols: usize) {
-        println!("\n  \x1b[2mSystem Health view coming soon\x1b[0m");
-        println!("  \x1b[2mPress 'r' to refresh from server\x1b[0m");
-    }
-
-    fn render_log_aggregator(&self, _rows: usize, _cols: usize) {
-        println!("\n  \x1b[2mLog Aggregator view coming soon\x1b[0m");
-        println!("  \x1b[2mPress 'r' to refresh from server\x1b[0m");
-    }
-    fn render_footer(&self, rows: usize, cols: usize) {
-        print!("\x1b[{};1H", rows.saturating_sub(1));
+    fn render_system_health(&self, _rows: usize, cols: usize) {
+        const ENDPOINTS: [RequestType; 4] = [
+            RequestType::Beads,
+            RequestType::Pipeline,
+            RequestType::Agents,
+            RequestType::Graph,
+        ];
 
-        let view_mode = match self.mode {
-            ViewMode::BeadList => "List",
-            ViewMode::BeadDetail => "Detail",
-            ViewMode::PipelineView => "Pipeline",
-            ViewMode::AgentView => "Agents",
-            ViewMode::GraphView => "Graph",
-            ViewMode::SystemHealth => "Health",
-            ViewMode::LogAggregator => "Logs",
-        };
+        println!("\n  \x1b[1mPlugin Self-Health\x1b[0m");
+        println!("  {}", "─".repeat(cols.saturating_sub(2)));
 
-        println!("{}", "─".repeat(cols));
+        println!("\n  \x1b[1mEndpoint Latency (p50 / p95)\x1b[0m");
+        for kind in ENDPOINTS {
+            let window = self.endpoint_latency.get(&kind);
+            match window.map(|w| (w.p50(), w.p95(), w.len())) {
+                Some((Some(p50), Some(p95), samples)) => println!(
+                    "    {:<10} {:>6.0}ms / {:>6.0}ms  ({} samples)",
+                    kind.label(),
+                    p50.as_secs_f64() * 1000.0,
+                    p95.as_secs_f64() * 1000.0,
+                    samples
+                ),
+                _ => println!("    {:<10} \x1b[2mno samples yet\x1b[0m", kind.label()),
+            }
+        }
 
-        let enter_hint = if self.mode == ViewMode::PipelineView {
-            "Enter:Rerun"
-        } else {
-            "Enter:Cycle"
-        };
+        println!("\n  \x1b[1mCache Hit Ratio\x1b[0m");
+        for kind in ENDPOINTS {
+            match self.cache_counters.get(&kind).and_then(|c| c.hit_ratio()) {
+                Some(ratio) => println!("    {:<10} {:.0}%", kind.label(), ratio * 100.0),
+                None => println!("    {:<10} \x1b[2mno lookups yet\x1b[0m", kind.label()),
+            }
+        }
 
-        let help = format!(
-            "\x1b[2m[{}] 1:List 2:Detail 3:Pipeline 4:Agents 5:Graph 6:Health 7:Logs | j/k:Navigate g/G:Top/Bottom {} r:Refresh q:Quit\x1b[0m",
-            view_mode, enter_hint
-        );
+        println!("\n  \x1b[1mPipeline Stage Outcomes\x1b[0m");
+        match self.stage_tally.failure_rate() {
+            Some(rate) => println!(
+                "    {} passed | {} failed | {:.0}% failure rate",
+                self.stage_tally.passed,
+                self.stage_tally.failed,
+                rate * 100.0
+            ),
+            None => println!("    \x1b[2mno stages completed yet\x1b[0m"),
+        }
 
-        self.last_error.as_ref().map_or_else(
-            || println!("{}", help),
-            |err| {
-                println!(
-                    "\x1b[31mError: {}\x1b[0m",
-                    truncate(err, cols.saturating_sub(7))
-                )
-            },
+        println!("\n  \x1b[1mFleet Health (EMA)\x1b[0m");
+        let fleet_rows: [(&str, &plugin_metrics::EmaMetric); 6] = [
+            ("idle", &self.fleet_metrics.idle),
+            ("working", &self.fleet_metrics.working),
+            ("unhealthy", &self.fleet_metrics.unhealthy),
+            ("mean health", &self.fleet_metrics.mean_health_score),
+            ("beads done", &self.fleet_metrics.beads_completed),
+            ("ops executed", &self.fleet_metrics.operations_executed),
+        ];
+        for (label, metric) in fleet_rows {
+            match metric.current() {
+                Some(value) => println!(
+                    "    {:<14} {:>8.2} {}  {}",
+                    label,
+                    value,
+                    metric.trend().arrow(),
+                    metric.sparkline()
+                ),
+                None => println!("    {:<14} \x1b[2mno samples yet\x1b[0m", label),
+            }
+        }
+    }
+
+    // Lower-level instrumentation dump than `render_system_health`: raw
+    // per-endpoint request counts with cache hit/miss tallies, p50/p95
+    // latency, and error-by-code tallies, for spotting a noisy endpoint or
+    // a spike in a particular failure mode.
+    fn render_metrics(&self, _rows: usize, cols: usize) {
+        const ENDPOINTS: [RequestType; 5] = [
+            RequestType::Beads,
+            RequestType::Pipeline,
+            RequestType::Agents,
+            RequestType::Graph,
+            RequestType::Subscription,
+        ];
+
+        println!("\n  \x1b[1mDashboard Metrics\x1b[0m");
+        println!("  {}", "─".repeat(cols.saturating_sub(2)));
+
+        println!(
+            "\n  \x1b[1mRequests\x1b[0m  (total: {})",
+            self.total_requests
+        );
+        for kind in ENDPOINTS {
+            let requests = self.requests_by_type.get(&kind).copied().unwrap_or(0);
+            let counters = self.cache_counters.get(&kind).copied().unwrap_or_default();
+            match counters.hit_ratio() {
+                Some(ratio) => println!(
+                    "    {:<12} {:>6} sent  | {:>4} hits / {:>4} misses ({:.0}% hit rate)",
+                    kind.label(),
+                    requests,
+                    counters.hits,
+                    counters.misses,
+                    ratio * 100.0
+                ),
+                None => println!(
+                    "    {:<12} {:>6} sent  | \x1b[2mno cache lookups yet\x1b[0m",
+                    kind.label(),
+                    requests
+                ),
+            }
+        }
+
+        println!("\n  \x1b[1mLatency (p50 / p95)\x1b[0m");
+        for kind in ENDPOINTS {
+            match self.endpoint_latency.get(&kind).map(|w| (w.p50(), w.p95())) {
+                Some((Some(p50), Some(p95))) => println!(
+                    "    {:<12} {:>6.0}ms / {:>6.0}ms",
+                    kind.label(),
+                    p50.as_secs_f64() * 1000.0,
+                    p95.as_secs_f64() * 1000.0
+                ),
+                _ => println!("    {:<12} \x1b[2mno samples yet\x1b[0m", kind.label()),
+            }
+        }
+
+        println!("\n  \x1b[1mErrors by code\x1b[0m");
+        if self.error_counts.is_empty() {
+            println!("    \x1b[2mno errors recorded\x1b[0m");
+        } else {
+            let mut codes: Vec<(&str, u64)> =
+                self.error_counts.iter().map(|(c, n)| (*c, *n)).collect();
+            codes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+            for (code, count) in codes {
+                println!("    {:<16} {}", code, count);
+            }
+        }
+    }
+
+    fn render_log_aggregator(&self, rows: usize, cols: usize) {
+        println!("\n  \x1b[1mLog Aggregator\x1b[0m");
+        println!("  {}", "─".repeat(cols.saturating_sub(2)));
+        println!();
+
+        // Counts are over the full unfiltered buffer, so operators still see
+        // totals while a filter narrows what's listed below.
+        let info_count = self
+            .log_entries
+            .iter()
+            .filter(|e| e.level == EventLevel::Info)
+            .count();
+        let warning_count = self
+            .log_entries
+            .iter()
+            .filter(|e| e.level == EventLevel::Warning)
+            .count();
+        let error_count = self
+            .log_entries
+            .iter()
+            .filter(|e| e.level == EventLevel::Error)
+            .count();
+        println!(
+            "  \x1b[1mTotals:\x1b[0m {} info | {} warning | {} error",
+            info_count, warning_count, error_count
+        );
+
+        let query = self.log_query.as_ref().map(|q| q.to_lowercase());
+        println!(
+            "  \x1b[1mFilter:\x1b[0m floor={} query={}",
+            self.log_level_floor.label(),
+            query.as_deref().unwrap_or("(none)")
         );
+        if let Some(draft) = &self.log_query_draft {
+            println!("  \x1b[33msearch> {}\x1b[0m", draft);
+        }
+        println!();
+
+        if self.log_entries.is_empty() {
+            println!("  \x1b[2mNo log entries yet\x1b[0m");
+            return;
+        }
+
+        let message_width = cols.saturating_sub(20);
+        let mut shown = 0usize;
+        for entry in self.log_entries.iter().rev() {
+            if entry.level < self.log_level_floor {
+                continue;
+            }
+            if let Some(query) = &query {
+                let matches = entry.message.to_lowercase().contains(query.as_str())
+                    || entry.source.to_lowercase().contains(query.as_str());
+                if !matches {
+                    continue;
+                }
+            }
+            if shown >= rows {
+                break;
+            }
+
+            let age = format_event_age(entry.occurred_at);
+            println!(
+                "  {}{}\x1b[0m {:>4} {:<12} {}",
+                entry.level.color(),
+                entry.level.symbol(),
+                age,
+                truncate(&entry.source, 12),
+                truncate(&entry.message, message_width)
+            );
+            shown += 1;
+        }
+
+        if shown == 0 {
+            println!("  \x1b[2mNo entries match the current filter\x1b[0m");
+        }
+    }
+
+    fn render_footer(&self, rows: usize, cols: usize) {
+        print!("\x1b[{};1H", rows.saturating_sub(1));
+
+        let view_mode = match self.mode {
+            ViewMode::BeadList => "List",
+            ViewMode::BeadDetail => "Detail",
+            ViewMode::PipelineView => "Pipeline",
+            ViewMode::AgentView => "Agents",
+            ViewMode::GraphView => "Graph",
+            ViewMode::SystemHealth => "Health",
+            ViewMode::LogAggregator => "Logs",
+            ViewMode::Metrics => "Metrics",
+        };
+
+        println!("{}", "─".repeat(cols));
+
+        let enter_hint = if self.mode == ViewMode::PipelineView {
+            "Enter:Rerun"
+        } else {
+            "Enter:Cycle"
+        };
+
+        let help = if self.mode == ViewMode::LogAggregator {
+            format!(
+                "\x1b[2m[{}] /:Search 1:Info+ 2:Warning+ 3:Error+ | j/k:Navigate g/G:Top/Bottom r:Refresh q:Quit\x1b[0m",
+                view_mode
+            )
+        } else {
+            format!(
+                "\x1b[2m[{}] 1:List 2:Detail 3:Pipeline 4:Agents 5:Graph 6:Health 7:Logs 8:Metrics | j/k:Navigate g/G:Top/Bottom {} r:Refresh q:Quit\x1b[0m",
+                view_mode, enter_hint
+            )
+        };
+
+        // Errors are surfaced in the header now, with a code and a
+        // retryable-vs-fatal color - the footer just keeps its key hints.
+        println!("{}", help);
+    }
+
+    // Samples fleet-wide aggregates into `fleet_metrics` every time the agent
+    // list changes, so the EMA has a data point per refresh rather than per
+    // render (which would sample at the terminal's redraw rate instead of
+    // the server's).
+    fn update_fleet_metrics(mut self, next_agents: &Vector<AgentInfo>) -> Self {
+        let idle = next_agents
+            .iter()
+            .filter(|a| a.state == AgentState::Idle)
+            .count() as f64;
+        let working = next_agents
+            .iter()
+            .filter(|a| a.state == AgentState::Working)
+            .count() as f64;
+        let unhealthy = next_agents
+            .iter()
+            .filter(|a| a.state == AgentState::Unhealthy)
+            .count() as f64;
+        let mean_health_score = if next_agents.is_empty() {
+            0.0
+        } else {
+            next_agents.iter().map(|a| a.health_score).sum::<f64>() / next_agents.len() as f64
+        };
+        let beads_completed = next_agents
+            .iter()
+            .map(|a| a.workload_history.beads_completed)
+            .sum::<u64>() as f64;
+        let operations_executed = next_agents
+            .iter()
+            .map(|a| a.workload_history.operations_executed)
+            .sum::<u64>() as f64;
+
+        self.fleet_metrics.idle.record(idle);
+        self.fleet_metrics.working.record(working);
+        self.fleet_metrics.unhealthy.record(unhealthy);
+        self.fleet_metrics.mean_health_score.record(mean_health_score);
+        self.fleet_metrics.beads_completed.record(beads_completed);
+        self.fleet_metrics
+            .operations_executed
+            .record(operations_executed);
+        self
     }
 
     fn update_agent_events(mut self, next_agents: &Vector<AgentInfo>) -> Self {
@@ -1731,6 +4086,7 @@ impl State {
             match previous_by_id.remove(agent_id) {
                 None => {
                     self = self.push_agent_event(
+                        agent_id,
                         EventLevel::Info,
                         format!("Agent {} registered", agent_id),
                     );
@@ -1743,6 +4099,7 @@ impl State {
                             _ => EventLevel::Info,
                         };
                         self = self.push_agent_event(
+                            agent_id,
                             level,
                             format!(
                                 "Agent {} state {} → {}",
@@ -1757,18 +4114,21 @@ impl State {
                         match (&previous.current_bead, &next_agent.current_bead) {
                             (None, Some(bead)) => {
                                 self = self.push_agent_event(
+                                    agent_id,
                                     EventLevel::Info,
                                     format!("Agent {} assigned bead {}", agent_id, bead),
                                 );
                             }
                             (Some(bead), None) => {
                                 self = self.push_agent_event(
+                                    agent_id,
                                     EventLevel::Info,
                                     format!("Agent {} released bead {}", agent_id, bead),
                                 );
                             }
                             (Some(previous_bead), Some(next_bead)) => {
                                 self = self.push_agent_event(
+                                    agent_id,
                                     EventLevel::Info,
                                     format!(
                                         "Agent {} switched bead {} → {}",
@@ -1789,6 +4149,7 @@ impl State {
                             HealthBand::Critical => EventLevel::Error,
                         };
                         self = self.push_agent_event(
+                            agent_id,
                             level,
                             format!(
                                 "Agent {} health {:.0}% → {:.0}%",
@@ -1803,22 +4164,41 @@ impl State {
         }
 
         for (agent_id, _) in previous_by_id.iter() {
-            self =
-                self.push_agent_event(EventLevel::Warning, format!("Agent {} removed", agent_id));
+            self = self.push_agent_event(
+                agent_id,
+                EventLevel::Warning,
+                format!("Agent {} removed", agent_id),
+            );
         }
 
         self
     }
 
-    fn push_agent_event(mut self, level: EventLevel, message: String) -> Self {
+    // Pushes to both `agent_events` (this agent's own timeline) and
+    // `log_entries` (the cross-source feed behind the LogAggregator view) in
+    // one call, so the two never drift out of sync with each other.
+    fn push_agent_event(mut self, source: &str, level: EventLevel, message: String) -> Self {
         self.agent_events.push_back(AgentEvent {
-            message,
+            message: message.clone(),
             level,
             occurred_at: Instant::now(),
         });
         while self.agent_events.len() > AGENT_EVENT_LIMIT {
             self.agent_events.pop_front();
         }
+        self.push_log_entry(source.to_string(), level, message)
+    }
+
+    fn push_log_entry(mut self, source: String, level: EventLevel, message: String) -> Self {
+        self.log_entries.push_back(LogEntry {
+            source,
+            level,
+            message,
+            occurred_at: Instant::now(),
+        });
+        while self.log_entries.len() > LOG_ENTRY_LIMIT {
+            self.log_entries.pop_front();
+        }
         self
     }
 
@@ -2072,8 +4452,440 @@ mod tests {
     }
 
     #[test]
-    fn test_view_mode_has_seven_variants() {
-        // Verify all 7 ViewMode variants are present and usable
+    fn test_is_owner_idle_is_false_for_the_active_view() {
+        let mut state = State {
+            mode: ViewMode::AgentView,
+            ..Default::default()
+        };
+        state
+            .view_last_active
+            .insert(ViewMode::AgentView, Instant::now() - CACHE_DISCARD_THRESHOLD * 2);
+
+        assert!(!state.is_owner_idle(&[ViewMode::AgentView]));
+    }
+
+    #[test]
+    fn test_is_owner_idle_is_true_once_threshold_elapses() {
+        let mut state = State::default();
+        state
+            .view_last_active
+            .insert(ViewMode::AgentView, Instant::now() - CACHE_DISCARD_THRESHOLD * 2);
+
+        assert!(state.is_owner_idle(&[ViewMode::AgentView]));
+    }
+
+    #[test]
+    fn test_is_owner_idle_is_true_when_never_visited() {
+        let state = State::default();
+        assert!(state.is_owner_idle(&[ViewMode::AgentView]));
+    }
+
+    #[test]
+    fn test_sweep_idle_caches_discards_agents_cache_when_inactive() {
+        let mut state = State {
+            mode: ViewMode::BeadList,
+            agents: to_vector(vec![build_agent("agent-1", AgentState::Idle, None, 0.9)]),
+            agents_cache: Some(Cached::fresh(to_vector(vec![build_agent(
+                "agent-1",
+                AgentState::Idle,
+                None,
+                0.9,
+            )]))),
+            ..Default::default()
+        };
+        state
+            .view_last_active
+            .insert(ViewMode::AgentView, Instant::now() - CACHE_DISCARD_THRESHOLD * 2);
+
+        let state = state.sweep_idle_caches();
+
+        assert!(matches!(state.agents_cache, Some(Cached::Discarded)));
+        assert!(state.agents.is_empty());
+    }
+
+    #[test]
+    fn test_sweep_idle_caches_keeps_the_active_views_cache_live() {
+        let mut state = State {
+            mode: ViewMode::AgentView,
+            agents: to_vector(vec![build_agent("agent-1", AgentState::Idle, None, 0.9)]),
+            agents_cache: Some(Cached::fresh(to_vector(vec![build_agent(
+                "agent-1",
+                AgentState::Idle,
+                None,
+                0.9,
+            )]))),
+            ..Default::default()
+        };
+        state
+            .view_last_active
+            .insert(ViewMode::AgentView, Instant::now() - CACHE_DISCARD_THRESHOLD * 2);
+
+        let state = state.sweep_idle_caches();
+
+        assert!(matches!(state.agents_cache, Some(Cached::Live(..))));
+        assert!(!state.agents.is_empty());
+    }
+
+    #[test]
+    fn test_sweep_idle_caches_clamps_selected_stage_index() {
+        let mut pipeline_caches = HashMap::new();
+        pipeline_caches.insert(
+            "bead-1".to_string(),
+            Cached::fresh(to_vector_stages(vec![StageInfo {
+                name: "stage-1".to_string(),
+                status: StageStatus::Pending,
+                duration_ms: None,
+                exit_code: None,
+            }])),
+        );
+        let mut state = State {
+            mode: ViewMode::BeadList,
+            pipeline_stages: to_vector_stages(vec![StageInfo {
+                name: "stage-1".to_string(),
+                status: StageStatus::Pending,
+                duration_ms: None,
+                exit_code: None,
+            }]),
+            selected_stage_index: 0,
+            pipeline_caches,
+            ..Default::default()
+        };
+        state
+            .view_last_active
+            .insert(ViewMode::PipelineView, Instant::now() - CACHE_DISCARD_THRESHOLD * 2);
+
+        let state = state.sweep_idle_caches();
+
+        assert!(state.pipeline_stages.is_empty());
+        assert_eq!(state.selected_stage_index, 0);
+        assert!(state
+            .pipeline_caches
+            .get("bead-1")
+            .is_some_and(Cached::is_discarded));
+    }
+
+    #[test]
+    fn test_backoff_for_doubles_and_caps() {
+        assert_eq!(backoff_for(1), Duration::from_secs(1));
+        assert_eq!(backoff_for(2), Duration::from_secs(2));
+        assert_eq!(backoff_for(3), Duration::from_secs(4));
+        assert_eq!(backoff_for(10), BACKOFF_MAX);
+    }
+
+    #[test]
+    fn test_check_request_timeouts_schedules_backoff_without_disconnecting() {
+        let mut state = State::default();
+        state
+            .request_sent_at
+            .insert(RequestType::Graph, Instant::now() - REQUEST_TIMEOUT * 2);
+
+        let state = state.check_request_timeouts();
+
+        assert!(state.request_sent_at.get(&RequestType::Graph).is_none());
+        assert_eq!(state.request_failures.get(&RequestType::Graph), Some(&1));
+        assert!(state.retry_after.contains_key(&RequestType::Graph));
+        assert!(state.api_connected);
+        assert!(state
+            .last_error
+            .as_ref()
+            .is_some_and(|err| err.message().contains("graph")));
+    }
+
+    #[test]
+    fn test_check_request_timeouts_disconnects_after_threshold_across_types() {
+        let mut state = State::default();
+        state.request_failures.insert(RequestType::Beads, 1);
+        state.request_failures.insert(RequestType::Agents, 1);
+        state
+            .request_sent_at
+            .insert(RequestType::Graph, Instant::now() - REQUEST_TIMEOUT * 2);
+
+        let state = state.check_request_timeouts();
+
+        assert!(!state.api_connected);
+    }
+
+    #[test]
+    fn test_is_retry_due_false_until_backoff_elapses() {
+        let mut state = State::default();
+        state
+            .retry_after
+            .insert(RequestType::Beads, Instant::now() + Duration::from_secs(30));
+
+        assert!(!state.is_retry_due(RequestType::Beads));
+
+        state
+            .retry_after
+            .insert(RequestType::Beads, Instant::now() - Duration::from_secs(1));
+
+        assert!(state.is_retry_due(RequestType::Beads));
+    }
+
+    #[test]
+    fn test_retry_delay_for_doubles_and_caps() {
+        assert_eq!(retry_delay_for(1), Duration::from_millis(250));
+        assert_eq!(retry_delay_for(2), Duration::from_millis(500));
+        assert_eq!(retry_delay_for(3), Duration::from_secs(1));
+        assert_eq!(retry_delay_for(10), RETRY_MAX_DELAY);
+    }
+
+    #[test]
+    fn test_handle_server_error_schedules_retry_without_giving_up() {
+        let state = State::default();
+        let state = state.handle_server_error(Some(RequestType::Beads), 503);
+
+        assert!(!state.api_connected);
+        assert_eq!(state.request_failures.get(&RequestType::Beads), Some(&1));
+        assert!(state.retry_after.contains_key(&RequestType::Beads));
+        assert!(state
+            .last_error
+            .as_ref()
+            .is_some_and(|err| err.message().contains("attempt 1")));
+    }
+
+    #[test]
+    fn test_handle_server_error_gives_up_after_max_attempts() {
+        let mut state = State::default();
+        state
+            .request_failures
+            .insert(RequestType::Beads, MAX_RETRY_ATTEMPTS);
+
+        let state = state.handle_server_error(Some(RequestType::Beads), 500);
+
+        assert!(!state.retry_after.contains_key(&RequestType::Beads));
+        assert!(!state.is_retry_due(RequestType::Beads));
+        assert!(state
+            .last_error
+            .as_ref()
+            .is_some_and(|err| err.message().contains("giving up")));
+    }
+
+    #[test]
+    fn test_handle_refresh_resets_exhausted_retry_state() {
+        let mut state = State::default();
+        state
+            .request_failures
+            .insert(RequestType::Beads, MAX_RETRY_ATTEMPTS + 1);
+        state
+            .retry_after
+            .insert(RequestType::Beads, Instant::now() + Duration::from_secs(60));
+
+        let (state, _) = state.handle_refresh();
+
+        assert!(!state.request_failures.contains_key(&RequestType::Beads));
+        assert!(!state.retry_after.contains_key(&RequestType::Beads));
+        assert!(state.is_retry_due(RequestType::Beads));
+    }
+
+    #[test]
+    fn test_has_stale_request_true_past_warning_threshold() {
+        let mut state = State::default();
+        assert!(!state.has_stale_request());
+
+        state
+            .request_sent_at
+            .insert(RequestType::Beads, Instant::now() - STALE_REQUEST_WARNING * 2);
+
+        assert!(state.has_stale_request());
+    }
+
+    #[test]
+    fn test_apply_bead_delta_upserts_and_removes() {
+        let state = State::default();
+        let state = state.apply_bead_delta(
+            "upsert",
+            "bead-1",
+            Some("Bead One".to_string()),
+            Some("in_progress".to_string()),
+            Some("build".to_string()),
+            Some(0.5),
+        );
+        assert_eq!(state.beads.len(), 1);
+        assert_eq!(state.beads.get(0).unwrap().title, "Bead One");
+        assert_eq!(state.beads.get(0).unwrap().status, BeadStatus::InProgress);
+
+        let state = state.apply_bead_delta(
+            "upsert",
+            "bead-1",
+            None,
+            Some("completed".to_string()),
+            None,
+            Some(1.0),
+        );
+        assert_eq!(state.beads.len(), 1);
+        assert_eq!(state.beads.get(0).unwrap().status, BeadStatus::Completed);
+        assert_eq!(state.beads.get(0).unwrap().title, "Bead One");
+
+        let state = state.apply_bead_delta("remove", "bead-1", None, None, None, None);
+        assert!(state.beads.is_empty());
+    }
+
+    #[test]
+    fn test_apply_stage_delta_counts_transition_into_tally() {
+        let state = State::default();
+        let state = state.apply_stage_delta("upsert", "build", Some("running".to_string()), None, None);
+        assert_eq!(state.pipeline_stages.len(), 1);
+
+        let state =
+            state.apply_stage_delta("upsert", "build", Some("passed".to_string()), Some(100), Some(0));
+        assert_eq!(state.stage_tally.passed, 1);
+        assert_eq!(
+            state.pipeline_stages.get(0).unwrap().status as u8,
+            StageStatus::Passed as u8
+        );
+    }
+
+    #[test]
+    fn test_apply_agent_delta_upserts_and_removes() {
+        let state = State::default();
+        let state = state.apply_agent_delta(
+            "upsert",
+            "agent-1",
+            Some("working".to_string()),
+            Some("bead-1".to_string()),
+            Some(0.9),
+            Some(120),
+        );
+        assert_eq!(state.agents.len(), 1);
+        assert_eq!(state.agents.get(0).unwrap().state, AgentState::Working);
+
+        let state = state.apply_agent_delta("remove", "agent-1", None, None, None, None);
+        assert!(state.agents.is_empty());
+    }
+
+    #[test]
+    fn test_parse_subscription_response_applies_bead_and_agent_deltas() {
+        let state = State::default();
+        let body = br#"{
+            "cursor": "42",
+            "events": [
+                {"kind": "bead", "op": "upsert", "id": "bead-1", "title": "Bead One", "status": "in_progress"},
+                {"kind": "agent", "op": "upsert", "id": "agent-1", "state": "working"}
+            ]
+        }"#;
+
+        let state = state.parse_subscription_response(body);
+
+        assert_eq!(state.subscription_cursor.as_deref(), Some("42"));
+        assert_eq!(state.beads.len(), 1);
+        assert_eq!(state.agents.len(), 1);
+    }
+
+    #[test]
+    fn test_handle_web_response_subscription_404_disables_it_permanently() {
+        let state = State::default();
+        let mut context = BTreeMap::new();
+        context.insert(CTX_REQUEST_TYPE.to_string(), CTX_SUBSCRIPTION.to_string());
+
+        let (state, _) = state.handle_web_response(404, BTreeMap::new(), vec![], context);
+
+        assert_eq!(state.subscription_supported, Some(false));
+        assert!(state.last_error.is_none());
+        assert!(!state.request_failures.contains_key(&RequestType::Subscription));
+    }
+
+    #[test]
+    fn test_load_subscription_skips_when_unsupported() {
+        let mut state = State::default();
+        state.subscription_supported = Some(false);
+
+        let (state, _) = state.load_subscription();
+
+        assert!(!state.request_sent_at.contains_key(&RequestType::Subscription));
+    }
+
+    #[test]
+    fn test_handle_web_response_success_clears_failures_for_its_type() {
+        let mut state = State::default();
+        state.request_failures.insert(RequestType::Beads, 2);
+        state
+            .retry_after
+            .insert(RequestType::Beads, Instant::now() + Duration::from_secs(10));
+
+        let mut context = BTreeMap::new();
+        context.insert(CTX_REQUEST_TYPE.to_string(), CTX_BEADS_LIST.to_string());
+
+        let (state, _) = state.handle_web_response(200, BTreeMap::new(), b"[]".to_vec(), context);
+
+        assert!(!state.request_failures.contains_key(&RequestType::Beads));
+        assert!(!state.retry_after.contains_key(&RequestType::Beads));
+    }
+
+    #[test]
+    fn test_handle_web_response_records_endpoint_latency() {
+        let mut state = State::default();
+        state
+            .request_sent_at
+            .insert(RequestType::Beads, Instant::now() - Duration::from_millis(5));
+
+        let mut context = BTreeMap::new();
+        context.insert(CTX_REQUEST_TYPE.to_string(), CTX_BEADS_LIST.to_string());
+
+        let (state, _) = state.handle_web_response(200, BTreeMap::new(), b"[]".to_vec(), context);
+
+        let window = state
+            .endpoint_latency
+            .get(&RequestType::Beads)
+            .expect("latency should be recorded for the completed request type");
+        assert_eq!(window.len(), 1);
+    }
+
+    #[test]
+    fn test_load_beads_records_a_cache_hit_without_issuing_a_request() {
+        let mut state = State::default();
+        state.beads_cache = Some(Cached::fresh(Vector::new()));
+
+        let (state, _) = state.load_beads();
+
+        assert_eq!(
+            state.cache_counters.get(&RequestType::Beads).map(|c| c.hits),
+            Some(1)
+        );
+        assert!(!state.request_sent_at.contains_key(&RequestType::Beads));
+    }
+
+    #[test]
+    fn test_cache_counters_track_hits_and_misses() {
+        let mut state = State::default();
+        state.record_cache_hit(RequestType::Beads);
+        state.record_cache_miss(RequestType::Beads);
+        state.record_cache_miss(RequestType::Beads);
+
+        let counters = state
+            .cache_counters
+            .get(&RequestType::Beads)
+            .expect("counters should exist after recording");
+        assert_eq!(counters.hits, 1);
+        assert_eq!(counters.misses, 2);
+    }
+
+    #[test]
+    fn test_record_stage_transitions_counts_each_terminal_stage_once() {
+        let previous = to_vector_stages(vec![StageInfo {
+            name: "build".to_string(),
+            status: StageStatus::Running,
+            duration_ms: None,
+            exit_code: None,
+        }]);
+        let next = to_vector_stages(vec![StageInfo {
+            name: "build".to_string(),
+            status: StageStatus::Passed,
+            duration_ms: Some(100),
+            exit_code: Some(0),
+        }]);
+
+        let state = State::default().record_stage_transitions(&previous, &next);
+        assert_eq!(state.stage_tally.passed, 1);
+        assert_eq!(state.stage_tally.failed, 0);
+
+        // Re-observing the same terminal status should not double-count.
+        let state = state.record_stage_transitions(&next, &next);
+        assert_eq!(state.stage_tally.passed, 1);
+    }
+
+    #[test]
+    fn test_view_mode_has_eight_variants() {
+        // Verify all 8 ViewMode variants are present and usable
         let _ = ViewMode::BeadList;
         let _ = ViewMode::BeadDetail;
         let _ = ViewMode::PipelineView;
@@ -2081,9 +4893,460 @@ mod tests {
         let _ = ViewMode::GraphView;
         let _ = ViewMode::SystemHealth;
         let _ = ViewMode::LogAggregator;
+        let _ = ViewMode::Metrics;
 
         // Verify default is BeadList
         let default_mode = ViewMode::default();
         assert_eq!(default_mode, ViewMode::BeadList);
     }
+
+    #[test]
+    fn test_load_batch_with_no_requests_is_a_noop() {
+        let state = State::default();
+        let (state, _) = state.load_batch(vec![]);
+        assert_eq!(state.pending_requests, 0);
+        assert!(state.request_sent_at.is_empty());
+    }
+
+    #[test]
+    fn test_apply_batch_response_routes_each_sub_result() {
+        let mut state = State::default();
+        state.request_sent_at.insert(RequestType::Beads, Instant::now());
+        state.request_sent_at.insert(RequestType::Agents, Instant::now());
+
+        let body = br#"[
+            {"type": "beads_list", "body": [{"id": "bead-1", "title": "Bead One", "status": "pending"}]},
+            {"type": "agents_list", "body": [{"id": "agent-1", "state": "idle", "health_score": 1.0, "uptime_secs": 0}]}
+        ]"#;
+
+        let state = state.apply_batch_response(body);
+        assert_eq!(state.beads.len(), 1);
+        assert_eq!(state.agents.len(), 1);
+        assert!(state.beads_cache.is_some());
+        assert!(state.agents_cache.is_some());
+        assert!(!state.request_sent_at.contains_key(&RequestType::Beads));
+        assert!(!state.request_sent_at.contains_key(&RequestType::Agents));
+    }
+
+    #[test]
+    fn test_dashboard_error_retryability_and_codes() {
+        let server_error = DashboardError::ServerError {
+            status: 503,
+            detail: "boom".to_string(),
+        };
+        assert!(server_error.is_retryable());
+        assert_eq!(server_error.code(), "server_error");
+
+        let client_error = DashboardError::ClientError {
+            status: 404,
+            detail: "not found".to_string(),
+        };
+        assert!(!client_error.is_retryable());
+        assert_eq!(client_error.code(), "client_error");
+
+        assert!(DashboardError::Transport {
+            detail: "timed out".to_string(),
+        }
+        .is_retryable());
+        assert!(!DashboardError::Parse {
+            detail: "bad json".to_string(),
+        }
+        .is_retryable());
+        assert!(!DashboardError::InvalidUtf8.is_retryable());
+        assert_eq!(DashboardError::InvalidUtf8.code(), "invalid_utf8");
+    }
+
+    #[test]
+    fn test_parse_beads_response_invalid_utf8_is_classified() {
+        let state = State::default();
+        let state = state.parse_beads_response(&[0xff, 0xfe, 0xfd]);
+        assert_eq!(state.last_error, Some(DashboardError::InvalidUtf8));
+    }
+
+    #[test]
+    fn test_snapshot_path_sanitizes_server_url() {
+        let path = snapshot_path("http://example.com:3000/api");
+        assert!(!path.contains(':'));
+        assert!(!path.contains('/') || path.starts_with("./"));
+    }
+
+    #[test]
+    fn test_persist_and_load_snapshot_round_trips() {
+        let server_url = "http://unit-test-persist-snapshot-round-trip:1".to_string();
+        let path = snapshot_path(&server_url);
+        let _ = std::fs::remove_file(&path);
+
+        let mut state = State {
+            server_url: server_url.clone(),
+            ..State::default()
+        };
+        state.beads.push_back(BeadInfo {
+            id: "bead-1".to_string(),
+            title: "Bead One".to_string(),
+            status: BeadStatus::InProgress,
+            current_stage: Some("build".to_string()),
+            progress: 0.5,
+            history: Vector::new(),
+        });
+        state.agents.push_back(build_agent("agent-1", AgentState::Working, None, 0.9));
+        state.persist_snapshot();
+
+        let loaded = State {
+            server_url,
+            ..State::default()
+        }
+        .load_persisted_snapshot();
+
+        assert_eq!(loaded.beads.len(), 1);
+        assert_eq!(loaded.beads[0].id, "bead-1");
+        assert_eq!(loaded.agents.len(), 1);
+        assert!(loaded.showing_persisted_snapshot);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_load_persisted_snapshot_missing_file_is_a_noop() {
+        let state = State {
+            server_url: "http://unit-test-no-such-snapshot:1".to_string(),
+            ..State::default()
+        };
+        let state = state.load_persisted_snapshot();
+        assert!(state.beads.is_empty());
+        assert!(!state.showing_persisted_snapshot);
+    }
+
+    #[test]
+    fn test_record_request_sent_tallies_total_and_per_type() {
+        let mut state = State::default();
+        state.record_request_sent(RequestType::Beads);
+        state.record_request_sent(RequestType::Beads);
+        state.record_request_sent(RequestType::Agents);
+
+        assert_eq!(state.total_requests, 3);
+        assert_eq!(state.requests_by_type.get(&RequestType::Beads), Some(&2));
+        assert_eq!(state.requests_by_type.get(&RequestType::Agents), Some(&1));
+    }
+
+    #[test]
+    fn test_record_error_tallies_by_code_and_sets_last_error() {
+        let state = State::default();
+        let state = state.record_error(DashboardError::InvalidUtf8);
+        let state = state.record_error(DashboardError::InvalidUtf8);
+        let state = state.record_error(DashboardError::Parse {
+            detail: "bad json".to_string(),
+        });
+
+        assert_eq!(state.error_counts.get("invalid_utf8"), Some(&2));
+        assert_eq!(state.error_counts.get("parse_error"), Some(&1));
+        assert_eq!(
+            state.last_error,
+            Some(DashboardError::Parse {
+                detail: "bad json".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_switch_to_metrics_view_updates_mode() {
+        let state = State::default();
+        let (state, should_render) = state.switch_to_metrics_view();
+        assert_eq!(state.mode, ViewMode::Metrics);
+        assert!(should_render);
+    }
+
+    fn mock_graph_node(id: &str, duration_ms: Option<u64>) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            label: id.to_string(),
+            is_on_critical_path: false,
+            state: NodeState::Idle,
+            duration_ms,
+        }
+    }
+
+    fn mock_graph_edge(from: &str, to: &str) -> GraphEdge {
+        GraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            is_on_critical_path: false,
+        }
+    }
+
+    #[test]
+    fn test_compute_critical_path_diamond_picks_longer_branch() {
+        // a -> b -> d (weights 1+5+1 = 7), a -> c -> d (weights 1+1+1 = 3).
+        // The b branch is strictly longer, so only a/b/d and their
+        // connecting edges should be critical.
+        let mut state = State::default();
+        state.graph_nodes.push_back(mock_graph_node("a", Some(1)));
+        state.graph_nodes.push_back(mock_graph_node("b", Some(5)));
+        state.graph_nodes.push_back(mock_graph_node("c", Some(1)));
+        state.graph_nodes.push_back(mock_graph_node("d", Some(1)));
+        state.graph_edges.push_back(mock_graph_edge("a", "b"));
+        state.graph_edges.push_back(mock_graph_edge("a", "c"));
+        state.graph_edges.push_back(mock_graph_edge("b", "d"));
+        state.graph_edges.push_back(mock_graph_edge("c", "d"));
+
+        let critical_path = state.compute_critical_path().expect("no cycle");
+
+        assert!(critical_path.nodes.contains("a"));
+        assert!(critical_path.nodes.contains("b"));
+        assert!(critical_path.nodes.contains("d"));
+        assert!(!critical_path.nodes.contains("c"));
+        assert!(critical_path.edges.contains(&("a".to_string(), "b".to_string())));
+        assert!(critical_path.edges.contains(&("b".to_string(), "d".to_string())));
+        assert!(!critical_path.edges.contains(&("a".to_string(), "c".to_string())));
+    }
+
+    #[test]
+    fn test_compute_critical_path_unweighted_uses_unit_weights() {
+        // No durations set -> every node falls back to weight 1, so the
+        // critical path is simply the longest chain by node count.
+        let mut state = State::default();
+        state.graph_nodes.push_back(mock_graph_node("a", None));
+        state.graph_nodes.push_back(mock_graph_node("b", None));
+        state.graph_edges.push_back(mock_graph_edge("a", "b"));
+
+        let critical_path = state.compute_critical_path().expect("no cycle");
+        assert!(critical_path.nodes.contains("a"));
+        assert!(critical_path.nodes.contains("b"));
+        assert!(critical_path.edges.contains(&("a".to_string(), "b".to_string())));
+    }
+
+    #[test]
+    fn test_update_fleet_metrics_tallies_counts_and_mean_health() {
+        let state = State::default();
+        let agents = to_vector(vec![
+            build_agent("agent-1", AgentState::Idle, None, 0.8),
+            build_agent("agent-2", AgentState::Working, Some("bead-1"), 0.4),
+            build_agent("agent-3", AgentState::Unhealthy, None, 0.1),
+        ]);
+
+        let state = state.update_fleet_metrics(&agents);
+
+        assert_eq!(state.fleet_metrics.idle.current(), Some(1.0));
+        assert_eq!(state.fleet_metrics.working.current(), Some(1.0));
+        assert_eq!(state.fleet_metrics.unhealthy.current(), Some(1.0));
+        let mean_health = state.fleet_metrics.mean_health_score.current().unwrap();
+        assert!((mean_health - (0.8 + 0.4 + 0.1) / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_update_fleet_metrics_empty_fleet_records_zeros() {
+        let state = State::default();
+        let state = state.update_fleet_metrics(&Vector::new());
+
+        assert_eq!(state.fleet_metrics.idle.current(), Some(0.0));
+        assert_eq!(state.fleet_metrics.mean_health_score.current(), Some(0.0));
+    }
+
+    #[test]
+    fn test_compute_critical_path_returns_none_on_cycle() {
+        let mut state = State::default();
+        state.graph_nodes.push_back(mock_graph_node("a", Some(1)));
+        state.graph_nodes.push_back(mock_graph_node("b", Some(1)));
+        state.graph_edges.push_back(mock_graph_edge("a", "b"));
+        state.graph_edges.push_back(mock_graph_edge("b", "a"));
+
+        assert!(state.compute_critical_path().is_none());
+    }
+
+    #[test]
+    fn test_compute_node_ranks_diamond_ranks_by_longest_chain() {
+        // a -> b -> d and a -> c -> d: both branches are two hops long, so
+        // b and c share rank 1 and d sits at rank 2 regardless of branch.
+        let mut state = State::default();
+        state.graph_nodes.push_back(mock_graph_node("a", None));
+        state.graph_nodes.push_back(mock_graph_node("b", None));
+        state.graph_nodes.push_back(mock_graph_node("c", None));
+        state.graph_nodes.push_back(mock_graph_node("d", None));
+        state.graph_edges.push_back(mock_graph_edge("a", "b"));
+        state.graph_edges.push_back(mock_graph_edge("a", "c"));
+        state.graph_edges.push_back(mock_graph_edge("b", "d"));
+        state.graph_edges.push_back(mock_graph_edge("c", "d"));
+
+        let ranks = state.compute_node_ranks().expect("no cycle");
+        assert_eq!(ranks.get("a"), Some(&0));
+        assert_eq!(ranks.get("b"), Some(&1));
+        assert_eq!(ranks.get("c"), Some(&1));
+        assert_eq!(ranks.get("d"), Some(&2));
+    }
+
+    #[test]
+    fn test_compute_node_ranks_uses_longest_not_shortest_path_to_a_node() {
+        // a -> d directly (would suggest rank 1), but a -> b -> c -> d also
+        // reaches d, so d's rank must be 3 (longest chain), not 1.
+        let mut state = State::default();
+        state.graph_nodes.push_back(mock_graph_node("a", None));
+        state.graph_nodes.push_back(mock_graph_node("b", None));
+        state.graph_nodes.push_back(mock_graph_node("c", None));
+        state.graph_nodes.push_back(mock_graph_node("d", None));
+        state.graph_edges.push_back(mock_graph_edge("a", "d"));
+        state.graph_edges.push_back(mock_graph_edge("a", "b"));
+        state.graph_edges.push_back(mock_graph_edge("b", "c"));
+        state.graph_edges.push_back(mock_graph_edge("c", "d"));
+
+        let ranks = state.compute_node_ranks().expect("no cycle");
+        assert_eq!(ranks.get("d"), Some(&3));
+    }
+
+    #[test]
+    fn test_compute_node_ranks_returns_none_on_cycle() {
+        let mut state = State::default();
+        state.graph_nodes.push_back(mock_graph_node("a", None));
+        state.graph_nodes.push_back(mock_graph_node("b", None));
+        state.graph_edges.push_back(mock_graph_edge("a", "b"));
+        state.graph_edges.push_back(mock_graph_edge("b", "a"));
+
+        assert!(state.compute_node_ranks().is_none());
+    }
+
+    #[test]
+    fn test_update_agent_events_also_populates_log_entries() {
+        let state = State::default();
+        let agents = to_vector(vec![build_agent("agent-1", AgentState::Idle, None, 0.95)]);
+
+        let state = state.update_agent_events(&agents);
+
+        assert_eq!(state.log_entries.len(), 1);
+        let entry = state.log_entries.back().unwrap();
+        assert_eq!(entry.source, "agent-1");
+        assert!(entry.message.contains("registered"));
+    }
+
+    #[test]
+    fn test_log_level_floor_filters_lower_severity() {
+        assert!(EventLevel::Error > EventLevel::Warning);
+        assert!(EventLevel::Warning > EventLevel::Info);
+        assert!(EventLevel::Info < EventLevel::Error);
+    }
+
+    #[test]
+    fn test_log_query_key_handling_builds_and_commits_draft() {
+        let mut state = State::default();
+        state.mode = ViewMode::LogAggregator;
+
+        let (state, _) = state.handle_key_event(key_char('/'));
+        assert_eq!(state.log_query_draft, Some(String::new()));
+
+        let (state, _) = state.handle_key_event(key_char('a'));
+        let (state, _) = state.handle_key_event(key_char('b'));
+        assert_eq!(state.log_query_draft, Some("ab".to_string()));
+
+        let (state, _) = state.handle_key_event(key(BareKey::Enter));
+        assert_eq!(state.log_query, Some("ab".to_string()));
+        assert_eq!(state.log_query_draft, None);
+    }
+
+    #[test]
+    fn test_log_query_esc_cancels_draft_without_closing() {
+        let mut state = State::default();
+        state.mode = ViewMode::LogAggregator;
+        state.log_query_draft = Some("partial".to_string());
+
+        let (state, should_render) = state.handle_key_event(key(BareKey::Esc));
+        assert_eq!(state.log_query_draft, None);
+        assert!(should_render);
+    }
+
+    #[test]
+    fn test_log_aggregator_number_keys_set_level_floor() {
+        let mut state = State::default();
+        state.mode = ViewMode::LogAggregator;
+
+        let (state, _) = state.handle_key_event(key_char('2'));
+        assert_eq!(state.log_level_floor, EventLevel::Warning);
+
+        let (state, _) = state.handle_key_event(key_char('3'));
+        assert_eq!(state.log_level_floor, EventLevel::Error);
+    }
+
+    fn key(bare_key: BareKey) -> KeyWithModifier {
+        KeyWithModifier::new(bare_key)
+    }
+
+    fn key_char(c: char) -> KeyWithModifier {
+        key(BareKey::Char(c))
+    }
+
+    #[test]
+    fn test_agent_snapshot_round_trips_through_agent_info() {
+        let agent = build_agent("agent-1", AgentState::Working, Some("bead-1"), 0.75);
+        let snapshot = agent_info_to_snapshot(&agent);
+        let round_tripped = agent_snapshot_to_agent_info(snapshot);
+
+        assert_eq!(round_tripped.id, "agent-1");
+        assert_eq!(round_tripped.state, AgentState::Working);
+        assert_eq!(round_tripped.current_bead, Some("bead-1".to_string()));
+        assert!((round_tripped.health_score - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_with_offline_modes_starts_simulator_from_sim_agents_config() {
+        let mut configuration = BTreeMap::new();
+        configuration.insert("sim_agents".to_string(), "4".to_string());
+
+        let state = State::default().with_offline_modes(&configuration);
+
+        assert!(state.simulator.is_some());
+        assert!(state.replayer.is_none());
+    }
+
+    #[test]
+    fn test_with_offline_modes_ignores_replay_path_that_does_not_exist() {
+        let mut configuration = BTreeMap::new();
+        configuration.insert(
+            "replay_path".to_string(),
+            "/nonexistent/oya_replay_missing.jsonl".to_string(),
+        );
+
+        let state = State::default().with_offline_modes(&configuration);
+
+        assert!(state.replayer.is_none());
+    }
+
+    #[test]
+    fn test_tick_simulation_populates_agents_and_events() {
+        let mut state = State::default();
+        state.simulator = Some(sim::Simulator::new(sim::SimScenario::default_fleet(3)));
+
+        let state = state.tick_simulation();
+
+        assert_eq!(state.agents.len(), 3);
+        assert!(!state.log_entries.is_empty());
+    }
+
+    #[test]
+    fn test_tick_simulation_is_a_no_op_without_a_simulator() {
+        let state = State::default();
+        let state = state.tick_simulation();
+        assert!(state.agents.is_empty());
+    }
+
+    #[test]
+    fn test_tick_replay_applies_the_elapsed_frame() {
+        let mut state = State::default();
+        let frame = sim::RecordedFrame {
+            captured_at_ms: 0,
+            agents: vec![sim::AgentSnapshot {
+                id: "replay-agent".to_string(),
+                state: "working".to_string(),
+                current_bead: Some("bead-1".to_string()),
+                health_score: 0.6,
+                uptime_secs: 5,
+            }],
+            graph_nodes: vec![],
+            graph_edges: vec![],
+        };
+        let replayer = sim::Replayer::from_frames(vec![frame]);
+        state.replayer = Some(replayer);
+        state.replay_started_at = Some(Instant::now());
+        state.replay_speed = 1.0;
+
+        let state = state.tick_replay();
+
+        assert_eq!(state.agents.len(), 1);
+        assert_eq!(state.agents[0].id, "replay-agent");
+    }
 }
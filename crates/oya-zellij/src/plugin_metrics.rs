@@ -0,0 +1,341 @@
+//! Self-instrumentation for the dashboard plugin itself.
+//!
+//! The `SystemHealth` view reports what the backend says about its own
+//! health, but that tells us nothing about whether *this* plugin's own API
+//! layer is keeping up. These types track per-endpoint round-trip latency,
+//! cache effectiveness, and pipeline stage outcomes so the dashboard can
+//! report on itself independently of the backend.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Number of latency samples kept per endpoint. Percentiles are computed by
+/// copying the window into a scratch vector and sorting it on demand - cheap
+/// at this size and avoids maintaining a live histogram.
+const LATENCY_WINDOW: usize = 256;
+
+/// A fixed-size ring buffer of round-trip latency samples for one endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct LatencyWindow {
+    samples: Vec<Duration>,
+    next: usize,
+}
+
+impl LatencyWindow {
+    pub fn record(&mut self, latency: Duration) {
+        if self.samples.len() < LATENCY_WINDOW {
+            self.samples.push(latency);
+        } else {
+            self.samples[self.next] = latency;
+        }
+        self.next = (self.next + 1) % LATENCY_WINDOW;
+    }
+
+    /// Percentile in `[0.0, 100.0]`, or `None` if no samples have been recorded.
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        let rank = ((p * sorted.len() as f64) / 100.0).ceil() as usize;
+        sorted.get(rank.saturating_sub(1)).copied()
+    }
+
+    pub fn p50(&self) -> Option<Duration> {
+        self.percentile(50.0)
+    }
+
+    pub fn p95(&self) -> Option<Duration> {
+        self.percentile(95.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+/// Cache hit/miss counters for one cache.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CacheCounters {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheCounters {
+    pub fn record_hit(&mut self) {
+        self.hits = self.hits.saturating_add(1);
+    }
+
+    pub fn record_miss(&mut self) {
+        self.misses = self.misses.saturating_add(1);
+    }
+
+    /// Fraction of lookups that were hits, or `None` if there have been none yet.
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            None
+        } else {
+            Some(self.hits as f64 / total as f64)
+        }
+    }
+}
+
+/// Pass/fail tally for pipeline stages, derived from observed status
+/// transitions rather than a point-in-time snapshot.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StageTally {
+    pub passed: u64,
+    pub failed: u64,
+}
+
+impl StageTally {
+    pub fn record_pass(&mut self) {
+        self.passed = self.passed.saturating_add(1);
+    }
+
+    pub fn record_fail(&mut self) {
+        self.failed = self.failed.saturating_add(1);
+    }
+
+    /// Fraction of completed stages that failed, or `None` if none have completed yet.
+    pub fn failure_rate(&self) -> Option<f64> {
+        let total = self.passed + self.failed;
+        if total == 0 {
+            None
+        } else {
+            Some(self.failed as f64 / total as f64)
+        }
+    }
+}
+
+/// Number of samples kept per fleet metric, for sparkline rendering.
+const FLEET_METRIC_WINDOW: usize = 20;
+
+/// Smoothing factor for the fast EMA - the displayed current reading.
+const EMA_ALPHA_FAST: f64 = 0.2;
+
+/// Smoothing factor for the slow EMA - the trend baseline compared against
+/// the fast EMA.
+const EMA_ALPHA_SLOW: f64 = 0.05;
+
+/// Unicode sparkline glyphs, in increasing order of magnitude.
+const SPARKLINE_GLYPHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Trend direction from comparing a metric's fast and slow EMA.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Flat,
+}
+
+impl Trend {
+    pub fn arrow(&self) -> &str {
+        match self {
+            Self::Rising => "▲",
+            Self::Falling => "▼",
+            Self::Flat => "–",
+        }
+    }
+}
+
+/// A dual exponential-moving-average tracker with a bounded sample ring
+/// buffer, for metrics that are noisy on a per-refresh basis (fleet agent
+/// counts, mean health score, cumulative workload). The fast EMA is the
+/// displayed current reading; comparing it to the slow EMA gives a trend
+/// arrow without flickering on every transient spike.
+#[derive(Clone, Debug, Default)]
+pub struct EmaMetric {
+    samples: VecDeque<f64>,
+    fast: Option<f64>,
+    slow: Option<f64>,
+}
+
+impl EmaMetric {
+    pub fn record(&mut self, sample: f64) {
+        self.fast = Some(match self.fast {
+            Some(ema) => ema + EMA_ALPHA_FAST * (sample - ema),
+            None => sample,
+        });
+        self.slow = Some(match self.slow {
+            Some(ema) => ema + EMA_ALPHA_SLOW * (sample - ema),
+            None => sample,
+        });
+
+        self.samples.push_back(sample);
+        while self.samples.len() > FLEET_METRIC_WINDOW {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Current smoothed reading, or `None` if no samples have been recorded.
+    pub fn current(&self) -> Option<f64> {
+        self.fast
+    }
+
+    /// Trend of the fast EMA relative to the slow EMA.
+    pub fn trend(&self) -> Trend {
+        match (self.fast, self.slow) {
+            (Some(fast), Some(slow)) if fast > slow + f64::EPSILON => Trend::Rising,
+            (Some(fast), Some(slow)) if fast < slow - f64::EPSILON => Trend::Falling,
+            _ => Trend::Flat,
+        }
+    }
+
+    /// Render the sample window as a unicode sparkline, scaled between the
+    /// window's observed min and max. Empty if no samples have been recorded.
+    pub fn sparkline(&self) -> String {
+        if self.samples.is_empty() {
+            return String::new();
+        }
+        let min = self.samples.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = self
+            .samples
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let range = max - min;
+
+        self.samples
+            .iter()
+            .map(|&sample| {
+                if range <= f64::EPSILON {
+                    SPARKLINE_GLYPHS[0]
+                } else {
+                    let scaled = ((sample - min) / range * (SPARKLINE_GLYPHS.len() - 1) as f64)
+                        .round() as usize;
+                    SPARKLINE_GLYPHS[scaled.min(SPARKLINE_GLYPHS.len() - 1)]
+                }
+            })
+            .collect()
+    }
+}
+
+/// Fleet-wide agent health metrics, refreshed each time the agent list
+/// updates. Unlike the per-endpoint instrumentation above, these track the
+/// fleet's own state rather than this plugin's API layer.
+#[derive(Clone, Debug, Default)]
+pub struct FleetMetrics {
+    pub idle: EmaMetric,
+    pub working: EmaMetric,
+    pub unhealthy: EmaMetric,
+    pub mean_health_score: EmaMetric,
+    pub beads_completed: EmaMetric,
+    pub operations_executed: EmaMetric,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_window_reports_percentiles() {
+        let mut window = LatencyWindow::default();
+        for ms in 1..=100 {
+            window.record(Duration::from_millis(ms));
+        }
+
+        assert_eq!(window.p50(), Some(Duration::from_millis(50)));
+        assert_eq!(window.p95(), Some(Duration::from_millis(95)));
+        assert_eq!(window.len(), 100);
+    }
+
+    #[test]
+    fn test_latency_window_empty_has_no_percentiles() {
+        let window = LatencyWindow::default();
+        assert!(window.is_empty());
+        assert_eq!(window.p50(), None);
+        assert_eq!(window.p95(), None);
+    }
+
+    #[test]
+    fn test_latency_window_wraps_after_capacity() {
+        let mut window = LatencyWindow::default();
+        for _ in 0..LATENCY_WINDOW {
+            window.record(Duration::from_millis(100));
+        }
+        window.record(Duration::from_millis(1));
+
+        assert_eq!(window.len(), LATENCY_WINDOW);
+        assert_eq!(window.p50(), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_cache_counters_hit_ratio() {
+        let mut counters = CacheCounters::default();
+        assert_eq!(counters.hit_ratio(), None);
+
+        counters.record_hit();
+        counters.record_hit();
+        counters.record_miss();
+
+        assert!((counters.hit_ratio().unwrap_or(0.0) - (2.0 / 3.0)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_stage_tally_failure_rate() {
+        let mut tally = StageTally::default();
+        assert_eq!(tally.failure_rate(), None);
+
+        tally.record_pass();
+        tally.record_fail();
+        tally.record_fail();
+
+        assert!((tally.failure_rate().unwrap_or(0.0) - (2.0 / 3.0)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ema_metric_empty_has_no_reading() {
+        let metric = EmaMetric::default();
+        assert_eq!(metric.current(), None);
+        assert_eq!(metric.trend(), Trend::Flat);
+        assert_eq!(metric.sparkline(), "");
+    }
+
+    #[test]
+    fn test_ema_metric_first_sample_seeds_both_emas() {
+        let mut metric = EmaMetric::default();
+        metric.record(5.0);
+        assert_eq!(metric.current(), Some(5.0));
+        assert_eq!(metric.trend(), Trend::Flat);
+    }
+
+    #[test]
+    fn test_ema_metric_trend_rises_then_falls() {
+        let mut metric = EmaMetric::default();
+        metric.record(1.0);
+        for _ in 0..10 {
+            metric.record(10.0);
+        }
+        assert_eq!(metric.trend(), Trend::Rising);
+
+        for _ in 0..40 {
+            metric.record(0.0);
+        }
+        assert_eq!(metric.trend(), Trend::Falling);
+    }
+
+    #[test]
+    fn test_ema_metric_sparkline_wraps_after_window() {
+        let mut metric = EmaMetric::default();
+        for i in 0..(FLEET_METRIC_WINDOW * 2) {
+            metric.record(i as f64);
+        }
+        assert_eq!(metric.sparkline().chars().count(), FLEET_METRIC_WINDOW);
+    }
+
+    #[test]
+    fn test_ema_metric_sparkline_flat_samples_use_lowest_glyph() {
+        let mut metric = EmaMetric::default();
+        for _ in 0..5 {
+            metric.record(3.0);
+        }
+        assert_eq!(metric.sparkline(), "▁▁▁▁▁");
+    }
+}
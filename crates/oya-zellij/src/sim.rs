@@ -0,0 +1,437 @@
+//! Offline simulation and record/replay, for exercising the dashboard
+//! without a live server.
+//!
+//! `Simulator` drives a population of agents through a weighted random walk
+//! over the fleet's state machine - the same churn-simulation approach used
+//! to stress-test distributed storage networks, applied here to agent
+//! health/workload instead of node membership - producing the same shape of
+//! data a live fetch does. `Recorder`/`Replayer` let a real fetched snapshot
+//! be captured to disk with a timestamp and played back later at original
+//! or accelerated speed, so a flaky fleet state seen in production can be
+//! reproduced deterministically.
+//!
+//! This module is deliberately ignorant of `AgentInfo`/`GraphNode` - it only
+//! knows plain, serializable snapshot shapes. The plugin binary converts
+//! between those and its own types, the same way it already does for
+//! `PersistedAgent`/`PersistedGraphNode`.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+
+/// A single agent's externally-visible state - the common shape fed into
+/// `update_agent_events`, whether it came from a live fetch, a simulated
+/// tick, or a replayed recording.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AgentSnapshot {
+    pub id: String,
+    pub state: String,
+    #[serde(default)]
+    pub current_bead: Option<String>,
+    pub health_score: f64,
+    pub uptime_secs: u64,
+}
+
+/// A single graph node/edge, in the recording's on-disk shape.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct GraphNodeSnapshot {
+    pub id: String,
+    pub label: String,
+    pub is_on_critical_path: bool,
+    pub state: String,
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct GraphEdgeSnapshot {
+    pub from: String,
+    pub to: String,
+    pub is_on_critical_path: bool,
+}
+
+/// A transition weighted by how likely it is to be sampled, relative to the
+/// other transitions out of the same source state.
+#[derive(Clone, Debug)]
+pub struct Transition {
+    pub to: String,
+    pub weight: f64,
+}
+
+impl Transition {
+    pub fn new(to: impl Into<String>, weight: f64) -> Self {
+        Self {
+            to: to.into(),
+            weight,
+        }
+    }
+}
+
+/// Population and weighted state-transition table driving one `Simulator`.
+#[derive(Clone, Debug)]
+pub struct SimScenario {
+    pub agent_count: usize,
+    pub bead_pool: Vec<String>,
+    /// Probability in `[0.0, 1.0]` that a working agent already holding a
+    /// bead swaps it for another on a given tick; a working agent with no
+    /// bead always picks one up.
+    pub bead_churn_probability: f64,
+    transitions: HashMap<String, Vec<Transition>>,
+}
+
+impl SimScenario {
+    /// A scenario resembling a healthy-but-noisy fleet: agents mostly cycle
+    /// between idle and working, with an occasional dip into unhealthy and
+    /// recovery back out. No agent spontaneously shuts down or terminates,
+    /// since those are operator-driven in production rather than background
+    /// churn.
+    pub fn default_fleet(agent_count: usize) -> Self {
+        let mut transitions = HashMap::new();
+        transitions.insert(
+            "idle".to_string(),
+            vec![Transition::new("idle", 0.4), Transition::new("working", 0.6)],
+        );
+        transitions.insert(
+            "working".to_string(),
+            vec![
+                Transition::new("working", 0.75),
+                Transition::new("idle", 0.15),
+                Transition::new("unhealthy", 0.10),
+            ],
+        );
+        transitions.insert(
+            "unhealthy".to_string(),
+            vec![
+                Transition::new("unhealthy", 0.5),
+                Transition::new("working", 0.3),
+                Transition::new("idle", 0.2),
+            ],
+        );
+
+        let bead_count = agent_count.max(1) * 2;
+        Self {
+            agent_count,
+            bead_pool: (1..=bead_count).map(|n| format!("bead-{}", n)).collect(),
+            bead_churn_probability: 0.2,
+            transitions,
+        }
+    }
+
+    /// Overrides (or adds) the weighted transition table for one source
+    /// state, so callers can script a specific failure scenario (e.g. a
+    /// whole fleet marching into `unhealthy`) on top of the defaults.
+    pub fn with_transitions(mut self, from: impl Into<String>, transitions: Vec<Transition>) -> Self {
+        self.transitions.insert(from.into(), transitions);
+        self
+    }
+
+    fn transitions_from(&self, state: &str) -> &[Transition] {
+        self.transitions
+            .get(state)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+/// Drives a `SimScenario` forward one tick at a time, holding the
+/// currently-simulated population between ticks.
+pub struct Simulator {
+    scenario: SimScenario,
+    agents: Vec<AgentSnapshot>,
+    rng: rand::rngs::ThreadRng,
+}
+
+impl Clone for Simulator {
+    fn clone(&self) -> Self {
+        Self {
+            scenario: self.scenario.clone(),
+            agents: self.agents.clone(),
+            rng: rand::thread_rng(),
+        }
+    }
+}
+
+impl Simulator {
+    pub fn new(scenario: SimScenario) -> Self {
+        let agents = (0..scenario.agent_count)
+            .map(|i| AgentSnapshot {
+                id: format!("sim-agent-{}", i),
+                state: "idle".to_string(),
+                current_bead: None,
+                health_score: 1.0,
+                uptime_secs: 0,
+            })
+            .collect();
+        Self {
+            scenario,
+            agents,
+            rng: rand::thread_rng(),
+        }
+    }
+
+    /// Samples a transition for every agent from its current state's
+    /// weighted table, nudges health score toward the extremes based on the
+    /// new state, and churns bead assignment for working agents. Returns the
+    /// resulting population.
+    pub fn tick(&mut self) -> &[AgentSnapshot] {
+        let bead_pool_len = self.scenario.bead_pool.len();
+        for agent in &mut self.agents {
+            agent.uptime_secs = agent.uptime_secs.saturating_add(1);
+
+            let transitions = self.scenario.transitions_from(&agent.state);
+            if !transitions.is_empty() {
+                let weights: Vec<f64> = transitions.iter().map(|t| t.weight).collect();
+                if let Ok(dist) = WeightedIndex::new(&weights) {
+                    agent.state = transitions[dist.sample(&mut self.rng)].to.clone();
+                }
+            }
+
+            agent.health_score = if agent.state == "unhealthy" {
+                (agent.health_score - 0.15).max(0.0)
+            } else {
+                (agent.health_score + 0.05).min(1.0)
+            };
+
+            if agent.state == "working" {
+                let should_churn = agent.current_bead.is_none()
+                    || self.rng.gen_bool(self.scenario.bead_churn_probability);
+                if should_churn && bead_pool_len > 0 {
+                    let index = self.rng.gen_range(0..bead_pool_len);
+                    agent.current_bead = self.scenario.bead_pool.get(index).cloned();
+                }
+            } else {
+                agent.current_bead = None;
+            }
+        }
+        &self.agents
+    }
+}
+
+/// Milliseconds since the Unix epoch, for timestamping recorded frames.
+/// Falls back to `0` on a clock before the epoch rather than panicking.
+pub fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// One recorded moment: a full agent/graph snapshot plus the wall-clock time
+/// it was captured, so a sequence of frames can be replayed at original (or
+/// accelerated) speed.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecordedFrame {
+    pub captured_at_ms: u64,
+    pub agents: Vec<AgentSnapshot>,
+    pub graph_nodes: Vec<GraphNodeSnapshot>,
+    pub graph_edges: Vec<GraphEdgeSnapshot>,
+}
+
+/// Appends `RecordedFrame`s as newline-delimited JSON, one per fetched
+/// snapshot, so a recording can be built up incrementally over a live
+/// session without holding the whole thing in memory.
+#[derive(Clone, Debug)]
+pub struct Recorder {
+    path: String,
+}
+
+impl Recorder {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn record(&self, frame: &RecordedFrame) -> io::Result<()> {
+        let mut line =
+            serde_json::to_string(frame).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        line.push('\n');
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())
+    }
+}
+
+/// A loaded recording, played back by picking the latest frame whose
+/// recorded offset has elapsed.
+#[derive(Clone, Debug, Default)]
+pub struct Replayer {
+    frames: Vec<RecordedFrame>,
+}
+
+impl Replayer {
+    /// Builds a `Replayer` from already-decoded frames, for callers that
+    /// assemble a recording in memory instead of loading one from disk
+    /// (e.g. tests).
+    pub fn from_frames(frames: Vec<RecordedFrame>) -> Self {
+        Self { frames }
+    }
+
+    pub fn load(path: &str) -> io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let reader = io::BufReader::new(file);
+        let mut frames = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Ok(frame) = serde_json::from_str::<RecordedFrame>(&line) {
+                frames.push(frame);
+            }
+        }
+        Ok(Self { frames })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The latest frame whose recorded offset (relative to the first frame)
+    /// has elapsed at `speed`x, given `elapsed_ms` of wall-clock playback
+    /// time. Once every frame's offset has elapsed, the last frame keeps
+    /// being returned; `None` only when the recording is empty.
+    pub fn frame_for_elapsed(&self, elapsed_ms: u64, speed: f64) -> Option<&RecordedFrame> {
+        let first = self.frames.first()?.captured_at_ms;
+        let target_offset = (elapsed_ms as f64 * speed.max(0.0001)) as u64;
+        self.frames
+            .iter()
+            .rev()
+            .find(|frame| frame.captured_at_ms.saturating_sub(first) <= target_offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deterministic_scenario() -> SimScenario {
+        // Zero-weight alternatives are never sampled by `WeightedIndex`, so
+        // pinning every transition's weight to a single destination makes
+        // the walk deterministic and assertable.
+        SimScenario::default_fleet(2)
+            .with_transitions("idle", vec![Transition::new("working", 1.0)])
+            .with_transitions("working", vec![Transition::new("unhealthy", 1.0)])
+            .with_transitions("unhealthy", vec![Transition::new("idle", 1.0)])
+    }
+
+    #[test]
+    fn test_simulator_starts_all_agents_idle() {
+        let sim = Simulator::new(SimScenario::default_fleet(3));
+        assert_eq!(sim.agents.len(), 3);
+        assert!(sim.agents.iter().all(|a| a.state == "idle"));
+    }
+
+    #[test]
+    fn test_simulator_tick_follows_deterministic_transition_table() {
+        let mut sim = Simulator::new(deterministic_scenario());
+        let after_one = sim.tick().to_vec();
+        assert!(after_one.iter().all(|a| a.state == "working"));
+
+        let after_two = sim.tick().to_vec();
+        assert!(after_two.iter().all(|a| a.state == "unhealthy"));
+        assert!(after_two.iter().all(|a| a.health_score < 1.0));
+    }
+
+    #[test]
+    fn test_simulator_tick_increments_uptime() {
+        let mut sim = Simulator::new(SimScenario::default_fleet(1));
+        sim.tick();
+        sim.tick();
+        assert_eq!(sim.agents[0].uptime_secs, 2);
+    }
+
+    #[test]
+    fn test_simulator_clears_bead_when_not_working() {
+        let mut sim = Simulator::new(deterministic_scenario());
+        sim.tick(); // -> working, picks up a bead
+        assert!(sim.agents.iter().all(|a| a.current_bead.is_some()));
+        sim.tick(); // -> unhealthy, bead released
+        assert!(sim.agents.iter().all(|a| a.current_bead.is_none()));
+    }
+
+    #[test]
+    fn test_replayer_picks_latest_elapsed_frame() {
+        let frames = vec![
+            RecordedFrame {
+                captured_at_ms: 1_000,
+                agents: vec![],
+                graph_nodes: vec![],
+                graph_edges: vec![],
+            },
+            RecordedFrame {
+                captured_at_ms: 1_500,
+                agents: vec![],
+                graph_nodes: vec![],
+                graph_edges: vec![],
+            },
+            RecordedFrame {
+                captured_at_ms: 3_000,
+                agents: vec![],
+                graph_nodes: vec![],
+                graph_edges: vec![],
+            },
+        ];
+        let replayer = Replayer::from_frames(frames);
+
+        assert_eq!(
+            replayer.frame_for_elapsed(0, 1.0).unwrap().captured_at_ms,
+            1_000
+        );
+        assert_eq!(
+            replayer.frame_for_elapsed(600, 1.0).unwrap().captured_at_ms,
+            1_500
+        );
+        // At 2x speed, 600ms of wall-clock playback covers 1200ms of
+        // recorded offset, which still only reaches the second frame.
+        assert_eq!(
+            replayer.frame_for_elapsed(600, 2.0).unwrap().captured_at_ms,
+            1_500
+        );
+        assert_eq!(
+            replayer.frame_for_elapsed(600, 4.0).unwrap().captured_at_ms,
+            3_000
+        );
+    }
+
+    #[test]
+    fn test_replayer_empty_has_no_frame() {
+        let replayer = Replayer::default();
+        assert!(replayer.is_empty());
+        assert!(replayer.frame_for_elapsed(1_000, 1.0).is_none());
+    }
+
+    #[test]
+    fn test_recorder_and_replayer_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "oya_sim_test_{}.jsonl",
+            std::process::id()
+        ));
+        let path = path.to_string_lossy().to_string();
+        let _ = std::fs::remove_file(&path);
+
+        let recorder = Recorder::new(path.clone());
+        let frame = RecordedFrame {
+            captured_at_ms: 42,
+            agents: vec![AgentSnapshot {
+                id: "agent-1".to_string(),
+                state: "working".to_string(),
+                current_bead: Some("bead-1".to_string()),
+                health_score: 0.9,
+                uptime_secs: 10,
+            }],
+            graph_nodes: vec![],
+            graph_edges: vec![],
+        };
+        recorder.record(&frame).expect("record succeeds");
+
+        let replayer = Replayer::load(&path).expect("load succeeds");
+        assert_eq!(replayer.frames.len(), 1);
+        assert_eq!(replayer.frames[0].agents[0].id, "agent-1");
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
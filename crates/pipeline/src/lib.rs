@@ -37,6 +37,7 @@ pub mod pipeline;
 pub mod process;
 pub mod process_pool;
 pub mod quality_gates;
+pub mod queue;
 pub mod repo;
 pub mod retry;
 pub mod stages;
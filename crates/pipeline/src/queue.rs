@@ -0,0 +1,430 @@
+//! Durable task queue - explicit lifecycle state machine with crash recovery.
+//!
+//! Unlike `persistence`, which tracks a task's pipeline stage history, this
+//! module tracks *queue position*: which bucket a task currently occupies on
+//! its way from submission to completion. Buckets are separate SurrealDB
+//! tables (`queued`, `running`, `staged`, `failed`, `finished`), and a
+//! transition - a delete from one table plus an insert into another - runs
+//! as a single SurrealDB transaction, never a partial update of shared
+//! state. A separate task index (never pruned) lets a startup scan notice
+//! and recover any task that still ended up missing from every bucket.
+
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use surrealdb::{
+    Surreal,
+    engine::local::{Db, RocksDb},
+};
+
+use crate::error::{Error, Result};
+
+/// Bucket a queued task currently occupies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueueBucket {
+    Queued,
+    Running,
+    Staged,
+    Failed,
+    Finished,
+}
+
+impl QueueBucket {
+    /// SurrealDB table name backing this bucket.
+    #[must_use]
+    pub const fn table(self) -> &'static str {
+        match self {
+            Self::Queued => "queue_queued",
+            Self::Running => "queue_running",
+            Self::Staged => "queue_staged",
+            Self::Failed => "queue_failed",
+            Self::Finished => "queue_finished",
+        }
+    }
+}
+
+/// A task's durable queue record, re-inserted under a new table on each
+/// lifecycle transition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueEntry {
+    pub task_id: String,
+    pub payload: String,
+    pub enqueued_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub failure_reason: String,
+}
+
+/// Table backing the bucket-independent task index (see
+/// [`TaskQueue::reconcile_index`]).
+const TASK_INDEX_TABLE: &str = "queue_index";
+
+/// Bucket-independent record of every task id ever enqueued. Written once
+/// at `enqueue` time and never removed, so it always has enough (`payload`,
+/// `enqueued_at`) to rebuild a `QueueEntry` for a task that a startup scan
+/// finds missing from all five buckets - the index is the thing recovery
+/// checks against, rather than relying on the buckets being self-consistent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TaskIndexEntry {
+    task_id: String,
+    payload: String,
+    enqueued_at: DateTime<Utc>,
+}
+
+/// Durable task queue backed by embedded SurrealDB, one table per bucket.
+pub struct TaskQueue {
+    db: Surreal<Db>,
+}
+
+impl TaskQueue {
+    /// Open the queue's database at `repo_root`, then run recovery: any
+    /// task left in `running` from a previous process (a crash) goes back
+    /// to `staged` so it can be re-picked, and any task the index knows
+    /// about but that isn't in any of the five buckets - which atomic
+    /// transitions (see [`Self::move_entry`]) prevent going forward, but
+    /// which earlier non-atomic writes could still have produced - is
+    /// likewise restored to `staged`.
+    pub async fn open(repo_root: &Path) -> Result<Self> {
+        let db_path = repo_root.join(".OYA").join("queue-db");
+        if let Some(parent) = db_path.parent() {
+            crate::process::create_dir_all(parent)?;
+        }
+
+        let db = Surreal::new::<RocksDb>(db_path)
+            .await
+            .map_err(|e| Error::QueueError {
+                reason: format!("failed to open queue database: {e}"),
+            })?;
+
+        db.use_ns("oya")
+            .use_db("queue")
+            .await
+            .map_err(|e| Error::QueueError {
+                reason: format!("failed to select namespace/database: {e}"),
+            })?;
+
+        let queue = Self { db };
+        queue.recover_crashed_tasks().await?;
+        queue.reconcile_index().await?;
+        Ok(queue)
+    }
+
+    async fn put(&self, bucket: QueueBucket, entry: &QueueEntry) -> Result<()> {
+        self.db
+            .create::<Option<QueueEntry>>((bucket.table(), entry.task_id.clone()))
+            .content(entry.clone())
+            .await
+            .map_err(|e| Error::QueueError {
+                reason: format!(
+                    "failed to insert task '{}' into {}: {e}",
+                    entry.task_id,
+                    bucket.table()
+                ),
+            })?;
+        Ok(())
+    }
+
+    /// Records `entry` in the bucket-independent task index. Idempotent, so
+    /// it's safe to call unconditionally from `enqueue`.
+    async fn index(&self, entry: &QueueEntry) -> Result<()> {
+        self.db
+            .upsert::<Option<TaskIndexEntry>>((TASK_INDEX_TABLE, entry.task_id.clone()))
+            .content(TaskIndexEntry {
+                task_id: entry.task_id.clone(),
+                payload: entry.payload.clone(),
+                enqueued_at: entry.enqueued_at,
+            })
+            .await
+            .map_err(|e| Error::QueueError {
+                reason: format!("failed to index task '{}': {e}", entry.task_id),
+            })?;
+        Ok(())
+    }
+
+    /// Atomically moves a task from `from` to `to` within a single SurrealDB
+    /// transaction, so a crash between "remove from the old bucket" and
+    /// "insert into the new one" can never happen - the task is always in
+    /// exactly the bucket the last *committed* transition left it in. The
+    /// `CREATE` only runs if the `DELETE` actually removed something, so a
+    /// task that isn't in `from` leaves both buckets untouched.
+    ///
+    /// `new_failure_reason`, when `Some`, overwrites the entry's
+    /// `failure_reason`; otherwise the existing value carries over
+    /// unchanged (e.g. a `requeue` keeps the reason from the last failure).
+    ///
+    /// Returns `Ok(None)` if the task wasn't in `from`; callers that require
+    /// it to be there turn that into an error themselves (see `claim`,
+    /// `complete`, `fail`), while `requeue` uses it to fall through to a
+    /// second bucket.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::QueueError` if the transaction itself fails.
+    async fn move_entry(
+        &self,
+        from: QueueBucket,
+        to: QueueBucket,
+        task_id: &str,
+        new_failure_reason: Option<&str>,
+    ) -> Result<Option<QueueEntry>> {
+        let now = Utc::now();
+        let failure_reason_expr = if new_failure_reason.is_some() {
+            "$new_failure_reason"
+        } else {
+            "$old[0].failure_reason"
+        };
+
+        let query = format!(
+            "BEGIN TRANSACTION;
+             LET $old = (DELETE type::thing($from_tb, $task_id) RETURN BEFORE);
+             IF array::len($old) > 0 {{
+                 CREATE type::thing($to_tb, $task_id) CONTENT {{
+                     task_id: $old[0].task_id,
+                     payload: $old[0].payload,
+                     enqueued_at: $old[0].enqueued_at,
+                     updated_at: $updated_at,
+                     failure_reason: {failure_reason_expr},
+                 }};
+             }};
+             COMMIT TRANSACTION;"
+        );
+
+        self.db
+            .query(query)
+            .bind(("from_tb", from.table()))
+            .bind(("to_tb", to.table()))
+            .bind(("task_id", task_id.to_string()))
+            .bind(("updated_at", now))
+            .bind((
+                "new_failure_reason",
+                new_failure_reason.unwrap_or_default().to_string(),
+            ))
+            .await
+            .map_err(|e| Error::QueueError {
+                reason: format!(
+                    "failed to atomically move task '{task_id}' from {} to {}: {e}",
+                    from.table(),
+                    to.table()
+                ),
+            })?
+            .check()
+            .map_err(|e| Error::QueueError {
+                reason: format!(
+                    "failed to atomically move task '{task_id}' from {} to {}: {e}",
+                    from.table(),
+                    to.table()
+                ),
+            })?;
+
+        self.db
+            .select((to.table(), task_id))
+            .await
+            .map_err(|e| Error::QueueError {
+                reason: format!("failed to read back task '{task_id}' from {}: {e}", to.table()),
+            })
+    }
+
+    /// Move every task still in `running` back to `staged`. Called once on
+    /// `open`, since a task stuck in `running` at startup can only mean the
+    /// process that claimed it crashed before completing or failing it.
+    async fn recover_crashed_tasks(&self) -> Result<()> {
+        let stranded: Vec<QueueEntry> =
+            self.db
+                .select(QueueBucket::Running.table())
+                .await
+                .map_err(|e| Error::QueueError {
+                    reason: format!("failed to scan running bucket: {e}"),
+                })?;
+
+        for entry in stranded {
+            self.move_entry(QueueBucket::Running, QueueBucket::Staged, &entry.task_id, None)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Scan the task index against the five buckets, and restore to
+    /// `staged` any task the index knows about but that `locate` can't find
+    /// anywhere - a task that fell through the gap between a delete and the
+    /// following insert under the pre-transaction version of `move_entry`.
+    /// Called once on `open`, after `recover_crashed_tasks`.
+    async fn reconcile_index(&self) -> Result<()> {
+        let indexed: Vec<TaskIndexEntry> =
+            self.db.select(TASK_INDEX_TABLE).await.map_err(|e| Error::QueueError {
+                reason: format!("failed to scan task index: {e}"),
+            })?;
+
+        for indexed_entry in indexed {
+            if self.locate(&indexed_entry.task_id).await?.is_some() {
+                continue;
+            }
+
+            let now = Utc::now();
+            self.put(
+                QueueBucket::Staged,
+                &QueueEntry {
+                    task_id: indexed_entry.task_id,
+                    payload: indexed_entry.payload,
+                    enqueued_at: indexed_entry.enqueued_at,
+                    updated_at: now,
+                    failure_reason: String::new(),
+                },
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Add a new task to the `queued` bucket.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::QueueError` if `task_id` is already present in any
+    /// bucket, or if the write fails.
+    pub async fn enqueue(&self, task_id: &str, payload: &str) -> Result<()> {
+        if self.locate(task_id).await?.is_some() {
+            return Err(Error::QueueError {
+                reason: format!("task '{task_id}' is already in the queue"),
+            });
+        }
+
+        let now = Utc::now();
+        let entry = QueueEntry {
+            task_id: task_id.to_string(),
+            payload: payload.to_string(),
+            enqueued_at: now,
+            updated_at: now,
+            failure_reason: String::new(),
+        };
+
+        self.put(QueueBucket::Queued, &entry).await?;
+        self.index(&entry).await
+    }
+
+    /// Transition a task from `queued` to `running`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::QueueError` if the task isn't in `queued`.
+    pub async fn claim(&self, task_id: &str) -> Result<QueueEntry> {
+        self.move_entry(QueueBucket::Queued, QueueBucket::Running, task_id, None)
+            .await?
+            .ok_or_else(|| Error::QueueError {
+                reason: format!(
+                    "task '{task_id}' is not in the {} bucket",
+                    QueueBucket::Queued.table()
+                ),
+            })
+    }
+
+    /// Transition a task from `running` to `finished`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::QueueError` if the task isn't in `running`.
+    pub async fn complete(&self, task_id: &str) -> Result<QueueEntry> {
+        self.move_entry(QueueBucket::Running, QueueBucket::Finished, task_id, None)
+            .await?
+            .ok_or_else(|| Error::QueueError {
+                reason: format!(
+                    "task '{task_id}' is not in the {} bucket",
+                    QueueBucket::Running.table()
+                ),
+            })
+    }
+
+    /// Transition a task from `running` to `failed`, recording `reason`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::QueueError` if the task isn't in `running`.
+    pub async fn fail(&self, task_id: &str, reason: &str) -> Result<QueueEntry> {
+        self.move_entry(QueueBucket::Running, QueueBucket::Failed, task_id, Some(reason))
+            .await?
+            .ok_or_else(|| Error::QueueError {
+                reason: format!("task '{task_id}' is not in the running bucket"),
+            })
+    }
+
+    /// Move a task from `failed` (or `staged`) back to `queued` for another
+    /// attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::QueueError` if the task is in neither bucket.
+    pub async fn requeue(&self, task_id: &str) -> Result<QueueEntry> {
+        if let Some(entry) = self
+            .move_entry(QueueBucket::Failed, QueueBucket::Queued, task_id, None)
+            .await?
+        {
+            return Ok(entry);
+        }
+
+        self.move_entry(QueueBucket::Staged, QueueBucket::Queued, task_id, None)
+            .await?
+            .ok_or_else(|| Error::QueueError {
+                reason: format!("task '{task_id}' is not in the failed or staged bucket"),
+            })
+    }
+
+    /// Locate which bucket, if any, currently holds `task_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::QueueError` if a bucket scan fails.
+    pub async fn locate(&self, task_id: &str) -> Result<Option<QueueBucket>> {
+        const BUCKETS: [QueueBucket; 5] = [
+            QueueBucket::Queued,
+            QueueBucket::Running,
+            QueueBucket::Staged,
+            QueueBucket::Failed,
+            QueueBucket::Finished,
+        ];
+
+        for bucket in BUCKETS {
+            let entry: Option<QueueEntry> =
+                self.db
+                    .select((bucket.table(), task_id))
+                    .await
+                    .map_err(|e| Error::QueueError {
+                        reason: format!("failed to look up task '{task_id}' in {}: {e}", bucket.table()),
+                    })?;
+
+            if entry.is_some() {
+                return Ok(Some(bucket));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_table_names_are_distinct() {
+        let tables = [
+            QueueBucket::Queued.table(),
+            QueueBucket::Running.table(),
+            QueueBucket::Staged.table(),
+            QueueBucket::Failed.table(),
+            QueueBucket::Finished.table(),
+        ];
+
+        for (i, a) in tables.iter().enumerate() {
+            for (j, b) in tables.iter().enumerate() {
+                assert!(i == j || a != b, "bucket tables must not collide");
+            }
+        }
+    }
+}
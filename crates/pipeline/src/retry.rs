@@ -22,6 +22,12 @@ pub struct RetryConfig {
     pub backoff_multiplier: f64,
     /// Whether to add jitter to delays (reduces thundering herd).
     pub jitter: bool,
+    /// When set, replaces the additive `jitter` scheme with full jitter:
+    /// the delay for attempt *n* (n >= 1) is drawn uniformly from
+    /// `[0, initial_delay * multiplier^(n-1)]` (capped at `max_delay`) rather
+    /// than that value plus up to 25%. Takes precedence over `jitter` when
+    /// both are set.
+    pub full_jitter: bool,
 }
 
 impl Default for RetryConfig {
@@ -32,6 +38,7 @@ impl Default for RetryConfig {
             max_delay: Duration::from_secs(30),
             backoff_multiplier: 2.0,
             jitter: true,
+            full_jitter: false,
         }
     }
 }
@@ -72,6 +79,13 @@ impl RetryConfig {
         self
     }
 
+    /// Create a new retry config with full jitter enabled/disabled.
+    #[must_use]
+    pub const fn with_full_jitter(mut self, full_jitter: bool) -> Self {
+        self.full_jitter = full_jitter;
+        self
+    }
+
     /// No retries - execute only once.
     #[must_use]
     pub fn no_retry() -> Self {
@@ -90,6 +104,7 @@ impl RetryConfig {
             max_delay: Duration::from_millis(500),
             backoff_multiplier: 2.0,
             jitter: true,
+            full_jitter: false,
         }
     }
 
@@ -108,6 +123,7 @@ impl RetryConfig {
             max_delay: Duration::from_secs(60),
             backoff_multiplier: 2.0,
             jitter: true,
+            full_jitter: false,
         }
     }
 
@@ -124,7 +140,10 @@ impl RetryConfig {
 
         let capped_delay_ms = base_delay_ms.min(self.max_delay.as_millis() as f64);
 
-        let final_delay_ms = if self.jitter {
+        let final_delay_ms = if self.full_jitter {
+            // Full jitter: draw uniformly from [0, capped_delay].
+            capped_delay_ms * simple_random()
+        } else if self.jitter {
             // Add up to 25% jitter
             let jitter_factor = 1.0 + (simple_random() * 0.25);
             capped_delay_ms * jitter_factor
@@ -316,6 +335,182 @@ where
     }))
 }
 
+/// Which level of the pipeline a [`RetryAttempt`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryLevel {
+    /// Retrying a single failing command.
+    Task,
+    /// Retrying an entire stage.
+    Stage,
+}
+
+/// One row of the retry audit trail: which attempt this was, at which
+/// level, and what (if anything) went wrong.
+#[derive(Debug, Clone)]
+pub struct RetryAttempt {
+    /// 1-based attempt number within its level.
+    pub attempt: u32,
+    /// Whether this was a task-level or stage-level retry.
+    pub level: RetryLevel,
+    /// Error from this attempt, if it failed.
+    pub error: Option<String>,
+}
+
+/// Independent retry configurations for command-level and stage-level
+/// retry, so a single flaky command can be re-run in place without
+/// restarting the whole stage.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retry configuration for a single command.
+    pub task: RetryConfig,
+    /// Retry configuration for an entire stage.
+    pub stage: RetryConfig,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            task: RetryConfig::quick(),
+            stage: RetryConfig::standard(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy from explicit task- and stage-level configs.
+    #[must_use]
+    pub const fn new(task: RetryConfig, stage: RetryConfig) -> Self {
+        Self { task, stage }
+    }
+}
+
+/// Exit codes considered transient for a failed command (timeout, OOM-kill),
+/// worth retrying rather than treating as a deterministic failure.
+#[must_use]
+pub fn default_retryable_exit_codes() -> &'static [i32] {
+    &[124, 137]
+}
+
+/// Classify an error as retryable (`true`) or terminal (`false`) for
+/// [`RetryPolicy`]. `InvalidSlug`, `UnsupportedLanguage`, and `NotInRepo` are
+/// always terminal — retrying them can't change the outcome. `CommandFailed`
+/// is retryable only when its exit code is in `retryable_exit_codes`.
+#[must_use]
+pub fn classify(error: &Error, retryable_exit_codes: &[i32]) -> bool {
+    match error {
+        Error::InvalidSlug { .. }
+        | Error::UnsupportedLanguage { .. }
+        | Error::NotInRepo { .. } => false,
+        Error::CommandTimeout { .. } => true,
+        Error::CommandFailed { exit_code, .. } => retryable_exit_codes.contains(exit_code),
+        other => other.is_retryable(),
+    }
+}
+
+/// Core retry loop shared by [`retry_task`] and [`retry_stage`]: retries
+/// `operation` under `config`, classifying each failure with `classify` and
+/// recording every attempt into `audit`.
+fn retry_at_level<T, F>(
+    config: &RetryConfig,
+    level: RetryLevel,
+    retryable_exit_codes: &[i32],
+    mut operation: F,
+    audit: &mut Vec<RetryAttempt>,
+) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    let total_attempts = config.max_attempts + 1;
+    let mut last_error = None;
+
+    for attempt in 0..total_attempts {
+        let delay = config.calculate_delay(attempt);
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+
+        match operation() {
+            Ok(value) => {
+                audit.push(RetryAttempt {
+                    attempt: attempt + 1,
+                    level,
+                    error: None,
+                });
+                return Ok(value);
+            }
+            Err(e) => {
+                let retryable = classify(&e, retryable_exit_codes);
+                audit.push(RetryAttempt {
+                    attempt: attempt + 1,
+                    level,
+                    error: Some(e.to_string()),
+                });
+                if !retryable {
+                    return Err(e);
+                }
+                warn!(attempt, level = ?level, error = %e, "Retryable error, will retry");
+                last_error = Some(e);
+            }
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| Error::InvalidRecord {
+        reason: format!("{level:?}-level retry exhausted with no error"),
+    }))
+}
+
+/// Retry a single failing command under `policy.task`, recording every
+/// attempt into `audit`.
+pub fn retry_task<T, F>(
+    policy: &RetryPolicy,
+    retryable_exit_codes: &[i32],
+    operation: F,
+    audit: &mut Vec<RetryAttempt>,
+) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    retry_at_level(
+        &policy.task,
+        RetryLevel::Task,
+        retryable_exit_codes,
+        operation,
+        audit,
+    )
+}
+
+/// Retry a whole stage under `policy.stage`, recording every attempt into
+/// `audit`. On exhaustion, wraps the final cause in `Error::StageFailed` so
+/// the reported reason includes how many attempts were made.
+pub fn retry_stage<F>(
+    policy: &RetryPolicy,
+    retryable_exit_codes: &[i32],
+    language: &str,
+    stage_name: &str,
+    operation: F,
+    audit: &mut Vec<RetryAttempt>,
+) -> Result<()>
+where
+    F: FnMut() -> Result<()>,
+{
+    let attempts_before = audit.len();
+    retry_at_level(
+        &policy.stage,
+        RetryLevel::Stage,
+        retryable_exit_codes,
+        operation,
+        audit,
+    )
+    .map_err(|e| {
+        let attempts = audit.len() - attempts_before;
+        Error::StageFailed {
+            language: language.to_string(),
+            stage: stage_name.to_string(),
+            reason: format!("{e} (after {attempts} attempt(s))"),
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,4 +599,138 @@ mod tests {
         // 1 * 10^3 = 1000 seconds, but capped at 5
         assert_eq!(config.calculate_delay(4), Duration::from_secs(5));
     }
+
+    #[test]
+    fn test_calculate_delay_full_jitter_stays_within_bound() {
+        let config = RetryConfig {
+            initial_delay: Duration::from_millis(100),
+            backoff_multiplier: 2.0,
+            max_delay: Duration::from_secs(10),
+            jitter: false,
+            full_jitter: true,
+            ..Default::default()
+        };
+
+        let delay = config.calculate_delay(2);
+        assert!(delay <= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_classify_terminal_errors_never_retry() {
+        let codes = default_retryable_exit_codes();
+        assert!(!classify(
+            &Error::UnsupportedLanguage {
+                lang: "cobol".into(),
+            },
+            codes
+        ));
+    }
+
+    #[test]
+    fn test_classify_command_timeout_is_retryable() {
+        let codes = default_retryable_exit_codes();
+        assert!(classify(
+            &Error::CommandTimeout {
+                command: "build".into(),
+                timeout_secs: 30,
+            },
+            codes
+        ));
+    }
+
+    #[test]
+    fn test_classify_command_failed_respects_exit_codes() {
+        let codes = &[124];
+        assert!(classify(
+            &Error::CommandFailed {
+                command: "build".into(),
+                exit_code: 124,
+            },
+            codes
+        ));
+        assert!(!classify(
+            &Error::CommandFailed {
+                command: "build".into(),
+                exit_code: 1,
+            },
+            codes
+        ));
+    }
+
+    #[test]
+    fn test_retry_task_records_audit_trail() {
+        let policy = RetryPolicy::new(RetryConfig::no_retry().with_max_attempts(2), RetryConfig::standard());
+        let mut audit = Vec::new();
+        let attempts = RefCell::new(0);
+
+        let result = retry_task(
+            &policy,
+            default_retryable_exit_codes(),
+            || {
+                *attempts.borrow_mut() += 1;
+                if *attempts.borrow() < 2 {
+                    Err(Error::CommandTimeout {
+                        command: "build".into(),
+                        timeout_secs: 5,
+                    })
+                } else {
+                    Ok(42)
+                }
+            },
+            &mut audit,
+        );
+
+        assert_eq!(result.ok(), Some(42));
+        assert_eq!(audit.len(), 2);
+        assert!(audit.iter().all(|a| a.level == RetryLevel::Task));
+    }
+
+    #[test]
+    fn test_retry_task_stops_immediately_on_terminal_error() {
+        let policy = RetryPolicy::new(RetryConfig::no_retry().with_max_attempts(3), RetryConfig::standard());
+        let mut audit = Vec::new();
+
+        let result: Result<i32> = retry_task(
+            &policy,
+            default_retryable_exit_codes(),
+            || {
+                Err(Error::UnsupportedLanguage {
+                    lang: "cobol".into(),
+                })
+            },
+            &mut audit,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(audit.len(), 1);
+    }
+
+    #[test]
+    fn test_retry_stage_wraps_cause_with_attempt_count() {
+        let policy = RetryPolicy::new(RetryConfig::standard(), RetryConfig::no_retry().with_max_attempts(2));
+        let mut audit = Vec::new();
+
+        let result = retry_stage(
+            &policy,
+            default_retryable_exit_codes(),
+            "rust",
+            "test",
+            || {
+                Err(Error::CommandTimeout {
+                    command: "test".into(),
+                    timeout_secs: 5,
+                })
+            },
+            &mut audit,
+        );
+
+        match result {
+            Err(Error::StageFailed { reason, stage, .. }) => {
+                assert_eq!(stage, "test");
+                assert!(reason.contains('3'));
+            }
+            _ => panic!("expected StageFailed"),
+        }
+        assert_eq!(audit.len(), 3);
+    }
 }
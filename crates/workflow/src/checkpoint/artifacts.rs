@@ -0,0 +1,242 @@
+//! Content-addressed, integrity-verified storage for phase artifacts.
+//!
+//! `PhaseOutput.artifacts` are currently opaque strings re-stored on every
+//! checkpoint. This store keys each artifact blob by its SHA-256 hash, so
+//! identical artifacts across phases and runs are deduplicated, and tracks a
+//! manifest of `hash -> (relative path, size)`. [`ArtifactStore::verify`]
+//! detects both missing blobs and blobs whose content no longer matches
+//! their hash (tamper or corruption).
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::idempotent::hash_input;
+
+/// Hex-encoded SHA-256 digest identifying an artifact blob.
+pub type ArtifactHash = String;
+
+fn hash_bytes(data: &[u8]) -> ArtifactHash {
+    hash_input(data).iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+/// Manifest entry for one stored artifact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtifactEntry {
+    /// Path of the blob, relative to the store's root directory.
+    pub relative_path: String,
+    /// Size of the blob in bytes.
+    pub size: usize,
+}
+
+/// Result of verifying one manifest entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyFailure {
+    /// The manifest references a hash with no backing blob on disk.
+    Missing { hash: ArtifactHash },
+    /// The blob on disk no longer hashes to its manifest key.
+    Corrupt { hash: ArtifactHash },
+}
+
+/// Content-addressed blob store for checkpoint/phase artifacts.
+#[derive(Debug, Clone)]
+pub struct ArtifactStore {
+    dir: PathBuf,
+    manifest: BTreeMap<ArtifactHash, ArtifactEntry>,
+}
+
+impl ArtifactStore {
+    /// Open (creating if missing) an artifact store rooted at `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::StorageFailed` if `dir` cannot be created.
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)
+            .map_err(|e| Error::storage_failed("create artifact store dir", e.to_string()))?;
+        Ok(Self {
+            dir,
+            manifest: BTreeMap::new(),
+        })
+    }
+
+    fn blob_path(&self, hash: &str) -> PathBuf {
+        self.dir.join(hash)
+    }
+
+    /// Store `data`, deduplicating by content hash. Returns the hash to
+    /// reference this artifact by (e.g. from `PhaseOutput.artifacts`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::StorageFailed` if writing the blob fails.
+    pub fn put(&mut self, data: &[u8]) -> Result<ArtifactHash> {
+        let hash = hash_bytes(data);
+
+        if !self.manifest.contains_key(&hash) {
+            fs::write(self.blob_path(&hash), data)
+                .map_err(|e| Error::storage_failed("write artifact blob", e.to_string()))?;
+            self.manifest.insert(
+                hash.clone(),
+                ArtifactEntry {
+                    relative_path: hash.clone(),
+                    size: data.len(),
+                },
+            );
+        }
+
+        Ok(hash)
+    }
+
+    /// Load a previously stored artifact by hash.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ArtifactMissing` if `hash` isn't in the manifest, or
+    /// if the manifest entry has no backing blob on disk.
+    pub fn get(&self, hash: &str) -> Result<Vec<u8>> {
+        if !self.manifest.contains_key(hash) {
+            return Err(Error::artifact_missing(hash));
+        }
+        fs::read(self.blob_path(hash)).map_err(|_| Error::artifact_missing(hash))
+    }
+
+    /// Number of distinct artifacts tracked in the manifest.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.manifest.len()
+    }
+
+    /// True if the manifest has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.manifest.is_empty()
+    }
+
+    /// Verify that every manifest entry has a backing blob whose recomputed
+    /// hash matches its key. Returns every failure found, not just the
+    /// first, so a single pass surfaces the full extent of damage.
+    #[must_use]
+    pub fn verify(&self) -> Vec<VerifyFailure> {
+        self.manifest
+            .keys()
+            .filter_map(|hash| match fs::read(self.blob_path(hash)) {
+                Err(_) => Some(VerifyFailure::Missing { hash: hash.clone() }),
+                Ok(data) if hash_bytes(&data) != *hash => {
+                    Some(VerifyFailure::Corrupt { hash: hash.clone() })
+                }
+                Ok(_) => None,
+            })
+            .collect()
+    }
+
+    /// Verify only the given hashes (e.g. the artifacts a checkpoint restore
+    /// depends on) rather than the whole manifest.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `Error::ArtifactMissing`/`Error::ArtifactCorrupt`
+    /// encountered.
+    pub fn verify_each(&self, hashes: &[ArtifactHash]) -> Result<()> {
+        for hash in hashes {
+            if !self.manifest.contains_key(hash) {
+                return Err(Error::artifact_missing(hash));
+            }
+            let data = fs::read(self.blob_path(hash)).map_err(|_| Error::artifact_missing(hash))?;
+            if hash_bytes(&data) != *hash {
+                return Err(Error::artifact_corrupt(hash));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("oya-artifact-store-test-{name}"))
+    }
+
+    #[test]
+    fn test_put_then_get_round_trips() {
+        let dir = temp_dir("round-trip");
+        let mut store = ArtifactStore::open(&dir).expect("open");
+
+        let hash = store.put(b"hello artifact").expect("put");
+        assert_eq!(store.get(&hash).expect("get"), b"hello artifact");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_put_deduplicates_identical_content() {
+        let dir = temp_dir("dedup");
+        let mut store = ArtifactStore::open(&dir).expect("open");
+
+        let hash1 = store.put(b"same bytes").expect("put 1");
+        let hash2 = store.put(b"same bytes").expect("put 2");
+
+        assert_eq!(hash1, hash2);
+        assert_eq!(store.len(), 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_verify_detects_missing_blob() {
+        let dir = temp_dir("missing");
+        let mut store = ArtifactStore::open(&dir).expect("open");
+        let hash = store.put(b"will be deleted").expect("put");
+        fs::remove_file(store.blob_path(&hash)).expect("delete blob");
+
+        let failures = store.verify();
+        assert_eq!(failures, vec![VerifyFailure::Missing { hash }]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_verify_detects_corrupt_blob() {
+        let dir = temp_dir("corrupt");
+        let mut store = ArtifactStore::open(&dir).expect("open");
+        let hash = store.put(b"original content").expect("put");
+        fs::write(store.blob_path(&hash), b"tampered content").expect("tamper");
+
+        let failures = store.verify();
+        assert_eq!(failures, vec![VerifyFailure::Corrupt { hash }]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_verify_clean_store_has_no_failures() {
+        let dir = temp_dir("clean");
+        let mut store = ArtifactStore::open(&dir).expect("open");
+        store.put(b"fine").expect("put");
+
+        assert!(store.verify().is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_verify_each_rejects_unknown_hash() {
+        let dir = temp_dir("verify-each-unknown");
+        let store = ArtifactStore::open(&dir).expect("open");
+
+        let result = store.verify_each(&["deadbeef".to_string()]);
+        assert!(matches!(result, Err(Error::ArtifactMissing { .. })));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -16,6 +16,14 @@ pub enum CheckpointStrategy {
     OnSuccess,
     /// Checkpoint after every N phases.
     Interval(usize),
+    /// Checkpoint once cumulative elapsed time since the last checkpoint
+    /// reaches this many milliseconds.
+    Duration(u64),
+    /// Checkpoint on a dynamic interval: the effective interval halves
+    /// (down to a floor of 1) after a failed phase and grows by one after
+    /// each successful phase, so checkpoints come more often right after
+    /// trouble and less often during a streak of fast successes.
+    Adaptive,
 }
 
 /// Checkpoint decision result.
@@ -48,6 +56,10 @@ impl CheckpointDecision {
 struct CheckpointState {
     last_checkpoint: Option<Instant>,
     phases_since_last: usize,
+    /// Cumulative `duration_ms` of phase outputs since the last checkpoint.
+    elapsed_since_last_ms: u64,
+    /// Dynamic interval used by `CheckpointStrategy::Adaptive`.
+    adaptive_interval: usize,
     strategy: CheckpointStrategy,
 }
 
@@ -69,6 +81,8 @@ impl CheckpointManager {
             state: CheckpointState {
                 last_checkpoint: None,
                 phases_since_last: 0,
+                elapsed_since_last_ms: 0,
+                adaptive_interval: 1,
                 strategy,
             },
         }
@@ -86,17 +100,33 @@ impl CheckpointManager {
             CheckpointStrategy::Always => true,
             CheckpointStrategy::OnSuccess => phase_output.success,
             CheckpointStrategy::Interval(n) => self.state.phases_since_last >= n,
+            CheckpointStrategy::Duration(threshold_ms) => {
+                self.state.elapsed_since_last_ms + phase_output.duration_ms >= threshold_ms
+            }
+            CheckpointStrategy::Adaptive => {
+                self.state.phases_since_last >= self.state.adaptive_interval
+            }
+        };
+
+        let adaptive_interval = if phase_output.success {
+            self.state.adaptive_interval + 1
+        } else {
+            (self.state.adaptive_interval / 2).max(1)
         };
 
         let new_state = if should {
             CheckpointState {
                 last_checkpoint: Some(Instant::now()),
                 phases_since_last: 0,
+                elapsed_since_last_ms: 0,
+                adaptive_interval,
                 strategy: self.state.strategy,
             }
         } else {
             CheckpointState {
                 phases_since_last: self.state.phases_since_last + 1,
+                elapsed_since_last_ms: self.state.elapsed_since_last_ms + phase_output.duration_ms,
+                adaptive_interval,
                 ..self.state.clone()
             }
         };
@@ -131,6 +161,13 @@ impl CheckpointManager {
     pub fn last_checkpoint(&self) -> Option<Instant> {
         self.state.last_checkpoint
     }
+
+    /// Get the cumulative `duration_ms` of phase outputs since the last
+    /// checkpoint.
+    #[must_use]
+    pub const fn elapsed_since_last_ms(&self) -> u64 {
+        self.state.elapsed_since_last_ms
+    }
 }
 
 #[cfg(test)]
@@ -271,6 +308,73 @@ mod tests {
         assert!(decision2.should_checkpoint());
     }
 
+    /// Helper to create a successful phase output with a given duration.
+    fn success_output_with_duration(duration_ms: u64) -> PhaseOutput {
+        PhaseOutput::success(vec![1, 2, 3]).with_duration_ms(duration_ms)
+    }
+
+    /// Helper to create a failed phase output with a given duration.
+    fn failure_output_with_duration(duration_ms: u64) -> PhaseOutput {
+        PhaseOutput {
+            success: false,
+            data: std::sync::Arc::new(vec![]),
+            message: Some("Failed".to_string()),
+            artifacts: vec![],
+            duration_ms,
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_strategy_duration() {
+        let mut manager = CheckpointManager::new(CheckpointStrategy::Duration(500));
+
+        // 300ms elapsed -> below threshold, skip
+        let decision1 = manager.update(&success_output_with_duration(300));
+        assert!(!decision1.should_checkpoint());
+        assert_eq!(manager.elapsed_since_last_ms(), 300);
+
+        // +300ms = 600ms elapsed -> crosses threshold, checkpoint and reset
+        let decision2 = manager.update(&success_output_with_duration(300));
+        assert!(decision2.should_checkpoint());
+        assert_eq!(manager.elapsed_since_last_ms(), 0);
+
+        // Accumulation restarts from zero
+        let decision3 = manager.update(&success_output_with_duration(100));
+        assert!(!decision3.should_checkpoint());
+        assert_eq!(manager.elapsed_since_last_ms(), 100);
+    }
+
+    #[test]
+    fn test_checkpoint_strategy_adaptive_shortens_after_failure() {
+        let mut manager = CheckpointManager::new(CheckpointStrategy::Adaptive);
+
+        // Adaptive interval starts at its floor of 1, so the first failure
+        // alone isn't enough to catch up to it yet.
+        let decision1 = manager.update(&failure_output_with_duration(10));
+        assert!(!decision1.should_checkpoint());
+        assert_eq!(manager.phases_since_last(), 1);
+
+        // The interval stays pinned at its floor through repeated failures,
+        // so the very next phase now meets it and checkpoints.
+        let decision2 = manager.update(&failure_output_with_duration(10));
+        assert!(decision2.should_checkpoint());
+        assert_eq!(manager.phases_since_last(), 0);
+    }
+
+    #[test]
+    fn test_checkpoint_strategy_adaptive_lengthens_on_success_streak() {
+        let mut manager = CheckpointManager::new(CheckpointStrategy::Adaptive);
+
+        // Each success grows the interval by exactly as much as
+        // `phases_since_last` grows, so a long streak of fast successes
+        // never closes the gap and never re-triggers a checkpoint.
+        for _ in 0..5 {
+            let decision = manager.update(&success_output_with_duration(10));
+            assert!(!decision.should_checkpoint());
+        }
+        assert_eq!(manager.phases_since_last(), 5);
+    }
+
     #[test]
     fn test_checkpoint_state_immutability() {
         let manager = CheckpointManager::new(CheckpointStrategy::Always);
@@ -3,13 +3,16 @@
 //! This module provides compression and serialization for checkpointing workflow state,
 //! as well as automatic checkpoint timer functionality.
 
+pub mod artifacts;
 pub mod auto;
 pub mod compression;
 pub mod manager;
 pub mod restore;
 pub mod serialize;
 pub mod storage;
+pub mod store;
 
+pub use artifacts::{ArtifactEntry, ArtifactHash, ArtifactStore, VerifyFailure};
 pub use auto::{
     start_auto_checkpoint, AutoCheckpointConfig, AutoCheckpointTimer, StateProvider,
     DEFAULT_AUTO_CHECKPOINT_INTERVAL,
@@ -22,3 +25,4 @@ pub use storage::{
     CheckpointMetadata, CheckpointStorage, CompressionConfig as StorageCompressionConfig,
     StorageStats,
 };
+pub use store::{CheckpointStore, RestoreOutcome, WorkflowSnapshot};
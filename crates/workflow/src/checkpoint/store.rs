@@ -0,0 +1,302 @@
+//! Checkpoint persistence and workflow resume.
+//!
+//! `CheckpointManager` only decides *when* to checkpoint; `CheckpointStore`
+//! is the companion that actually persists a [`WorkflowSnapshot`] and
+//! restores it so an interrupted workflow resumes at the next uncompleted
+//! phase instead of from scratch.
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::artifacts::ArtifactStore;
+use crate::error::{Error, Result};
+use crate::types::PhaseOutput;
+
+/// A durable snapshot of workflow progress, written whenever the
+/// `CheckpointManager` decides `should_checkpoint()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowSnapshot {
+    /// Number of completed phases; phases `0..completed_phase` are
+    /// skipped on restore.
+    pub completed_phase: usize,
+    /// Accumulated phase outputs, in execution order.
+    pub phase_outputs: Vec<PhaseOutput>,
+    /// Monotonically increasing checkpoint sequence number.
+    pub sequence: u64,
+    /// When this snapshot was written.
+    pub timestamp: DateTime<Utc>,
+}
+
+impl WorkflowSnapshot {
+    /// Create a new snapshot for the given progress.
+    #[must_use]
+    pub fn new(completed_phase: usize, phase_outputs: Vec<PhaseOutput>, sequence: u64) -> Self {
+        Self {
+            completed_phase,
+            phase_outputs,
+            sequence,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// Outcome of a successful restore: the loaded snapshot plus how many
+/// leading phases the workflow engine should skip.
+#[derive(Debug, Clone)]
+pub struct RestoreOutcome {
+    /// The restored snapshot.
+    pub snapshot: WorkflowSnapshot,
+    /// Number of phases already completed; the engine resumes at this index.
+    pub skip_phases: usize,
+}
+
+/// Persists `WorkflowSnapshot`s as one file per sequence number under a
+/// directory, writing atomically (temp file + rename) so a crash mid-write
+/// never corrupts the live checkpoint.
+#[derive(Debug, Clone)]
+pub struct CheckpointStore {
+    dir: PathBuf,
+}
+
+impl CheckpointStore {
+    /// Create a store rooted at `dir`, creating it if missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::CheckpointWriteFailed` if `dir` cannot be created.
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|e| Error::checkpoint_write_failed(e.to_string()))?;
+        Ok(Self { dir })
+    }
+
+    fn snapshot_path(&self, sequence: u64) -> PathBuf {
+        self.dir.join(format!("checkpoint-{sequence:020}.json"))
+    }
+
+    /// Persist `snapshot` atomically: serialize, write to a temp file in the
+    /// same directory, then rename over the final path so readers never
+    /// observe a partially written file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::CheckpointWriteFailed` if serialization or either
+    /// filesystem operation fails.
+    pub fn save(&self, snapshot: &WorkflowSnapshot) -> Result<()> {
+        let final_path = self.snapshot_path(snapshot.sequence);
+        let tmp_path = self
+            .dir
+            .join(format!(".checkpoint-{}.tmp", snapshot.sequence));
+
+        let json = serde_json::to_vec_pretty(snapshot)
+            .map_err(|e| Error::checkpoint_write_failed(e.to_string()))?;
+
+        fs::write(&tmp_path, &json).map_err(|e| Error::checkpoint_write_failed(e.to_string()))?;
+
+        fs::rename(&tmp_path, &final_path)
+            .map_err(|e| Error::checkpoint_write_failed(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Load the highest-sequence snapshot in the store and compute the
+    /// phases the workflow engine should skip on resume. Older snapshots are
+    /// not tried when the highest-sequence one is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::CheckpointRestoreFailed` if the store directory can't
+    /// be read or contains no checkpoints, or `Error::CheckpointCorrupt` if
+    /// the highest-sequence snapshot fails to parse or has an invalid shape
+    /// (its recorded phase outputs don't match `completed_phase`, or its
+    /// embedded sequence doesn't match the filename).
+    pub fn restore(&self) -> Result<RestoreOutcome> {
+        let mut candidates: Vec<(u64, PathBuf)> = fs::read_dir(&self.dir)
+            .map_err(|e| Error::checkpoint_restore_failed(e.to_string()))?
+            .filter_map(std::result::Result::ok)
+            .filter_map(|entry| {
+                let path = entry.path();
+                let stem = path.file_stem()?.to_str()?;
+                let sequence = stem.strip_prefix("checkpoint-")?.parse::<u64>().ok()?;
+                Some((sequence, path))
+            })
+            .collect();
+
+        candidates.sort_by_key(|(sequence, _)| *sequence);
+
+        let (sequence, path) = candidates
+            .pop()
+            .ok_or_else(|| Error::checkpoint_restore_failed("no checkpoints found"))?;
+
+        let bytes =
+            fs::read(&path).map_err(|e| Error::checkpoint_restore_failed(e.to_string()))?;
+
+        let snapshot: WorkflowSnapshot = serde_json::from_slice(&bytes)
+            .map_err(|e| Error::checkpoint_corrupt(format!("sequence {sequence}: {e}")))?;
+
+        if snapshot.sequence != sequence {
+            return Err(Error::checkpoint_corrupt(format!(
+                "snapshot sequence {} does not match filename sequence {sequence}",
+                snapshot.sequence
+            )));
+        }
+
+        if snapshot.phase_outputs.len() != snapshot.completed_phase {
+            return Err(Error::checkpoint_corrupt(format!(
+                "completed_phase {} does not match {} recorded phase outputs",
+                snapshot.completed_phase,
+                snapshot.phase_outputs.len()
+            )));
+        }
+
+        let skip_phases = snapshot.completed_phase;
+        Ok(RestoreOutcome {
+            snapshot,
+            skip_phases,
+        })
+    }
+
+    /// Like [`Self::restore`], but also verifies every artifact referenced by
+    /// the restored snapshot's phase outputs against `artifacts`, refusing to
+    /// hand back a snapshot that depends on a missing or corrupt blob.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever `Self::restore` can return, plus
+    /// `Error::ArtifactMissing`/`Error::ArtifactCorrupt` if an artifact the
+    /// snapshot depends on fails verification.
+    pub fn restore_with_artifacts(&self, artifacts: &ArtifactStore) -> Result<RestoreOutcome> {
+        let outcome = self.restore()?;
+
+        for output in &outcome.snapshot.phase_outputs {
+            artifacts.verify_each(&output.artifacts)?;
+        }
+
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("oya-checkpoint-store-test-{name}"))
+    }
+
+    #[test]
+    fn test_save_then_restore_round_trips() {
+        let dir = temp_dir("round-trip");
+        let store = CheckpointStore::new(&dir).expect("create store");
+
+        let snapshot = WorkflowSnapshot::new(1, vec![PhaseOutput::success(vec![1, 2, 3])], 1);
+        store.save(&snapshot).expect("save");
+
+        let outcome = store.restore().expect("restore");
+        assert_eq!(outcome.skip_phases, 1);
+        assert_eq!(outcome.snapshot.sequence, 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_restore_picks_highest_sequence() {
+        let dir = temp_dir("highest-sequence");
+        let store = CheckpointStore::new(&dir).expect("create store");
+
+        store
+            .save(&WorkflowSnapshot::new(1, vec![PhaseOutput::success(vec![])], 1))
+            .expect("save 1");
+        store
+            .save(&WorkflowSnapshot::new(
+                2,
+                vec![PhaseOutput::success(vec![]), PhaseOutput::success(vec![])],
+                2,
+            ))
+            .expect("save 2");
+
+        let outcome = store.restore().expect("restore");
+        assert_eq!(outcome.snapshot.sequence, 2);
+        assert_eq!(outcome.skip_phases, 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_restore_fails_when_store_empty() {
+        let dir = temp_dir("empty");
+        let store = CheckpointStore::new(&dir).expect("create store");
+
+        let result = store.restore();
+        assert!(matches!(result, Err(Error::CheckpointRestoreFailed { .. })));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_restore_rejects_shape_mismatch() {
+        let dir = temp_dir("shape-mismatch");
+        let store = CheckpointStore::new(&dir).expect("create store");
+
+        // completed_phase claims 2 but only 1 output is recorded.
+        store
+            .save(&WorkflowSnapshot::new(2, vec![PhaseOutput::success(vec![])], 1))
+            .expect("save");
+
+        let result = store.restore();
+        assert!(matches!(result, Err(Error::CheckpointCorrupt { .. })));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_restore_with_artifacts_passes_when_all_present() {
+        let checkpoint_dir = temp_dir("artifacts-ok-checkpoints");
+        let artifact_dir = temp_dir("artifacts-ok-blobs");
+        let store = CheckpointStore::new(&checkpoint_dir).expect("create store");
+        let mut artifacts = ArtifactStore::open(&artifact_dir).expect("open artifact store");
+
+        let hash = artifacts.put(b"build output").expect("put artifact");
+        store
+            .save(&WorkflowSnapshot::new(
+                1,
+                vec![PhaseOutput::success(vec![]).with_artifacts(vec![hash])],
+                1,
+            ))
+            .expect("save");
+
+        let outcome = store
+            .restore_with_artifacts(&artifacts)
+            .expect("restore with artifacts");
+        assert_eq!(outcome.skip_phases, 1);
+
+        let _ = fs::remove_dir_all(&checkpoint_dir);
+        let _ = fs::remove_dir_all(&artifact_dir);
+    }
+
+    #[test]
+    fn test_restore_with_artifacts_rejects_missing_blob() {
+        let checkpoint_dir = temp_dir("artifacts-missing-checkpoints");
+        let artifact_dir = temp_dir("artifacts-missing-blobs");
+        let store = CheckpointStore::new(&checkpoint_dir).expect("create store");
+        let artifacts = ArtifactStore::open(&artifact_dir).expect("open artifact store");
+
+        store
+            .save(&WorkflowSnapshot::new(
+                1,
+                vec![PhaseOutput::success(vec![])
+                    .with_artifacts(vec!["never-stored-hash".to_string()])],
+                1,
+            ))
+            .expect("save");
+
+        let result = store.restore_with_artifacts(&artifacts);
+        assert!(matches!(result, Err(Error::ArtifactMissing { .. })));
+
+        let _ = fs::remove_dir_all(&checkpoint_dir);
+        let _ = fs::remove_dir_all(&artifact_dir);
+    }
+}
@@ -70,6 +70,26 @@ pub enum Error {
         phase_name: String,
         attempts: u32,
     },
+    /// Writing a checkpoint snapshot to the store failed.
+    CheckpointWriteFailed {
+        reason: String,
+    },
+    /// Restoring a checkpoint snapshot from the store failed.
+    CheckpointRestoreFailed {
+        reason: String,
+    },
+    /// A checkpoint snapshot exists but is malformed or internally inconsistent.
+    CheckpointCorrupt {
+        reason: String,
+    },
+    /// An artifact manifest references a hash with no backing blob on disk.
+    ArtifactMissing {
+        hash: String,
+    },
+    /// An artifact blob's recomputed hash disagrees with its manifest key.
+    ArtifactCorrupt {
+        hash: String,
+    },
 }
 
 impl fmt::Display for Error {
@@ -126,6 +146,21 @@ impl fmt::Display for Error {
                     "phase '{phase_name}' exceeded max retries ({attempts} attempts)"
                 )
             }
+            Self::CheckpointWriteFailed { reason } => {
+                write!(f, "checkpoint write failed: {reason}")
+            }
+            Self::CheckpointRestoreFailed { reason } => {
+                write!(f, "checkpoint restore failed: {reason}")
+            }
+            Self::CheckpointCorrupt { reason } => {
+                write!(f, "checkpoint corrupt: {reason}")
+            }
+            Self::ArtifactMissing { hash } => {
+                write!(f, "artifact '{hash}' has no backing blob")
+            }
+            Self::ArtifactCorrupt { hash } => {
+                write!(f, "artifact '{hash}' failed integrity verification")
+            }
         }
     }
 }
@@ -237,6 +272,37 @@ impl Error {
         }
     }
 
+    /// Create a checkpoint write failed error.
+    pub fn checkpoint_write_failed(reason: impl Into<String>) -> Self {
+        Self::CheckpointWriteFailed {
+            reason: reason.into(),
+        }
+    }
+
+    /// Create a checkpoint restore failed error.
+    pub fn checkpoint_restore_failed(reason: impl Into<String>) -> Self {
+        Self::CheckpointRestoreFailed {
+            reason: reason.into(),
+        }
+    }
+
+    /// Create a checkpoint corrupt error.
+    pub fn checkpoint_corrupt(reason: impl Into<String>) -> Self {
+        Self::CheckpointCorrupt {
+            reason: reason.into(),
+        }
+    }
+
+    /// Create an artifact missing error.
+    pub fn artifact_missing(hash: impl Into<String>) -> Self {
+        Self::ArtifactMissing { hash: hash.into() }
+    }
+
+    /// Create an artifact corrupt error.
+    pub fn artifact_corrupt(hash: impl Into<String>) -> Self {
+        Self::ArtifactCorrupt { hash: hash.into() }
+    }
+
     /// Check if this error is retryable.
     pub fn is_retryable(&self) -> bool {
         matches!(
@@ -265,4 +331,27 @@ mod tests {
         assert!(Error::phase_timeout("test", 30).is_retryable());
         assert!(!Error::handler_not_found("test").is_retryable());
     }
+
+    #[test]
+    fn test_checkpoint_errors_display() {
+        assert!(Error::checkpoint_write_failed("disk full")
+            .to_string()
+            .contains("disk full"));
+        assert!(Error::checkpoint_restore_failed("not found")
+            .to_string()
+            .contains("not found"));
+        assert!(Error::checkpoint_corrupt("bad shape")
+            .to_string()
+            .contains("bad shape"));
+    }
+
+    #[test]
+    fn test_artifact_errors_display() {
+        assert!(Error::artifact_missing("deadbeef")
+            .to_string()
+            .contains("deadbeef"));
+        assert!(Error::artifact_corrupt("deadbeef")
+            .to_string()
+            .contains("deadbeef"));
+    }
 }
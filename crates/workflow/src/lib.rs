@@ -68,9 +68,11 @@ pub mod types;
 // Re-export main types
 pub use checkpoint::{
     compress, compression_ratio, decompress, restore_checkpoint, serialize_state, space_savings,
-    start_auto_checkpoint, AutoCheckpointConfig, AutoCheckpointTimer, CheckpointDecision,
-    CheckpointId, CheckpointManager, CheckpointMetadata, CheckpointStorage, CheckpointStrategy,
-    RestoreError, RestoreResult, StateProvider, StorageStats, DEFAULT_AUTO_CHECKPOINT_INTERVAL,
+    start_auto_checkpoint, ArtifactEntry, ArtifactHash, ArtifactStore, AutoCheckpointConfig,
+    AutoCheckpointTimer, CheckpointDecision, CheckpointId, CheckpointManager, CheckpointMetadata,
+    CheckpointStorage, CheckpointStore, CheckpointStrategy, RestoreError, RestoreOutcome,
+    RestoreResult, StateProvider, StorageStats, VerifyFailure, WorkflowSnapshot,
+    DEFAULT_AUTO_CHECKPOINT_INTERVAL,
 };
 pub use cleanup::{
     check_zjj_exit_code, cleanup_task, create_cleanup_timer, log_cleanup_results, parse_zjj_json,
@@ -25,6 +25,10 @@ pub enum Error {
         source: String,
         is_not_found: bool,
     },
+    RateLimited {
+        /// Seconds the caller should wait before retrying
+        retry_after: u64,
+    },
     Unknown(String),
 }
 
@@ -73,6 +77,9 @@ impl fmt::Display for Error {
                     write!(f, "Failed to {operation}: {source}")
                 }
             }
+            Self::RateLimited { retry_after } => {
+                write!(f, "Rate limited: retry after {retry_after}s")
+            }
             Self::Unknown(msg) => write!(f, "Unknown error: {msg}"),
         }
     }
@@ -115,6 +122,7 @@ impl Error {
             Self::HookFailed { .. } => "HOOK_FAILED",
             Self::HookExecutionFailed { .. } => "HOOK_EXECUTION_FAILED",
             Self::JjCommandError { .. } => "JJ_COMMAND_ERROR",
+            Self::RateLimited { .. } => "RATE_LIMITED",
             Self::Unknown(_) => "UNKNOWN",
         }
     }
@@ -176,6 +184,10 @@ impl Error {
                 "source": source,
                 "is_not_found": is_not_found
             })),
+            Self::RateLimited { retry_after } => Some(serde_json::json!({
+                "operation": "rate_limit",
+                "retry_after": retry_after
+            })),
             _ => None,
         }
     }
@@ -8,7 +8,8 @@ use zjj_core::jj;
 use crate::{
     cli::{attach_to_zellij_session, is_inside_zellij, run_command},
     commands::{check_prerequisites, get_session_db},
-    session::{validate_session_name, SessionStatus, SessionUpdate},
+    db::{RateLimiter, CREATE_BUCKET_KEY},
+    session::{validate_session_name, SessionStatus},
 };
 
 /// Options for the add command
@@ -21,6 +22,10 @@ pub struct AddOptions {
     pub template: Option<String>,
     /// Create workspace but don't open Zellij tab
     pub no_open: bool,
+    /// Idempotency key: a retried `add` with the same key and the same
+    /// name/workspace returns the session created by the first call instead
+    /// of erroring on "already exists".
+    pub idempotency_key: Option<String>,
 }
 
 impl AddOptions {
@@ -32,6 +37,7 @@ impl AddOptions {
             no_hooks: false,
             template: None,
             no_open: false,
+            idempotency_key: None,
         }
     }
 }
@@ -51,15 +57,20 @@ pub fn run_with_options(options: &AddOptions) -> Result<()> {
 
     let db = get_session_db()?;
 
-    // Check if session already exists (REQ-ERR-004)
-    if db.get(&options.name)?.is_some() {
+    // Check if session already exists (REQ-ERR-004). An idempotency key
+    // changes this from a hard error to "was this key's request already
+    // applied?", answered below by `create_idempotent_guarded` instead.
+    if options.idempotency_key.is_none() && db.get(&options.name)?.is_some() {
         bail!("Session '{}' already exists", options.name);
     }
 
     let root = check_prerequisites()?;
     let workspace_path = format!("{}/.jjz/workspaces/{}", root.display(), options.name);
 
-    // Create the JJ workspace (REQ-JJ-003, REQ-JJ-007)
+    // Create the JJ workspace (REQ-JJ-003, REQ-JJ-007). Note this runs before
+    // the session is written to the database, so a crash-and-retry of the whole command is only
+    // idempotent from here on; a retry that reruns workspace creation against
+    // an already-provisioned workspace still needs `jj` itself to be a no-op.
     create_jj_workspace(&options.name, &workspace_path).with_context(|| {
         format!(
             "Failed to create JJ workspace for session '{}'",
@@ -67,32 +78,35 @@ pub fn run_with_options(options: &AddOptions) -> Result<()> {
         )
     })?;
 
-    // Insert into database with status 'creating' (REQ-STATE-004)
-    let mut session = db.create(&options.name, &workspace_path)?;
+    // Insert into database with status 'creating' (REQ-STATE-004), gated by
+    // the always-on create rate limiter (REQ-CLI-RATE-001) so a runaway
+    // script cannot spawn unbounded sessions. A given idempotency key is
+    // honored at most once, regardless of how many times `add` is retried.
+    let limiter = RateLimiter::default();
+    let mut session = match &options.idempotency_key {
+        Some(key) => db.create_idempotent_guarded(
+            key,
+            CREATE_BUCKET_KEY,
+            &limiter,
+            &options.name,
+            &workspace_path,
+            None,
+        )?,
+        None => db.create_guarded(CREATE_BUCKET_KEY, &limiter, &options.name, &workspace_path)?,
+    };
 
     // Execute post_create hooks unless --no-hooks (REQ-CLI-004, REQ-CLI-005)
     if !options.no_hooks {
         if let Err(e) = execute_post_create_hooks(&workspace_path) {
-            // Hook failure → status 'failed' (REQ-HOOKS-003)
-            let _ = db.update(
-                &options.name,
-                SessionUpdate {
-                    status: Some(SessionStatus::Failed),
-                    ..Default::default()
-                },
-            );
+            // Hook failure → status 'failed' (REQ-HOOKS-003), recorded in the
+            // append-only transition log rather than a bare column write.
+            let _ = db.apply_transition(&options.name, SessionStatus::Failed, None);
             return Err(e).context("post_create hook failed");
         }
     }
 
     // Transition to 'active' status after successful creation (REQ-STATE-004)
-    db.update(
-        &options.name,
-        SessionUpdate {
-            status: Some(SessionStatus::Active),
-            ..Default::default()
-        },
-    )?;
+    db.apply_transition(&options.name, SessionStatus::Active, None)?;
     session.status = SessionStatus::Active;
 
     // Open Zellij tab unless --no-open (REQ-CLI-003)
@@ -278,6 +292,7 @@ mod tests {
         assert!(!opts.no_hooks);
         assert!(opts.template.is_none());
         assert!(!opts.no_open);
+        assert!(opts.idempotency_key.is_none());
     }
 
     #[test]
@@ -222,6 +222,7 @@ pub fn run_command_introspect(command: &str, json: bool) -> Result<()> {
         "focus" => get_focus_introspection(),
         "status" => get_status_introspection(),
         "sync" => get_sync_introspection(),
+        "retry" => get_retry_introspection(),
         "diff" => get_diff_introspection(),
         "introspect" => get_introspect_introspection(),
         "doctor" => get_doctor_introspection(),
@@ -348,6 +349,14 @@ fn get_add_introspection() -> CommandIntrospection {
                 default: Some(serde_json::json!(false)),
                 possible_values: vec![],
             },
+            FlagSpec {
+                long: "idempotency-key".to_string(),
+                short: None,
+                description: "Retrying with the same key returns the first call's session instead of erroring".to_string(),
+                flag_type: "string".to_string(),
+                default: None,
+                possible_values: vec![],
+            },
         ],
         examples: vec![
             CommandExample {
@@ -665,6 +674,49 @@ fn get_sync_introspection() -> CommandIntrospection {
     }
 }
 
+fn get_retry_introspection() -> CommandIntrospection {
+    CommandIntrospection {
+        command: "retry".to_string(),
+        description: "Retry a failed session under exponential backoff".to_string(),
+        aliases: vec![],
+        arguments: vec![ArgumentSpec {
+            name: "name".to_string(),
+            arg_type: "string".to_string(),
+            required: true,
+            description: "Name of the failed session to retry".to_string(),
+            validation: None,
+            examples: vec!["my-session".to_string()],
+        }],
+        flags: vec![],
+        examples: vec![CommandExample {
+            command: "jjz retry my-session".to_string(),
+            description: "Retry a failed session".to_string(),
+        }],
+        prerequisites: Prerequisites {
+            initialized: true,
+            jj_installed: false,
+            zellij_running: false,
+            custom: vec![],
+        },
+        side_effects: vec![
+            "Transitions the session from 'failed' to 'creating'".to_string(),
+            "Increments the session's retry attempt counter".to_string(),
+        ],
+        error_conditions: vec![
+            ErrorCondition {
+                code: "NOT_FAILED".to_string(),
+                description: "Session is not in the 'failed' status".to_string(),
+                resolution: "Only failed sessions can be retried".to_string(),
+            },
+            ErrorCondition {
+                code: "RETRY_EXHAUSTED".to_string(),
+                description: "Session has exhausted its retry attempt budget".to_string(),
+                resolution: "Remove the session and create a new one".to_string(),
+            },
+        ],
+    }
+}
+
 fn get_diff_introspection() -> CommandIntrospection {
     CommandIntrospection {
         command: "diff".to_string(),
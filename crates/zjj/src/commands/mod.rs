@@ -11,6 +11,7 @@ pub mod introspect;
 pub mod list;
 pub mod query;
 pub mod remove;
+pub mod retry;
 pub mod status;
 pub mod sync;
 
@@ -0,0 +1,100 @@
+//! Retry a `Failed` session under a bounded exponential-backoff policy
+
+use anyhow::Result;
+
+use crate::{
+    commands::get_session_db,
+    db::SessionDb,
+    json_output::RetryOutput,
+    session::{RetryPolicy, StateTransitionEvent},
+};
+
+/// Options for the retry command
+#[derive(Debug, Clone, Default)]
+pub struct RetryOptions {
+    /// Output as JSON
+    pub json: bool,
+}
+
+/// Run the retry command with options
+pub fn run_with_options(name: &str, options: &RetryOptions) -> Result<()> {
+    let db = get_session_db()?;
+    let (event, attempt) = retry_session(&db, name)?;
+
+    if options.json {
+        let output = RetryOutput {
+            success: true,
+            session_name: name.to_string(),
+            from: event.from.to_string(),
+            to: event.to.to_string(),
+            attempt,
+        };
+        println!("{}", serde_json::to_string(&output)?);
+    } else {
+        println!(
+            "Retrying session '{name}' ({} -> {}, attempt {attempt})",
+            event.from, event.to
+        );
+    }
+
+    Ok(())
+}
+
+/// Transitions a `Failed` session back to `Creating` via
+/// [`SessionDb::request_retry`], using the default [`RetryPolicy`]. Rejected
+/// (attempt budget exhausted, or retried before `retry_after`) surfaces as an
+/// error rather than a silent no-op.
+fn retry_session(db: &SessionDb, name: &str) -> Result<(StateTransitionEvent, u32)> {
+    let policy = RetryPolicy::default();
+
+    let event = db
+        .request_retry(name, &policy, None)
+        .map_err(|e| anyhow::anyhow!("Failed to retry session '{name}': {e}"))?;
+
+    let attempt = db
+        .get(name)?
+        .map(|session| session.retry_attempt())
+        .unwrap_or_default();
+
+    Ok((event, attempt))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::session::SessionStatus;
+
+    fn setup_test_db() -> Result<(SessionDb, TempDir)> {
+        let dir = TempDir::new()?;
+        let db_path = dir.path().join("test.db");
+        let db = SessionDb::open(&db_path)?;
+        Ok((db, dir))
+    }
+
+    #[test]
+    fn test_retry_rejects_non_failed_session() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        db.create("s1", "/path")?;
+
+        let result = retry_session(&db, "s1");
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_retry_transitions_failed_session_to_creating() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        db.create("s1", "/path")?;
+        db.apply_transition("s1", SessionStatus::Failed, None)?;
+
+        let (event, attempt) = retry_session(&db, "s1")?;
+        assert_eq!(event.to, SessionStatus::Creating);
+        assert_eq!(attempt, 1);
+
+        let session = db.get("s1")?.expect("session should still exist");
+        assert_eq!(session.status, SessionStatus::Creating);
+        Ok(())
+    }
+}
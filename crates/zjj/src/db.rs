@@ -18,13 +18,140 @@ use std::{
 use rusqlite::Connection;
 use zjj_core::{Error, Result};
 
-use crate::session::{Session, SessionStatus, SessionUpdate};
+use crate::session::{
+    validate_status_transition, with_retry_state, RetryPolicy, Session, SessionStatus,
+    SessionUpdate, StateTransitionEvent,
+};
 
 /// Database wrapper for session storage with thread-safe connection management
 pub struct SessionDb {
     conn: Arc<Mutex<Connection>>,
 }
 
+/// A single operation in a [`SessionBulkWrite`] batch.
+#[derive(Debug, Clone)]
+pub enum SessionWriteModel {
+    /// Create a new session, optionally with a branch
+    Create {
+        /// Unique session name
+        name: String,
+        /// Path to the JJ workspace directory
+        workspace_path: String,
+        /// Optional git branch
+        branch: Option<String>,
+    },
+    /// Transition an existing session to a new status
+    UpdateStatus {
+        /// Session name
+        name: String,
+        /// Target status (validated against the current status)
+        to: SessionStatus,
+    },
+    /// Replace a session's metadata
+    SetMetadata {
+        /// Session name
+        name: String,
+        /// New metadata value
+        metadata: serde_json::Value,
+    },
+    /// Delete a session by name
+    Delete {
+        /// Session name
+        name: String,
+    },
+}
+
+/// A batch of session operations, modeled on the document-store bulk-write
+/// pattern.
+///
+/// This is a `SessionDb` library API for a caller that already has several
+/// operations to apply at once (e.g. an orchestrator driving `zjj` as a
+/// library); no `jjz` subcommand exposes it, since every CLI invocation
+/// acts on one session at a time.
+#[derive(Debug, Clone)]
+pub struct SessionBulkWrite {
+    /// Operations to apply, in order
+    pub ops: Vec<SessionWriteModel>,
+    /// When `true`, stop at the first failing op; when `false`, attempt every
+    /// op and collect all errors while applying the successful ones.
+    pub ordered: bool,
+}
+
+/// The outcome of a [`SessionBulkWrite`], with per-operation error reporting.
+#[derive(Debug, Default)]
+pub struct BulkWriteResult {
+    /// Number of sessions created
+    pub inserted: usize,
+    /// Number of sessions updated (status or metadata)
+    pub updated: usize,
+    /// Number of sessions deleted
+    pub deleted: usize,
+    /// Errors keyed by the zero-based index of the failing operation
+    pub errors: Vec<(usize, Error)>,
+}
+
+/// Compute a stable fingerprint of a create request over
+/// `name + workspace_path + branch`, used to detect idempotency-key reuse with
+/// different inputs. Rendered as a hex string for storage.
+fn request_fingerprint(name: &str, workspace_path: &str, branch: Option<&str>) -> String {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    name.hash(&mut hasher);
+    workspace_path.hash(&mut hasher);
+    branch.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// A classic token-bucket rate limiter with an optional concurrency ceiling,
+/// used as a pre-create guard so a runaway script cannot spawn unbounded
+/// sessions (each of which provisions a real workspace).
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    /// Maximum number of tokens the bucket can hold
+    pub capacity: f64,
+    /// Tokens added per second
+    pub refill_rate: f64,
+    /// Reject creation when `Active` + `Creating` sessions reach this ceiling
+    pub max_concurrent: Option<usize>,
+}
+
+impl RateLimiter {
+    /// Seconds to wait for enough tokens to accumulate for one more create.
+    fn retry_after(&self, tokens: f64) -> u64 {
+        if self.refill_rate <= 0.0 {
+            return u64::MAX;
+        }
+        ((1.0 - tokens) / self.refill_rate).ceil().max(1.0) as u64
+    }
+}
+
+impl Default for RateLimiter {
+    /// A permissive default (one session every 2s, up to a burst of 5, with
+    /// no concurrency ceiling) meant to stop a runaway script rather than
+    /// constrain normal interactive use. Used as the always-on guard for
+    /// `jjz add`; see [`CREATE_BUCKET_KEY`].
+    fn default() -> Self {
+        Self {
+            capacity: 5.0,
+            refill_rate: 0.5,
+            max_concurrent: None,
+        }
+    }
+}
+
+/// Token-bucket key shared by every `jjz add` invocation against a given
+/// database, since the guard is a global ceiling on session creation rather
+/// than one scoped per caller.
+pub const CREATE_BUCKET_KEY: &str = "add";
+
+/// Which [`BulkWriteResult`] counter a successful write advances.
+enum WriteKind {
+    Inserted,
+    Updated,
+    Deleted,
+}
+
 impl SessionDb {
     /// Open or create a session database at the given path
     ///
@@ -84,6 +211,59 @@ impl SessionDb {
         conn.execute("CREATE INDEX IF NOT EXISTS idx_name ON sessions(name)", [])
             .map_err(|e| Error::DatabaseError(format!("Failed to create name index: {e}")))?;
 
+        // Append-only log of accepted status transitions. Rows are only ever
+        // inserted or selected, never updated or deleted, so the table is an
+        // auditable history of how each session moved through its lifecycle.
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS state_transitions (
+                event_id TEXT PRIMARY KEY,
+                session_id INTEGER NOT NULL,
+                from_status TEXT NOT NULL,
+                to_status TEXT NOT NULL,
+                timestamp INTEGER NOT NULL,
+                actor TEXT
+            )",
+            [],
+        )
+        .map_err(|e| {
+            Error::DatabaseError(format!("Failed to create state_transitions table: {e}"))
+        })?;
+
+        // Index for replaying a single session's history in order
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_transitions_session
+             ON state_transitions(session_id, event_id)",
+            [],
+        )
+        .map_err(|e| Error::DatabaseError(format!("Failed to create transitions index: {e}")))?;
+
+        // Maps an idempotency key to the session it created, with a fingerprint
+        // of the originating request so a reused key with different inputs can
+        // be rejected.
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS idempotency_key (
+                key TEXT PRIMARY KEY,
+                session_id INTEGER NOT NULL,
+                request_hash TEXT NOT NULL,
+                created_at INTEGER NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| {
+            Error::DatabaseError(format!("Failed to create idempotency_key table: {e}"))
+        })?;
+
+        // Persistent token-bucket state so create rate limits survive restarts.
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS token_bucket (
+                key TEXT PRIMARY KEY,
+                tokens REAL NOT NULL,
+                last_refill INTEGER NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| Error::DatabaseError(format!("Failed to create token_bucket table: {e}")))?;
+
         // Create trigger to auto-update updated_at timestamp
         conn.execute(
             "CREATE TRIGGER IF NOT EXISTS update_timestamp
@@ -115,6 +295,13 @@ impl SessionDb {
             .lock()
             .map_err(|e| Error::DatabaseError(format!("Lock error: {e}")))?;
 
+        Self::insert_session(&conn, name, workspace_path)
+    }
+
+    /// Insert a new `Creating` session using an already-held connection, so a
+    /// caller that must check something else (e.g. [`SessionDb::create_guarded`])
+    /// can do so under the same lock as the insert.
+    fn insert_session(conn: &Connection, name: &str, workspace_path: &str) -> Result<Session> {
         let now = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .map_err(|e| Error::Unknown(format!("System time error: {e}")))?
@@ -136,7 +323,6 @@ impl SessionDb {
         })?;
 
         let id = conn.last_insert_rowid();
-        drop(conn);
 
         Ok(Session {
             id: Some(id),
@@ -152,6 +338,363 @@ impl SessionDb {
         })
     }
 
+    /// Pre-create guard enforcing a token-bucket rate limit and an optional
+    /// concurrency ceiling.
+    ///
+    /// The bucket refills lazily: `tokens = min(capacity, tokens + elapsed *
+    /// refill_rate)` is computed on each check, then one token is consumed. The
+    /// `{ tokens, last_refill }` state is persisted per `bucket_key` so limits
+    /// survive restarts.
+    ///
+    /// This only performs the check; nothing stops another call from running
+    /// between it returning and a subsequent `create`. Prefer
+    /// [`SessionDb::create_guarded`], which checks and creates under the same
+    /// held lock, for any call site that runs concurrently with itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::RateLimited { retry_after }` when the concurrency ceiling
+    /// is reached or the bucket is empty, or `Error::DatabaseError` on failure.
+    pub fn guard_create(&self, bucket_key: &str, limiter: &RateLimiter) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| Error::DatabaseError(format!("Lock error: {e}")))?;
+
+        Self::check_and_reserve(&conn, bucket_key, limiter)
+    }
+
+    /// Create a session, atomically gated by the same rate limit and
+    /// concurrency ceiling as [`SessionDb::guard_create`].
+    ///
+    /// Unlike calling `guard_create` followed by `create`, the ceiling count,
+    /// the token-bucket consumption, and the `INSERT INTO sessions` all run
+    /// under one held connection lock, so two callers racing through this
+    /// method can never both pass the gate before either session exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::RateLimited { retry_after }` when the concurrency
+    /// ceiling is reached or the bucket is empty, or `Error::DatabaseError`
+    /// if either check or the insert fails.
+    pub fn create_guarded(
+        &self,
+        bucket_key: &str,
+        limiter: &RateLimiter,
+        name: &str,
+        workspace_path: &str,
+    ) -> Result<Session> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| Error::DatabaseError(format!("Lock error: {e}")))?;
+
+        Self::check_and_reserve(&conn, bucket_key, limiter)?;
+        Self::insert_session(&conn, name, workspace_path)
+    }
+
+    /// Concurrency ceiling plus token-bucket check, run against an
+    /// already-held `conn` so the caller can fold it into a larger critical
+    /// section (see [`SessionDb::create_guarded`]).
+    fn check_and_reserve(conn: &Connection, bucket_key: &str, limiter: &RateLimiter) -> Result<()> {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|e| Error::Unknown(format!("System time error: {e}")))?
+            .as_secs();
+
+        if let Some(max) = limiter.max_concurrent {
+            let in_flight = Self::count_in_flight(conn)?;
+            if in_flight >= max {
+                return Err(Error::RateLimited { retry_after: 1 });
+            }
+        }
+
+        let state: Option<(f64, u64)> = conn
+            .query_row(
+                "SELECT tokens, last_refill FROM token_bucket WHERE key = ?1",
+                [bucket_key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map(Some)
+            .or_else(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                other => Err(Error::DatabaseError(format!(
+                    "Failed to read token bucket: {other}"
+                ))),
+            })?;
+
+        let (prev_tokens, last_refill) = state.unwrap_or((limiter.capacity, now));
+        let elapsed = now.saturating_sub(last_refill) as f64;
+        let mut tokens = (prev_tokens + elapsed * limiter.refill_rate).min(limiter.capacity);
+
+        if tokens < 1.0 {
+            return Err(Error::RateLimited {
+                retry_after: limiter.retry_after(tokens),
+            });
+        }
+
+        tokens -= 1.0;
+        conn.execute(
+            "INSERT INTO token_bucket (key, tokens, last_refill) VALUES (?1, ?2, ?3)
+             ON CONFLICT(key) DO UPDATE SET tokens = ?2, last_refill = ?3",
+            (bucket_key, tokens, now),
+        )
+        .map_err(|e| Error::DatabaseError(format!("Failed to persist token bucket: {e}")))?;
+
+        Ok(())
+    }
+
+    /// Count sessions in `Active` or `Creating` status against an
+    /// already-held `conn`, used by [`SessionDb::check_and_reserve`] for the
+    /// concurrency ceiling without re-locking via [`SessionDb::list`].
+    fn count_in_flight(conn: &Connection) -> Result<usize> {
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sessions WHERE status IN (?1, ?2)",
+                (
+                    SessionStatus::Active.to_string(),
+                    SessionStatus::Creating.to_string(),
+                ),
+                |row| row.get(0),
+            )
+            .map_err(|e| Error::DatabaseError(format!("Failed to count in-flight sessions: {e}")))?;
+
+        Ok(count.max(0) as usize)
+    }
+
+    /// Create a session at most once per idempotency key.
+    ///
+    /// If `key` has been seen before, the previously created [`Session`] is
+    /// returned unchanged, provided the request fingerprint (derived from
+    /// `name + workspace_path + branch`) matches; reusing a key with different
+    /// inputs is a [`Error::ValidationError`]. Otherwise the session and the
+    /// `(key, session_id, request_hash)` record are written together so a
+    /// retried create never spawns a duplicate workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ValidationError` on a conflicting key reuse, or
+    /// `Error::DatabaseError` on persistence failure.
+    pub fn create_idempotent(
+        &self,
+        key: &str,
+        name: &str,
+        workspace_path: &str,
+        branch: Option<&str>,
+    ) -> Result<Session> {
+        let request_hash = request_fingerprint(name, workspace_path, branch);
+
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| Error::DatabaseError(format!("Lock error: {e}")))?;
+
+        if let Some(session) = Self::lookup_idempotency_key(&conn, key, &request_hash)? {
+            return Ok(session);
+        }
+
+        Self::insert_idempotent_session(&conn, key, &request_hash, name, workspace_path, branch)
+    }
+
+    /// Create a session at most once per idempotency key, additionally gated
+    /// by the same rate limit and concurrency ceiling as
+    /// [`SessionDb::create_guarded`].
+    ///
+    /// A replayed key is a read of the previously created session and does
+    /// not consume a token or count against the concurrency ceiling, since no
+    /// new session is being created; only the first call for a given key
+    /// passes through [`SessionDb::check_and_reserve`]. Everything still
+    /// happens under one held lock, so this composes the same atomicity
+    /// guarantee `create_guarded` gives the unkeyed path.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ValidationError` on a conflicting key reuse,
+    /// `Error::RateLimited { retry_after }` when the concurrency ceiling is
+    /// reached or the bucket is empty, or `Error::DatabaseError` on
+    /// persistence failure.
+    pub fn create_idempotent_guarded(
+        &self,
+        key: &str,
+        bucket_key: &str,
+        limiter: &RateLimiter,
+        name: &str,
+        workspace_path: &str,
+        branch: Option<&str>,
+    ) -> Result<Session> {
+        let request_hash = request_fingerprint(name, workspace_path, branch);
+
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| Error::DatabaseError(format!("Lock error: {e}")))?;
+
+        if let Some(session) = Self::lookup_idempotency_key(&conn, key, &request_hash)? {
+            return Ok(session);
+        }
+
+        Self::check_and_reserve(&conn, bucket_key, limiter)?;
+        Self::insert_idempotent_session(&conn, key, &request_hash, name, workspace_path, branch)
+    }
+
+    /// Look up a previously recorded idempotency key against an
+    /// already-held `conn`, returning the session it created.
+    ///
+    /// Returns `Ok(None)` when the key has not been seen before, so the
+    /// caller can fall through to its own insert under the same lock.
+    fn lookup_idempotency_key(
+        conn: &Connection,
+        key: &str,
+        request_hash: &str,
+    ) -> Result<Option<Session>> {
+        let existing: Option<(i64, String)> = conn
+            .query_row(
+                "SELECT session_id, request_hash FROM idempotency_key WHERE key = ?1",
+                [key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map(Some)
+            .or_else(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                other => Err(Error::DatabaseError(format!(
+                    "Failed to read idempotency key: {other}"
+                ))),
+            })?;
+
+        let Some((session_id, stored_hash)) = existing else {
+            return Ok(None);
+        };
+
+        if stored_hash != request_hash {
+            return Err(Error::ValidationError(format!(
+                "Idempotency key '{key}' was reused with different inputs"
+            )));
+        }
+
+        Self::load_by_id(conn, session_id)?.ok_or_else(|| {
+            Error::DatabaseError(format!(
+                "Idempotency key '{key}' references a missing session"
+            ))
+        }).map(Some)
+    }
+
+    /// Insert a new `Creating` session and its idempotency-key record
+    /// together under an already-held `conn`, so a caller that must check
+    /// something else first (e.g. [`SessionDb::create_idempotent_guarded`])
+    /// can do so under the same lock as the insert.
+    fn insert_idempotent_session(
+        conn: &Connection,
+        key: &str,
+        request_hash: &str,
+        name: &str,
+        workspace_path: &str,
+        branch: Option<&str>,
+    ) -> Result<Session> {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|e| Error::Unknown(format!("System time error: {e}")))?
+            .as_secs();
+
+        conn.execute(
+            "INSERT INTO sessions (name, status, workspace_path, branch, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            (
+                name,
+                SessionStatus::Creating.to_string(),
+                workspace_path,
+                branch,
+                now,
+                now,
+            ),
+        )
+        .map_err(|e| {
+            if e.to_string().to_lowercase().contains("unique") {
+                Error::DatabaseError(format!("Session '{name}' already exists"))
+            } else {
+                Error::DatabaseError(format!("Failed to create session: {e}"))
+            }
+        })?;
+
+        let session_id = conn.last_insert_rowid();
+
+        conn.execute(
+            "INSERT INTO idempotency_key (key, session_id, request_hash, created_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            (key, session_id, request_hash, now),
+        )
+        .map_err(|e| Error::DatabaseError(format!("Failed to record idempotency key: {e}")))?;
+
+        Ok(Session {
+            id: Some(session_id),
+            name: name.to_string(),
+            status: SessionStatus::Creating,
+            workspace_path: workspace_path.to_string(),
+            zellij_tab: format!("jjz:{name}"),
+            branch: branch.map(str::to_string),
+            created_at: now,
+            updated_at: now,
+            last_synced: None,
+            metadata: None,
+        })
+    }
+
+    /// Load a session by its database id using an already-held connection.
+    fn load_by_id(conn: &Connection, id: i64) -> Result<Option<Session>> {
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, name, status, workspace_path, branch, created_at, updated_at, last_synced, metadata
+                 FROM sessions WHERE id = ?1",
+            )
+            .map_err(|e| Error::DatabaseError(format!("Failed to prepare query: {e}")))?;
+
+        let session = stmt
+            .query_row([id], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, u64>(5)?,
+                    row.get::<_, u64>(6)?,
+                    row.get::<_, Option<u64>>(7)?,
+                    row.get::<_, Option<String>>(8)?,
+                ))
+            })
+            .map(Some)
+            .or_else(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                other => Err(Error::DatabaseError(format!("Failed to read session: {other}"))),
+            })?;
+
+        let Some((id, name, status_str, workspace_path, branch, created_at, updated_at, last_synced, metadata_str)) =
+            session
+        else {
+            return Ok(None);
+        };
+
+        let metadata = match metadata_str {
+            Some(s) => Some(
+                serde_json::from_str(&s)
+                    .map_err(|e| Error::ParseError(format!("Invalid metadata JSON: {e}")))?,
+            ),
+            None => None,
+        };
+
+        Ok(Some(Session {
+            id: Some(id),
+            name: name.clone(),
+            status: SessionStatus::from_str(&status_str)?,
+            workspace_path,
+            zellij_tab: format!("jjz:{name}"),
+            branch,
+            created_at,
+            updated_at,
+            last_synced,
+            metadata,
+        }))
+    }
+
     /// Get a session by name
     ///
     /// # Errors
@@ -247,6 +790,19 @@ impl SessionDb {
     ///
     /// Returns `Error::DatabaseError` if the database update fails.
     pub fn update(&self, name: &str, update: SessionUpdate) -> Result<()> {
+        // Resolve the effective metadata outside the lock: an explicit metadata
+        // update wins, otherwise `reset_retry` strips the retry state from the
+        // session's current metadata. Fetching here avoids a self-deadlock on
+        // the connection mutex.
+        let metadata_update = if update.metadata.is_some() {
+            update.metadata
+        } else if update.reset_retry {
+            let current = self.get(name)?.and_then(|s| s.metadata);
+            Some(crate::session::clear_retry_state(current).unwrap_or(serde_json::Value::Null))
+        } else {
+            None
+        };
+
         let conn = self
             .conn
             .lock()
@@ -270,11 +826,16 @@ impl SessionDb {
             params.push(Box::new(last_synced));
         }
 
-        if let Some(metadata) = update.metadata {
+        if let Some(metadata) = metadata_update {
             updates.push("metadata = ?");
-            let json_str = serde_json::to_string(&metadata)
-                .map_err(|e| Error::ParseError(format!("Failed to serialize metadata: {e}")))?;
-            params.push(Box::new(json_str));
+            if metadata.is_null() {
+                params.push(Box::new(None::<String>));
+            } else {
+                let json_str = serde_json::to_string(&metadata).map_err(|e| {
+                    Error::ParseError(format!("Failed to serialize metadata: {e}"))
+                })?;
+                params.push(Box::new(json_str));
+            }
         }
 
         if updates.is_empty() {
@@ -423,6 +984,288 @@ impl SessionDb {
         Ok(sessions)
     }
 
+    /// Apply a status transition and record it in the append-only log.
+    ///
+    /// Loads the session's current status, validates the transition via
+    /// [`validate_status_transition`], inserts an immutable row into
+    /// `state_transitions`, and updates the `status` column so it stays in sync
+    /// with the log. Prior transition rows are never modified or removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the session does not exist,
+    /// `Error::ValidationError` if the transition is not permitted, or
+    /// `Error::DatabaseError` if persistence fails.
+    pub fn apply_transition(
+        &self,
+        name: &str,
+        to: SessionStatus,
+        actor: Option<&str>,
+    ) -> Result<StateTransitionEvent> {
+        let session = self
+            .get(name)?
+            .ok_or_else(|| Error::NotFound(format!("Session '{name}' not found")))?;
+        let from = session.status;
+        let session_id = session
+            .id
+            .ok_or_else(|| Error::DatabaseError("Session is missing a database id".into()))?;
+
+        validate_status_transition(from, to)?;
+
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|e| Error::Unknown(format!("System time error: {e}")))?;
+        let timestamp = now.as_secs();
+        let millis = now.as_millis();
+
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| Error::DatabaseError(format!("Lock error: {e}")))?;
+
+        // A global insertion index keeps event ids monotonic even when two
+        // transitions land in the same millisecond. The lock serializes writes.
+        let index: i64 = conn
+            .query_row("SELECT COUNT(*) FROM state_transitions", [], |row| row.get(0))
+            .map_err(|e| Error::DatabaseError(format!("Failed to count transitions: {e}")))?;
+        let event_id = format!("{millis:013}-{index:010}");
+
+        conn.execute(
+            "INSERT INTO state_transitions (event_id, session_id, from_status, to_status, timestamp, actor)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            (
+                &event_id,
+                session_id,
+                from.to_string(),
+                to.to_string(),
+                timestamp,
+                actor,
+            ),
+        )
+        .map_err(|e| Error::DatabaseError(format!("Failed to record transition: {e}")))?;
+
+        conn.execute(
+            "UPDATE sessions SET status = ?1 WHERE id = ?2",
+            (to.to_string(), session_id),
+        )
+        .map_err(|e| Error::DatabaseError(format!("Failed to update session status: {e}")))?;
+
+        drop(conn);
+
+        Ok(StateTransitionEvent {
+            event_id,
+            session_id,
+            from,
+            to,
+            timestamp,
+            actor: actor.map(str::to_string),
+        })
+    }
+
+    /// Apply a batch of session operations, reporting per-operation results.
+    ///
+    /// In `ordered` mode the batch stops at the first failing operation and
+    /// records it; in unordered mode every operation is attempted and all
+    /// errors are collected while the successful operations still take effect.
+    /// `UpdateStatus` operations are validated against the current status, and
+    /// a rejected transition surfaces as that operation's error.
+    ///
+    /// # Errors
+    ///
+    /// This method never returns `Err`; operation failures are reported in
+    /// [`BulkWriteResult::errors`]. It returns `Result` for signature symmetry
+    /// with the rest of the API.
+    pub fn bulk_write(&self, batch: SessionBulkWrite) -> Result<BulkWriteResult> {
+        let mut result = BulkWriteResult::default();
+
+        for (index, op) in batch.ops.into_iter().enumerate() {
+            let outcome = self.apply_write_model(op);
+            match outcome {
+                Ok(WriteKind::Inserted) => result.inserted += 1,
+                Ok(WriteKind::Updated) => result.updated += 1,
+                Ok(WriteKind::Deleted) => result.deleted += 1,
+                Err(error) => {
+                    result.errors.push((index, error));
+                    if batch.ordered {
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Execute a single write model, returning which counter it advances.
+    fn apply_write_model(&self, op: SessionWriteModel) -> Result<WriteKind> {
+        match op {
+            SessionWriteModel::Create {
+                name,
+                workspace_path,
+                branch,
+            } => {
+                self.create(&name, &workspace_path)?;
+                if let Some(branch) = branch {
+                    self.update(
+                        &name,
+                        SessionUpdate {
+                            branch: Some(branch),
+                            ..Default::default()
+                        },
+                    )?;
+                }
+                Ok(WriteKind::Inserted)
+            }
+            SessionWriteModel::UpdateStatus { name, to } => {
+                self.apply_transition(&name, to, None)?;
+                Ok(WriteKind::Updated)
+            }
+            SessionWriteModel::SetMetadata { name, metadata } => {
+                self.update(
+                    &name,
+                    SessionUpdate {
+                        metadata: Some(metadata),
+                        ..Default::default()
+                    },
+                )?;
+                Ok(WriteKind::Updated)
+            }
+            SessionWriteModel::Delete { name } => {
+                if self.delete(&name)? {
+                    Ok(WriteKind::Deleted)
+                } else {
+                    Err(Error::NotFound(format!("Session '{name}' not found")))
+                }
+            }
+        }
+    }
+
+    /// Retry a `Failed` session under a bounded exponential-backoff policy.
+    ///
+    /// On success the session transitions `Failed -> Creating`, the retry
+    /// `attempt` counter is incremented, and `retry_after` is advanced by the
+    /// policy's backoff delay. The retry is rejected when the attempt budget is
+    /// exhausted (the session stays terminally `Failed`) or when it is
+    /// requested before the session's `retry_after` timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the session does not exist,
+    /// `Error::ValidationError` if the session is not `Failed`, the attempt
+    /// budget is exhausted, or the backoff window has not yet elapsed, or
+    /// `Error::DatabaseError` on persistence failure.
+    pub fn request_retry(
+        &self,
+        name: &str,
+        policy: &RetryPolicy,
+        actor: Option<&str>,
+    ) -> Result<StateTransitionEvent> {
+        let session = self
+            .get(name)?
+            .ok_or_else(|| Error::NotFound(format!("Session '{name}' not found")))?;
+
+        if session.status != SessionStatus::Failed {
+            return Err(Error::ValidationError(format!(
+                "Session '{name}' is {} and cannot be retried",
+                session.status
+            )));
+        }
+
+        let attempt = session.retry_attempt();
+        if attempt >= policy.max_attempts {
+            return Err(Error::ValidationError(format!(
+                "Session '{name}' exhausted its {} retry attempts",
+                policy.max_attempts
+            )));
+        }
+
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|e| Error::Unknown(format!("System time error: {e}")))?
+            .as_secs();
+
+        if let Some(retry_after) = session.next_retry_at() {
+            if now < retry_after {
+                return Err(Error::ValidationError(format!(
+                    "Session '{name}' cannot retry for another {}s",
+                    retry_after - now
+                )));
+            }
+        }
+
+        let delay = policy.backoff(attempt);
+        let retry_after = now + delay.as_secs();
+        let metadata = with_retry_state(session.metadata.clone(), attempt + 1, retry_after);
+
+        // Persist the updated retry state, then record the transition.
+        self.update(
+            name,
+            SessionUpdate {
+                metadata: Some(metadata),
+                ..Default::default()
+            },
+        )?;
+
+        self.apply_transition(name, SessionStatus::Creating, actor)
+    }
+
+    /// Return a session's transition history in `event_id` (chronological) order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotFound` if the session does not exist, or
+    /// `Error::DatabaseError` if the query fails.
+    pub fn history(&self, name: &str) -> Result<Vec<StateTransitionEvent>> {
+        let session = self
+            .get(name)?
+            .ok_or_else(|| Error::NotFound(format!("Session '{name}' not found")))?;
+        let session_id = session
+            .id
+            .ok_or_else(|| Error::DatabaseError("Session is missing a database id".into()))?;
+
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| Error::DatabaseError(format!("Lock error: {e}")))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT event_id, session_id, from_status, to_status, timestamp, actor
+                 FROM state_transitions WHERE session_id = ?1 ORDER BY event_id",
+            )
+            .map_err(|e| Error::DatabaseError(format!("Failed to prepare query: {e}")))?;
+
+        let rows = stmt
+            .query_map([session_id], |row| {
+                let event_id: String = row.get(0)?;
+                let session_id: i64 = row.get(1)?;
+                let from_status: String = row.get(2)?;
+                let to_status: String = row.get(3)?;
+                let timestamp: u64 = row.get(4)?;
+                let actor: Option<String> = row.get(5)?;
+                Ok((event_id, session_id, from_status, to_status, timestamp, actor))
+            })
+            .map_err(|e| Error::DatabaseError(format!("Failed to execute query: {e}")))?;
+
+        let mut events = Vec::new();
+        for row_result in rows {
+            let (event_id, session_id, from_status, to_status, timestamp, actor) =
+                row_result.map_err(|e| Error::DatabaseError(format!("Failed to read row: {e}")))?;
+            events.push(StateTransitionEvent {
+                event_id,
+                session_id,
+                from: SessionStatus::from_str(&from_status)?,
+                to: SessionStatus::from_str(&to_status)?,
+                timestamp,
+                actor,
+            });
+        }
+
+        drop(stmt);
+        drop(conn);
+        Ok(events)
+    }
+
     /// Rebuild database from a list of discovered sessions
     ///
     /// Drops existing data and recreates the schema, then inserts all provided sessions.
@@ -951,4 +1794,291 @@ mod tests {
         assert_eq!(success_count, 1);
         Ok(())
     }
+
+    // ===== Rate Limiter Tests =====
+
+    #[test]
+    fn test_guard_create_consumes_tokens_then_rejects() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        let limiter = RateLimiter {
+            capacity: 2.0,
+            refill_rate: 0.0, // no refill so the bucket drains deterministically
+            max_concurrent: None,
+        };
+
+        db.guard_create("creates", &limiter)?;
+        db.guard_create("creates", &limiter)?;
+        let result = db.guard_create("creates", &limiter);
+        match result {
+            Err(Error::RateLimited { retry_after }) => assert!(retry_after > 0),
+            other => panic!("expected RateLimited, got {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_guard_create_concurrency_ceiling() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        db.create("a", "/a")?; // Creating
+        let limiter = RateLimiter {
+            capacity: 100.0,
+            refill_rate: 100.0,
+            max_concurrent: Some(1),
+        };
+        let result = db.guard_create("creates", &limiter);
+        assert!(matches!(result, Err(Error::RateLimited { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_guarded_enforces_concurrency_ceiling_under_race() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        let db = Arc::new(db);
+        let limiter = RateLimiter {
+            capacity: 100.0,
+            refill_rate: 100.0,
+            max_concurrent: Some(1),
+        };
+
+        let db1 = Arc::clone(&db);
+        let limiter1 = limiter.clone();
+        let db2 = Arc::clone(&db);
+        let limiter2 = limiter.clone();
+
+        let h1 = std::thread::spawn(move || db1.create_guarded("creates", &limiter1, "a", "/a"));
+        let h2 = std::thread::spawn(move || db2.create_guarded("creates", &limiter2, "b", "/b"));
+
+        let r1 = h1.join();
+        let r2 = h2.join();
+
+        let success_count = [r1, r2]
+            .iter()
+            .filter(|r| r.as_ref().is_ok_and(std::result::Result::is_ok))
+            .count();
+
+        // The ceiling allows only one in-flight session, so exactly one of
+        // the two racing creates must be rejected - never both accepted.
+        assert_eq!(success_count, 1);
+        assert_eq!(db.list(None)?.len(), 1);
+        Ok(())
+    }
+
+    // ===== Idempotency Tests =====
+
+    #[test]
+    fn test_create_idempotent_returns_same_session_on_reuse() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        let first = db.create_idempotent("key-1", "s", "/w", Some("main"))?;
+        let second = db.create_idempotent("key-1", "s", "/w", Some("main"))?;
+
+        assert_eq!(first.id, second.id);
+        // Only one session exists.
+        assert_eq!(db.list(None)?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_idempotent_rejects_conflicting_inputs() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        db.create_idempotent("key-1", "s", "/w", None)?;
+        let result = db.create_idempotent("key-1", "s", "/different", None);
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    // ===== Bulk Write Tests =====
+
+    #[test]
+    fn test_bulk_write_unordered_collects_all_errors() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        let batch = SessionBulkWrite {
+            ordered: false,
+            ops: vec![
+                SessionWriteModel::Create {
+                    name: "a".into(),
+                    workspace_path: "/a".into(),
+                    branch: Some("main".into()),
+                },
+                // Invalid: Creating -> Paused is not allowed.
+                SessionWriteModel::UpdateStatus {
+                    name: "a".into(),
+                    to: SessionStatus::Paused,
+                },
+                SessionWriteModel::Delete {
+                    name: "missing".into(),
+                },
+                SessionWriteModel::UpdateStatus {
+                    name: "a".into(),
+                    to: SessionStatus::Active,
+                },
+            ],
+        };
+
+        let result = db.bulk_write(batch)?;
+        assert_eq!(result.inserted, 1);
+        assert_eq!(result.updated, 1);
+        assert_eq!(result.errors.len(), 2);
+        // Errors are keyed by operation index.
+        assert_eq!(result.errors[0].0, 1);
+        assert_eq!(result.errors[1].0, 2);
+
+        let session = db.get("a")?.ok_or(Error::NotFound("a".into()))?;
+        assert_eq!(session.status, SessionStatus::Active);
+        assert_eq!(session.branch.as_deref(), Some("main"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_write_ordered_stops_at_first_error() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        let batch = SessionBulkWrite {
+            ordered: true,
+            ops: vec![
+                SessionWriteModel::Delete {
+                    name: "missing".into(),
+                },
+                SessionWriteModel::Create {
+                    name: "b".into(),
+                    workspace_path: "/b".into(),
+                    branch: None,
+                },
+            ],
+        };
+
+        let result = db.bulk_write(batch)?;
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.inserted, 0);
+        // The op after the failure was not attempted.
+        assert!(db.get("b")?.is_none());
+        Ok(())
+    }
+
+    // ===== Retry Policy Tests =====
+
+    fn fail_session(db: &SessionDb, name: &str) -> Result<()> {
+        db.apply_transition(name, SessionStatus::Failed, None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_request_retry_increments_attempt() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        db.create("s1", "/path")?;
+        fail_session(&db, "s1")?;
+
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_secs(0),
+            max_delay: std::time::Duration::from_secs(0),
+            jitter: false,
+        };
+        db.request_retry("s1", &policy, None)?;
+
+        let session = db.get("s1")?.ok_or(Error::NotFound("s1".into()))?;
+        assert_eq!(session.status, SessionStatus::Creating);
+        assert_eq!(session.retry_attempt(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_request_retry_rejects_after_max_attempts() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        db.create("s1", "/path")?;
+
+        let policy = RetryPolicy {
+            max_attempts: 1,
+            base_delay: std::time::Duration::from_secs(0),
+            max_delay: std::time::Duration::from_secs(0),
+            jitter: false,
+        };
+
+        fail_session(&db, "s1")?;
+        db.request_retry("s1", &policy, None)?; // attempt 0 -> 1
+        db.apply_transition("s1", SessionStatus::Failed, None)?; // fail again
+        let result = db.request_retry("s1", &policy, None); // budget exhausted
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset_retry_clears_counter() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        db.create("s1", "/path")?;
+        fail_session(&db, "s1")?;
+
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_secs(0),
+            max_delay: std::time::Duration::from_secs(0),
+            jitter: false,
+        };
+        db.request_retry("s1", &policy, None)?;
+
+        db.update(
+            "s1",
+            SessionUpdate {
+                status: Some(SessionStatus::Active),
+                reset_retry: true,
+                ..Default::default()
+            },
+        )?;
+
+        let session = db.get("s1")?.ok_or(Error::NotFound("s1".into()))?;
+        assert_eq!(session.retry_attempt(), 0);
+        assert_eq!(session.next_retry_at(), None);
+        Ok(())
+    }
+
+    // ===== State Transition Log Tests =====
+
+    #[test]
+    fn test_apply_transition_records_event() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        db.create("s1", "/path")?;
+
+        let event = db.apply_transition("s1", SessionStatus::Active, Some("alice"))?;
+        assert_eq!(event.from, SessionStatus::Creating);
+        assert_eq!(event.to, SessionStatus::Active);
+        assert_eq!(event.actor.as_deref(), Some("alice"));
+
+        // The persisted status column is kept in sync.
+        let session = db.get("s1")?.ok_or(Error::NotFound("s1".into()))?;
+        assert_eq!(session.status, SessionStatus::Active);
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_transition_rejects_invalid() -> Result<()> {
+        let (db, _dir) = setup_test_db()?;
+        db.create("s1", "/path")?;
+        // Creating -> Paused is not a valid transition.
+        let result = db.apply_transition("s1", SessionStatus::Paused, None);
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_history_is_ordered_and_reconstructs_status() -> Result<()> {
+        use crate::session::reconstruct_status;
+
+        let (db, _dir) = setup_test_db()?;
+        db.create("s1", "/path")?;
+        db.apply_transition("s1", SessionStatus::Active, None)?;
+        db.apply_transition("s1", SessionStatus::Paused, None)?;
+        db.apply_transition("s1", SessionStatus::Active, Some("bob"))?;
+
+        let history = db.history("s1")?;
+        assert_eq!(history.len(), 3);
+
+        // Events come back in event_id (chronological) order.
+        let ids: Vec<&str> = history.iter().map(|e| e.event_id.as_str()).collect();
+        let mut sorted = ids.clone();
+        sorted.sort_unstable();
+        assert_eq!(ids, sorted);
+
+        // The folded status matches the persisted column.
+        let session = db.get("s1")?.ok_or(Error::NotFound("s1".into()))?;
+        assert_eq!(reconstruct_status(&history), Some(session.status));
+        Ok(())
+    }
 }
@@ -367,6 +367,7 @@ impl Default for Capabilities {
                     "status".to_string(),
                     "focus".to_string(),
                     "sync".to_string(),
+                    "retry".to_string(),
                 ],
                 features: vec![
                     "parallel_workspaces".to_string(),
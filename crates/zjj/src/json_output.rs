@@ -44,6 +44,17 @@ pub struct FocusOutput {
     pub message: String,
 }
 
+/// Retry command JSON output
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+pub struct RetryOutput {
+    pub success: bool,
+    pub session_name: String,
+    pub from: String,
+    pub to: String,
+    pub attempt: u32,
+}
+
 /// Sync command JSON output
 #[derive(Debug, Serialize)]
 #[allow(dead_code)]
@@ -14,8 +14,8 @@ mod json_output;
 mod session;
 
 use commands::{
-    add, config, dashboard, diff, doctor, focus, init, introspect, list, query, remove, status,
-    sync,
+    add, config, dashboard, diff, doctor, focus, init, introspect, list, query, remove, retry,
+    status, sync,
 };
 
 fn cmd_init() -> ClapCommand {
@@ -57,6 +57,12 @@ fn cmd_add() -> ClapCommand {
                 .action(clap::ArgAction::SetTrue)
                 .help("Create workspace without opening Zellij tab"),
         )
+        .arg(
+            Arg::new("idempotency-key")
+                .long("idempotency-key")
+                .value_name("KEY")
+                .help("Retrying with the same key returns the session the first call created instead of erroring on \"already exists\""),
+        )
         .arg(
             Arg::new("json")
                 .long("json")
@@ -175,6 +181,23 @@ fn cmd_sync() -> ClapCommand {
         )
 }
 
+fn cmd_retry() -> ClapCommand {
+    ClapCommand::new("retry")
+        .about("Retry a failed session under exponential backoff")
+        .arg(
+            Arg::new("name")
+                .required(true)
+                .allow_hyphen_values(true) // Allow -name to be passed through for validation
+                .help("Name of the failed session to retry"),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .action(clap::ArgAction::SetTrue)
+                .help("Output as JSON"),
+        )
+}
+
 fn cmd_diff() -> ClapCommand {
     ClapCommand::new("diff")
         .about("Show diff between session and main branch")
@@ -281,6 +304,7 @@ fn build_cli() -> ClapCommand {
         .subcommand(cmd_focus())
         .subcommand(cmd_status())
         .subcommand(cmd_sync())
+        .subcommand(cmd_retry())
         .subcommand(cmd_diff())
         .subcommand(cmd_config())
         .subcommand(cmd_dashboard())
@@ -320,12 +344,14 @@ fn run_cli() -> Result<()> {
             let no_hooks = sub_m.get_flag("no-hooks");
             let template = sub_m.get_one::<String>("template").cloned();
             let no_open = sub_m.get_flag("no-open");
+            let idempotency_key = sub_m.get_one::<String>("idempotency-key").cloned();
 
             let options = add::AddOptions {
                 name: name.clone(),
                 no_hooks,
                 template,
                 no_open,
+                idempotency_key,
             };
 
             add::run_with_options(&options)
@@ -369,6 +395,15 @@ fn run_cli() -> Result<()> {
             };
             sync::run_with_options(name, options)
         }
+        Some(("retry", sub_m)) => {
+            let name = sub_m
+                .get_one::<String>("name")
+                .ok_or_else(|| anyhow::anyhow!("Name is required"))?;
+            let options = retry::RetryOptions {
+                json: sub_m.get_flag("json"),
+            };
+            retry::run_with_options(name, &options)
+        }
         Some(("diff", sub_m)) => {
             let name = sub_m
                 .get_one::<String>("name")
@@ -2,7 +2,7 @@
 
 #[cfg(test)]
 use std::time::SystemTime;
-use std::{fmt, str::FromStr};
+use std::{fmt, str::FromStr, time::Duration};
 
 use serde::{Deserialize, Serialize};
 use zjj_core::{Error, Result};
@@ -107,6 +107,26 @@ impl Session {
             metadata: None,
         })
     }
+
+    /// Number of retry attempts recorded in metadata (0 if none).
+    #[must_use]
+    pub fn retry_attempt(&self) -> u32 {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.get(RETRY_ATTEMPT_KEY))
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|v| u32::try_from(v).ok())
+            .unwrap_or(0)
+    }
+
+    /// Earliest unix timestamp at which a retry is permitted, if one is pending.
+    #[must_use]
+    pub fn next_retry_at(&self) -> Option<u64> {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.get(RETRY_AFTER_KEY))
+            .and_then(serde_json::Value::as_u64)
+    }
 }
 
 /// Fields that can be updated on an existing session
@@ -120,6 +140,8 @@ pub struct SessionUpdate {
     pub last_synced: Option<u64>,
     /// Update the metadata
     pub metadata: Option<serde_json::Value>,
+    /// Clear the retry counter/backoff state (e.g. after a successful retry)
+    pub reset_retry: bool,
 }
 
 /// Validate a session name
@@ -202,6 +224,140 @@ pub fn validate_status_transition(from: SessionStatus, to: SessionStatus) -> Res
     }
 }
 
+/// An immutable record of an accepted session status transition.
+///
+/// These events form an append-only log: once written, a transition is never
+/// updated or deleted. The `event_id` is a lexically sortable string whose
+/// ordering matches wall-clock time, so sorting events by `event_id` replays
+/// them in the order they occurred.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateTransitionEvent {
+    /// Monotonically sortable identifier (time-prefixed)
+    pub event_id: String,
+    /// The session this transition belongs to (database id)
+    pub session_id: i64,
+    /// Status the session moved from
+    pub from: SessionStatus,
+    /// Status the session moved to
+    pub to: SessionStatus,
+    /// Unix timestamp (seconds) when the transition was recorded
+    pub timestamp: u64,
+    /// Optional actor that initiated the transition
+    pub actor: Option<String>,
+}
+
+/// Metadata key holding the number of retry attempts made for a session.
+pub const RETRY_ATTEMPT_KEY: &str = "retry_attempt";
+/// Metadata key holding the earliest unix timestamp at which a retry is allowed.
+pub const RETRY_AFTER_KEY: &str = "retry_after";
+
+/// Bounded exponential-backoff policy for retrying `Failed` sessions.
+///
+/// Mirrors the decision-policy shape used by async DB drivers: given the
+/// current attempt count, [`RetryPolicy::backoff`] yields the delay that must
+/// elapse before the next `Failed -> Creating` retry, and the caller rejects
+/// the retry entirely once `max_attempts` is reached.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of retry attempts before the session stays terminally `Failed`
+    pub max_attempts: u32,
+    /// Base delay used for the first backoff step
+    pub base_delay: Duration,
+    /// Upper bound the computed delay is clamped to
+    pub max_delay: Duration,
+    /// Whether to add random jitter in `[0, delay / 2]`
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Compute the backoff delay for the given (zero-based) attempt.
+    ///
+    /// The delay is `min(max_delay, base_delay * 2^attempt)`, with random
+    /// jitter in `[0, delay / 2]` added when [`RetryPolicy::jitter`] is set.
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let base_ms = u64::try_from(self.base_delay.as_millis()).unwrap_or(u64::MAX);
+        let max_ms = u64::try_from(self.max_delay.as_millis()).unwrap_or(u64::MAX);
+        let scaled = base_ms.checked_shl(attempt).unwrap_or(u64::MAX);
+        let capped = scaled.min(max_ms);
+
+        let mut delay = Duration::from_millis(capped);
+        if self.jitter && capped > 0 {
+            delay += jitter_up_to(capped / 2);
+        }
+        delay
+    }
+}
+
+/// Draw a pseudo-random jitter in `[0, bound_ms]` milliseconds.
+///
+/// The repo has no `rand` dependency, so entropy is taken from the sub-second
+/// component of the system clock, which is sufficient to decorrelate retry
+/// storms across processes.
+fn jitter_up_to(bound_ms: u64) -> Duration {
+    if bound_ms == 0 {
+        return Duration::ZERO;
+    }
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| u64::from(d.subsec_nanos()))
+        .unwrap_or(0);
+    Duration::from_millis(nanos % (bound_ms + 1))
+}
+
+/// Build the retry-state object to store in [`Session::metadata`], preserving
+/// any other keys already present.
+pub(crate) fn with_retry_state(
+    metadata: Option<serde_json::Value>,
+    attempt: u32,
+    retry_after: u64,
+) -> serde_json::Value {
+    let mut obj = match metadata {
+        Some(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    obj.insert(RETRY_ATTEMPT_KEY.to_string(), attempt.into());
+    obj.insert(RETRY_AFTER_KEY.to_string(), retry_after.into());
+    serde_json::Value::Object(obj)
+}
+
+/// Remove the retry-state keys from a metadata object, preserving other keys.
+pub(crate) fn clear_retry_state(metadata: Option<serde_json::Value>) -> Option<serde_json::Value> {
+    match metadata {
+        Some(serde_json::Value::Object(mut map)) => {
+            map.remove(RETRY_ATTEMPT_KEY);
+            map.remove(RETRY_AFTER_KEY);
+            if map.is_empty() {
+                None
+            } else {
+                Some(serde_json::Value::Object(map))
+            }
+        }
+        other => other,
+    }
+}
+
+/// Reconstruct the current status purely from an ordered event stream.
+///
+/// The log is folded from its first event forward; the resulting status is the
+/// `to` of the most recent transition. Returns `None` when the stream is empty.
+/// This lets callers cross-check the persisted `status` column against the log.
+#[must_use]
+pub fn reconstruct_status(events: &[StateTransitionEvent]) -> Option<SessionStatus> {
+    events.iter().fold(None, |_, event| Some(event.to))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -354,4 +510,64 @@ mod tests {
         let result = validate_status_transition(SessionStatus::Completed, SessionStatus::Paused);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_retry_policy_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(10),
+            jitter: false,
+        };
+        assert_eq!(policy.backoff(0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(1), Duration::from_secs(2));
+        assert_eq!(policy.backoff(2), Duration::from_secs(4));
+        // Capped at max_delay.
+        assert_eq!(policy.backoff(20), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_retry_state_roundtrip_in_metadata() {
+        let meta = with_retry_state(None, 2, 12345);
+        let session = Session {
+            metadata: Some(meta),
+            ..Default::default()
+        };
+        assert_eq!(session.retry_attempt(), 2);
+        assert_eq!(session.next_retry_at(), Some(12345));
+
+        let cleared = clear_retry_state(session.metadata);
+        assert_eq!(cleared, None);
+    }
+
+    #[test]
+    fn test_clear_retry_state_preserves_other_keys() {
+        let meta = serde_json::json!({ "retry_attempt": 1, "retry_after": 9, "note": "x" });
+        let cleared = clear_retry_state(Some(meta)).expect("other keys remain");
+        assert_eq!(cleared.get("note").and_then(|v| v.as_str()), Some("x"));
+        assert!(cleared.get("retry_attempt").is_none());
+    }
+
+    #[test]
+    fn test_reconstruct_status_empty_stream() {
+        assert_eq!(reconstruct_status(&[]), None);
+    }
+
+    #[test]
+    fn test_reconstruct_status_folds_to_latest() {
+        let mk = |id: &str, from, to| StateTransitionEvent {
+            event_id: id.to_string(),
+            session_id: 1,
+            from,
+            to,
+            timestamp: 0,
+            actor: None,
+        };
+        let events = vec![
+            mk("0001", SessionStatus::Creating, SessionStatus::Active),
+            mk("0002", SessionStatus::Active, SessionStatus::Paused),
+            mk("0003", SessionStatus::Paused, SessionStatus::Active),
+        ];
+        assert_eq!(reconstruct_status(&events), Some(SessionStatus::Active));
+    }
 }
@@ -148,6 +148,194 @@ pub struct BeadIssue {
     pub updated_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub closed_at: Option<DateTime<Utc>>,
+    /// Optional effort estimate used to weight the critical-path computation;
+    /// absent issues default to a weight of 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<u32>,
+    /// Tracked time entries loaded from the `time_entries` companion table in
+    /// `beads.db`; absent when no time has been logged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_entries: Option<Vec<TimeEntry>>,
+    /// User-defined attributes (Taskwarrior-style): org-specific fields like
+    /// `story_points` or `customer` that the fixed schema doesn't model. Kept
+    /// as its own map rather than `#[serde(flatten)]`-ed onto the struct so
+    /// an arbitrary UDA name can never collide with one of `BeadIssue`'s own
+    /// fields.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub udas: Option<std::collections::BTreeMap<String, UdaValue>>,
+}
+
+/// A single user-defined attribute value (see [`BeadIssue::udas`]), following
+/// Taskwarrior's UDA type model. Internally tagged so JSON round-trips
+/// unambiguously — a `#[serde(untagged)]` encoding can't tell a `Duration`
+/// from a `Number` once both are just a JSON number.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum UdaValue {
+    String(String),
+    Number(f64),
+    Date(DateTime<Utc>),
+    /// Seconds, matching `TimeEntry`'s offset/duration convention
+    Duration(i64),
+}
+
+impl std::fmt::Display for UdaValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::String(s) => write!(f, "{s}"),
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Date(d) => write!(f, "{d}"),
+            Self::Duration(secs) => write!(f, "{secs}s"),
+        }
+    }
+}
+
+/// A single tracked interval of work on an issue, expressed as monotonic
+/// offsets (seconds) from an arbitrary origin plus the duration spent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeEntry {
+    /// Offset (seconds) at which tracking started
+    pub start: i64,
+    /// Offset (seconds) at which tracking stopped
+    pub stop: i64,
+    /// Duration tracked, in seconds
+    pub duration: i64,
+}
+
+impl BeadIssue {
+    /// Total tracked time across all entries, in seconds.
+    #[must_use]
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn tracked_seconds(&self) -> i64 {
+        self.time_entries
+            .iter()
+            .flatten()
+            .map(|e| e.duration)
+            .sum()
+    }
+
+    /// Cycle time (`closed_at - created_at`) for a closed issue, else `None`.
+    #[must_use]
+    pub fn cycle_time(&self) -> Option<chrono::Duration> {
+        self.closed_at.map(|closed| closed - self.created_at)
+    }
+}
+
+/// An issue's own tracked time, from its [`TimeEntry`] rows.
+#[must_use]
+pub fn time_tracked(issue: &BeadIssue) -> chrono::Duration {
+    chrono::Duration::seconds(issue.tracked_seconds())
+}
+
+/// Rolled-up tracked time for `id` and its whole subtree: every issue reached
+/// by following `depends_on` edges forward (things that depend on `id`, via
+/// [`get_dependency_graph`]) or `parent` edges downward (children of `id`),
+/// transitively. Useful for seeing total effort under an epic.
+///
+/// A visited set guards against double-counting an issue reachable by more
+/// than one path, e.g. a child listed under two parents or a dependency cycle.
+#[must_use]
+pub fn total_time_tracked(issues: &[BeadIssue], id: &str) -> chrono::Duration {
+    let dependents = get_dependency_graph(issues);
+    let children: HashMap<String, Vec<String>> = issues
+        .iter()
+        .filter_map(|issue| issue.parent.as_ref().map(|parent| (parent.clone(), issue.id.clone())))
+        .into_group_map()
+        .into_iter()
+        .collect();
+
+    let mut visited = std::collections::HashSet::new();
+    let mut queue = std::collections::VecDeque::new();
+    queue.push_back(id.to_string());
+    let mut total = chrono::Duration::zero();
+
+    while let Some(current) = queue.pop_front() {
+        if !visited.insert(current.clone()) {
+            continue;
+        }
+        if let Some(issue) = issues.iter().find(|i| i.id == current) {
+            total = total + time_tracked(issue);
+        }
+        for next in dependents.get(&current).into_iter().flatten() {
+            queue.push_back(next.clone());
+        }
+        for next in children.get(&current).into_iter().flatten() {
+            queue.push_back(next.clone());
+        }
+    }
+
+    total
+}
+
+/// Whether a [`TrackEvent`] starts or stops the clock on an issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackKind {
+    Start,
+    Stop,
+}
+
+/// A single start/stop time-tracking event, as an alternative to the
+/// pre-aggregated [`TimeEntry`] rows loaded from `beads.db`: this layer lets
+/// callers record raw events (e.g. from a CLI `bd track start`/`bd track
+/// stop`) and derive totals on demand via [`time_tracked_for`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackEvent {
+    pub issue_id: String,
+    pub kind: TrackKind,
+    pub at: DateTime<Utc>,
+}
+
+/// Total time tracked against `issue_id`, replaying `events` in chronological
+/// order.
+///
+/// A `Start` for `issue_id` opens an interval; the next `Stop` for that issue,
+/// or a `Start` for any *other* issue (which implicitly stops the current
+/// one), closes it and adds its length to the total, resetting the open
+/// start so it is never double-counted. An interval still open after the
+/// last event counts up to [`Utc::now`], so active tracking shows immediately
+/// rather than waiting for a `Stop` that hasn't happened yet.
+#[must_use]
+pub fn time_tracked_for(events: &[TrackEvent], issue_id: &str) -> chrono::Duration {
+    let mut sorted: Vec<&TrackEvent> = events.iter().collect();
+    sorted.sort_by_key(|e| e.at);
+
+    let mut total = chrono::Duration::zero();
+    let mut start: Option<DateTime<Utc>> = None;
+    for event in sorted {
+        let is_this_issue = event.issue_id == issue_id;
+        match (event.kind, is_this_issue) {
+            (TrackKind::Start, true) => start = Some(event.at),
+            (TrackKind::Stop, true) | (TrackKind::Start, false) => {
+                if let Some(began) = start.take() {
+                    total = total + (event.at - began);
+                }
+            }
+            (TrackKind::Stop, false) => {}
+        }
+    }
+    if let Some(began) = start {
+        total = total + (Utc::now() - began);
+    }
+    total
+}
+
+/// Sums [`time_tracked_for`] per issue, grouped by status the way
+/// [`group_by_status`] groups counts.
+#[must_use]
+pub fn total_tracked_per_status(
+    issues: &[BeadIssue],
+    events: &[TrackEvent],
+) -> HashMap<IssueStatus, chrono::Duration> {
+    group_by_status(issues)
+        .into_iter()
+        .map(|(status, grouped)| {
+            let total = grouped.iter().fold(chrono::Duration::zero(), |acc, issue| {
+                acc + time_tracked_for(events, &issue.id)
+            });
+            (status, total)
+        })
+        .collect()
 }
 
 impl BeadIssue {
@@ -161,6 +349,34 @@ impl BeadIssue {
     pub fn is_open(&self) -> bool {
         self.status == IssueStatus::Open || self.status == IssueStatus::InProgress
     }
+
+    /// Parses `key:value` labels into a property map (last value wins per key).
+    #[must_use]
+    pub fn properties(&self) -> std::collections::HashMap<String, String> {
+        self.labels
+            .iter()
+            .flatten()
+            .filter_map(|label| parse_property(label))
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    /// Returns the value of the `key:value` label `key`, if present.
+    #[must_use]
+    pub fn property(&self, key: &str) -> Option<String> {
+        self.labels
+            .iter()
+            .flatten()
+            .filter_map(|label| parse_property(label))
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.to_string())
+    }
+}
+
+/// Splits a `key:value` label into its parts, trimming whitespace. Labels
+/// without a colon (plain tags) are not properties and return `None`.
+fn parse_property(label: &str) -> Option<(&str, &str)> {
+    label.split_once(':').map(|(k, v)| (k.trim(), v.trim()))
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -202,7 +418,7 @@ impl BeadsSummary {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BeadFilter {
     pub status: Vec<IssueStatus>,
     pub issue_type: Vec<IssueType>,
@@ -214,8 +430,51 @@ pub struct BeadFilter {
     pub has_parent: bool,
     pub blocked_only: bool,
     pub search_text: Option<String>,
+    /// Exact `key:value` property predicates; values sharing a key are ORed,
+    /// distinct keys are ANDed.
+    pub properties: Vec<(String, String)>,
+    /// Numeric `key ∈ [min, max]` property range predicates.
+    pub property_ranges: Vec<(String, f64, f64)>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
+    /// Id to scope the view around; paired with `depth` and applied via
+    /// [`filter_by_depth`]. `None` means no hierarchical scoping.
+    pub focus_id: Option<String>,
+    /// How far to descend `depends_on`/`parent` from `focus_id`: negative for
+    /// leaf issues only, zero for just the focused issue, positive `N` for `N`
+    /// levels. Ignored unless `focus_id` is set.
+    pub depth: Option<i8>,
+    /// When a `labels` match yields fewer than this many issues, expand the
+    /// result to the matched issues' whole ancestor/descendant subtree (see
+    /// [`filter_issues`]), so an epic-level tag doesn't collapse to one node
+    /// just because its children don't repeat the label. `None` disables
+    /// expansion.
+    pub tag_expansion_threshold: Option<usize>,
+    /// `(uda name, predicate)` pairs evaluated against the issue's
+    /// [`BeadIssue::udas`]; every pair must match (ANDed), mirroring
+    /// `properties`'s AND-across-keys rule.
+    pub udas: Vec<(String, UdaPredicate)>,
+}
+
+/// A predicate over a single [`UdaValue`], used by [`BeadFilter::with_uda`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UdaPredicate {
+    Equals(UdaValue),
+    NumberRange(f64, f64),
+}
+
+impl UdaPredicate {
+    /// Whether `value` satisfies this predicate. A `NumberRange` never
+    /// matches a non-`Number` value.
+    #[must_use]
+    pub fn matches(&self, value: &UdaValue) -> bool {
+        match self {
+            Self::Equals(wanted) => wanted == value,
+            Self::NumberRange(min, max) => {
+                matches!(value, UdaValue::Number(n) if *n >= *min && *n <= *max)
+            }
+        }
+    }
 }
 
 impl BeadFilter {
@@ -279,6 +538,44 @@ impl BeadFilter {
         self
     }
 
+    #[must_use]
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.push((key.into(), value.into()));
+        self
+    }
+
+    #[must_use]
+    pub fn with_property_range(mut self, key: impl Into<String>, min: f64, max: f64) -> Self {
+        self.property_ranges.push((key.into(), min, max));
+        self
+    }
+
+    /// Scope this filter to the subtree around `focus_id` (see
+    /// [`filter_by_depth`] for the meaning of `depth`).
+    #[must_use]
+    pub fn with_depth(mut self, focus_id: impl Into<String>, depth: i8) -> Self {
+        self.focus_id = Some(focus_id.into());
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Enables tag-aware search-depth expansion: if a `labels` match yields
+    /// fewer than `threshold` issues, pull in the matched issues'
+    /// ancestors/descendants too (see [`filter_issues`]).
+    #[must_use]
+    pub const fn with_tag_expansion(mut self, threshold: usize) -> Self {
+        self.tag_expansion_threshold = Some(threshold);
+        self
+    }
+
+    /// Requires the issue's UDA `name` (see [`BeadIssue::udas`]) to satisfy
+    /// `predicate`. Multiple calls with the same `name` are ANDed, not ORed.
+    #[must_use]
+    pub fn with_uda(mut self, name: impl Into<String>, predicate: UdaPredicate) -> Self {
+        self.udas.push((name.into(), predicate));
+        self
+    }
+
     #[must_use]
     pub const fn limit(mut self, n: usize) -> Self {
         self.limit = Some(n);
@@ -290,9 +587,89 @@ impl BeadFilter {
         self.offset = Some(n);
         self
     }
+
+    /// Builds the boolean expression equivalent of this filter's flat
+    /// status/type/priority/label/assignee/search constraints, for callers
+    /// that want to combine it with further [`Expr`] clauses via
+    /// [`BeadQuery::with_expr`]. `parent`, `has_parent`, `blocked_only`, the
+    /// `key:value` property predicates, and the UDA predicates have no
+    /// [`Expr`] leaf yet, so they are left out of the returned expression;
+    /// keep using [`filter_issues`] directly when those are required.
+    #[must_use]
+    pub fn to_expr(&self) -> Expr {
+        let mut clauses = Vec::new();
+
+        if !self.status.is_empty() {
+            clauses.push(Expr::Or(self.status.iter().cloned().map(Expr::Status).collect()));
+        }
+        if !self.issue_type.is_empty() {
+            clauses.push(Expr::Or(self.issue_type.iter().cloned().map(Expr::Type).collect()));
+        }
+        if self.priority_min.is_some() || self.priority_max.is_some() {
+            let min = self.priority_min.unwrap_or(Priority::P0);
+            let max = self.priority_max.unwrap_or(Priority::P4);
+            clauses.push(Expr::PriorityRange(min, max));
+        }
+        clauses.extend(self.labels.iter().cloned().map(Expr::Label));
+        if let Some(assignee) = &self.assignee {
+            clauses.push(Expr::Assignee(assignee.clone()));
+        }
+        if let Some(text) = &self.search_text {
+            clauses.push(Expr::TextMatch(text.clone()));
+        }
+
+        Expr::And(clauses)
+    }
+}
+
+/// A composable boolean predicate over a [`BeadIssue`], letting
+/// [`BeadQuery::with_expr`] express constraints `BeadFilter`'s flat
+/// conjunction can't, e.g. `(Bug AND P0) OR label:urgent AND NOT closed`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Expr {
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+    Status(IssueStatus),
+    Type(IssueType),
+    Label(String),
+    PriorityRange(Priority, Priority),
+    Assignee(String),
+    TextMatch(String),
+}
+
+impl Expr {
+    /// Evaluates this expression against `issue`. An empty `And` is
+    /// vacuously true (no constraints to fail); an empty `Or` is vacuously
+    /// false (nothing to satisfy it), matching standard boolean-algebra
+    /// identities.
+    #[must_use]
+    pub fn eval(&self, issue: &BeadIssue) -> bool {
+        match self {
+            Self::And(exprs) => exprs.iter().all(|e| e.eval(issue)),
+            Self::Or(exprs) => exprs.iter().any(|e| e.eval(issue)),
+            Self::Not(expr) => !expr.eval(issue),
+            Self::Status(status) => issue.status == *status,
+            Self::Type(issue_type) => issue.issue_type.as_ref() == Some(issue_type),
+            Self::Label(label) => issue
+                .labels
+                .as_ref()
+                .is_some_and(|labels| labels.contains(label)),
+            Self::PriorityRange(min, max) => issue.priority.is_some_and(|p| p >= *min && p <= *max),
+            Self::Assignee(assignee) => issue.assignee.as_deref() == Some(assignee.as_str()),
+            Self::TextMatch(text) => {
+                let text_lower = text.to_lowercase();
+                issue.title.to_lowercase().contains(&text_lower)
+                    || issue
+                        .description
+                        .as_ref()
+                        .is_some_and(|d| d.to_lowercase().contains(&text_lower))
+            }
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, EnumString, Display, PartialEq, Eq)]
+#[derive(Debug, Clone, EnumString, Display, PartialEq, Eq, Serialize, Deserialize)]
 #[strum(serialize_all = "snake_case")]
 pub enum BeadSort {
     #[strum(to_string = "priority")]
@@ -315,9 +692,17 @@ pub enum BeadSort {
 
     #[strum(to_string = "id")]
     Id,
+
+    #[strum(to_string = "relevance")]
+    Relevance,
+
+    /// Order by an arbitrary `key:value` label property. Also the parse
+    /// fallback, so an unrecognized sort string is treated as a property name.
+    #[strum(default)]
+    Property(String),
 }
 
-#[derive(Debug, Clone, Copy, EnumString, Display, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, EnumString, Display, PartialEq, Eq, Serialize, Deserialize)]
 #[strum(serialize_all = "snake_case")]
 pub enum SortDirection {
     #[strum(to_string = "asc")]
@@ -327,12 +712,28 @@ pub enum SortDirection {
     Desc,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BeadQuery {
     pub filter: BeadFilter,
     pub sort: BeadSort,
     pub direction: SortDirection,
     pub include_closed: bool,
+    /// Free-text query ranked with [`search_issues`]'s exact/fuzzy-token
+    /// cascade — the default full-text path, suited to interactive
+    /// type-ahead where "does this token roughly match" matters more than a
+    /// calibrated score. When set it takes precedence over `sort`.
+    ///
+    /// For BM25 relevance scoring instead (larger corpora, or a caller that
+    /// wants a comparable numeric score), leave this unset, set
+    /// `sort: BeadSort::Relevance`, and put the query text in
+    /// `filter.search_text`. Both paths classify a token's match the same
+    /// way (see [`SearchIndex::fuzzy_terms`]); they differ in how matches
+    /// are ranked once found, not in what counts as a match.
+    pub search: Option<String>,
+    /// A composable boolean query (see [`Expr`]) evaluated instead of
+    /// `filter`'s flat conjunction when set, via [`BeadQuery::with_expr`].
+    #[serde(default)]
+    pub expr: Option<Expr>,
 }
 
 impl Default for BeadQuery {
@@ -342,6 +743,8 @@ impl Default for BeadQuery {
             sort: BeadSort::Priority,
             direction: SortDirection::Desc,
             include_closed: false,
+            search: None,
+            expr: None,
         }
     }
 }
@@ -359,7 +762,7 @@ impl BeadQuery {
     }
 
     #[must_use]
-    pub const fn sort_by(mut self, sort: BeadSort) -> Self {
+    pub fn sort_by(mut self, sort: BeadSort) -> Self {
         self.sort = sort;
         self
     }
@@ -375,6 +778,22 @@ impl BeadQuery {
         self.include_closed = include;
         self
     }
+
+    /// Builder: rank results by fuzzy full-text relevance to `query` (see
+    /// [`search_issues`]) instead of a field comparator.
+    #[must_use]
+    pub fn search(mut self, query: &str) -> Self {
+        self.search = Some(query.to_string());
+        self
+    }
+
+    /// Builder: evaluate a composable boolean [`Expr`] instead of `filter`'s
+    /// flat conjunction, e.g. `(Bug AND P0) OR label:urgent AND NOT closed`.
+    #[must_use]
+    pub fn with_expr(mut self, expr: Expr) -> Self {
+        self.expr = Some(expr);
+        self
+    }
 }
 
 pub fn query_beads(workspace_path: &Path) -> std::result::Result<Vec<BeadIssue>, BeadsError> {
@@ -449,20 +868,163 @@ pub fn query_beads(workspace_path: &Path) -> std::result::Result<Vec<BeadIssue>,
                 created_at,
                 updated_at,
                 closed_at,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             })
         })
         .map_err(|e| BeadsError::QueryFailed(format!("Failed to execute query: {e}")))?;
 
-    rows.collect::<std::result::Result<Vec<BeadIssue>, _>>()
-        .map_err(|e| BeadsError::QueryFailed(format!("Failed to collect results: {e}")))
+    let mut issues = rows
+        .collect::<std::result::Result<Vec<BeadIssue>, _>>()
+        .map_err(|e| BeadsError::QueryFailed(format!("Failed to collect results: {e}")))?;
+    drop(stmt);
+
+    let time_entries = load_time_entries(&conn)?;
+    for issue in &mut issues {
+        if let Some(entries) = time_entries.get(&issue.id) {
+            issue.time_entries = Some(entries.clone());
+        }
+    }
+
+    Ok(issues)
+}
+
+/// Directory holding saved named filters, `<workspace>/.beads/filters/`.
+fn filters_dir(workspace_path: &Path) -> std::path::PathBuf {
+    workspace_path.join(".beads/filters")
+}
+
+/// Persists `query` as a reusable named filter (e.g. "my open blockers") at
+/// `<workspace>/.beads/filters/<name>.json`, so it can be re-loaded with
+/// [`load_filter`] across sessions.
+pub fn save_filter(
+    workspace_path: &Path,
+    name: &str,
+    query: &BeadQuery,
+) -> std::result::Result<(), BeadsError> {
+    let dir = filters_dir(workspace_path);
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| BeadsError::PathError(format!("Failed to create filters dir: {e}")))?;
+
+    let json = serde_json::to_string_pretty(query)
+        .map_err(|e| BeadsError::QueryFailed(format!("Failed to serialize filter: {e}")))?;
+
+    std::fs::write(dir.join(format!("{name}.json")), json)
+        .map_err(|e| BeadsError::PathError(format!("Failed to write filter {name}: {e}")))
+}
+
+/// Loads a named filter previously written by [`save_filter`].
+pub fn load_filter(workspace_path: &Path, name: &str) -> std::result::Result<BeadQuery, BeadsError> {
+    let path = filters_dir(workspace_path).join(format!("{name}.json"));
+
+    let json = std::fs::read_to_string(&path)
+        .map_err(|_| BeadsError::NotFound(format!("Saved filter not found: {name}")))?;
+
+    serde_json::from_str(&json)
+        .map_err(|e| BeadsError::QueryFailed(format!("Failed to parse filter {name}: {e}")))
+}
+
+/// Loads all tracked time entries from the `time_entries` companion table,
+/// grouped by issue id. A missing table is treated as "no entries" so older
+/// databases keep working.
+fn load_time_entries(
+    conn: &Connection,
+) -> std::result::Result<std::collections::HashMap<String, Vec<TimeEntry>>, BeadsError> {
+    let mut stmt = match conn
+        .prepare("SELECT issue_id, start, stop, duration FROM time_entries ORDER BY start")
+    {
+        Ok(stmt) => stmt,
+        // The table is optional; absence just means no tracked time.
+        Err(_) => return Ok(std::collections::HashMap::new()),
+    };
+
+    let rows = stmt
+        .query_map([], |row| {
+            let issue_id: String = row.get(0)?;
+            Ok((
+                issue_id,
+                TimeEntry {
+                    start: row.get(1)?,
+                    stop: row.get(2)?,
+                    duration: row.get(3)?,
+                },
+            ))
+        })
+        .map_err(|e| BeadsError::QueryFailed(format!("Failed to query time entries: {e}")))?;
+
+    let mut map: std::collections::HashMap<String, Vec<TimeEntry>> =
+        std::collections::HashMap::new();
+    for row in rows {
+        let (issue_id, entry) =
+            row.map_err(|e| BeadsError::QueryFailed(format!("Failed to read time entry: {e}")))?;
+        map.entry(issue_id).or_default().push(entry);
+    }
+    Ok(map)
 }
 
 #[must_use]
 pub fn filter_issues(issues: &[BeadIssue], filter: &BeadFilter) -> Vec<BeadIssue> {
-    issues
+    // Hierarchical scoping runs first since it needs the whole issue set
+    // (unlike every other predicate, which only looks at one issue at a time).
+    let scoped = match (&filter.focus_id, filter.depth) {
+        (Some(focus_id), Some(depth)) => filter_by_depth(issues, focus_id, depth),
+        _ => issues.to_vec(),
+    };
+
+    let matched: Vec<BeadIssue> = scoped
         .iter()
         .filter(|issue| matches_filter(issue, filter))
         .cloned()
+        .collect();
+
+    match filter.tag_expansion_threshold {
+        Some(threshold) if !filter.labels.is_empty() && matched.len() < threshold => {
+            expand_tag_matches(issues, &matched, filter)
+        }
+        _ => matched,
+    }
+}
+
+/// Pulls each of `matched`'s ancestors and descendants into the result
+/// (deduplicated by id), so a label match on an epic also surfaces its
+/// subtree even where intermediate issues don't repeat the label. `Closed`
+/// issues are dropped unless `filter.status` explicitly asks for them.
+fn expand_tag_matches(issues: &[BeadIssue], matched: &[BeadIssue], filter: &BeadFilter) -> Vec<BeadIssue> {
+    let dependents = get_dependency_graph(issues);
+    let children = get_child_graph(issues);
+
+    let mut expanded: std::collections::HashSet<String> =
+        matched.iter().map(|issue| issue.id.clone()).collect();
+
+    for issue in matched {
+        expanded.extend(subtree_ids(&issue.id, &dependents, &children, None));
+
+        // Ancestors: walk `parent` and `depends_on` upward from the match.
+        let mut frontier = vec![issue.id.clone()];
+        while let Some(current) = frontier.pop() {
+            let Some(found) = issues.iter().find(|i| i.id == current) else {
+                continue;
+            };
+            if let Some(parent) = &found.parent {
+                if expanded.insert(parent.clone()) {
+                    frontier.push(parent.clone());
+                }
+            }
+            for dep in found.depends_on.iter().flatten() {
+                if expanded.insert(dep.clone()) {
+                    frontier.push(dep.clone());
+                }
+            }
+        }
+    }
+
+    let include_closed = filter.status.contains(&IssueStatus::Closed);
+
+    issues
+        .iter()
+        .filter(|issue| expanded.contains(&issue.id) && (include_closed || issue.status != IssueStatus::Closed))
+        .cloned()
         .collect()
 }
 
@@ -502,6 +1064,64 @@ fn matches_filter(issue: &BeadIssue, filter: &BeadFilter) -> bool {
                     .as_ref()
                     .is_some_and(|d| d.to_lowercase().contains(&text_lower))
         })
+        && matches_properties(issue, filter)
+        && matches_udas(issue, filter)
+}
+
+/// Evaluates a filter's `(name, predicate)` UDA constraints against an
+/// issue's [`BeadIssue::udas`]. An issue lacking a named UDA fails that
+/// predicate, so absent attributes never vacuously match.
+fn matches_udas(issue: &BeadIssue, filter: &BeadFilter) -> bool {
+    filter.udas.iter().all(|(name, predicate)| {
+        issue
+            .udas
+            .as_ref()
+            .and_then(|udas| udas.get(name))
+            .is_some_and(|value| predicate.matches(value))
+    })
+}
+
+/// Evaluates a filter's `key:value` property and range predicates against an
+/// issue's parsed label properties. Values sharing a key are ORed together;
+/// distinct keys (and every range) must all match.
+fn matches_properties(issue: &BeadIssue, filter: &BeadFilter) -> bool {
+    let props = issue.properties();
+
+    let exact_ok = filter
+        .properties
+        .iter()
+        .into_group_map_by(|(key, _)| key.clone())
+        .into_iter()
+        .all(|(key, wanted)| {
+            props
+                .get(&key)
+                .is_some_and(|actual| wanted.iter().any(|(_, value)| value == actual))
+        });
+
+    let range_ok = filter.property_ranges.iter().all(|(key, min, max)| {
+        props
+            .get(key)
+            .and_then(|value| value.parse::<f64>().ok())
+            .is_some_and(|n| n >= *min && n <= *max)
+    });
+
+    exact_ok && range_ok
+}
+
+/// Enumerates observed `key:value` label properties across issues, mapping each
+/// key to its distinct values (sorted), for dynamic filter/column pickers.
+#[must_use]
+pub fn list_properties(issues: &[BeadIssue]) -> HashMap<String, Vec<String>> {
+    let mut map: std::collections::BTreeMap<String, std::collections::BTreeSet<String>> =
+        std::collections::BTreeMap::new();
+    for issue in issues {
+        for (key, value) in issue.properties() {
+            map.entry(key).or_default().insert(value);
+        }
+    }
+    map.into_iter()
+        .map(|(key, values)| (key, values.into_iter().collect()))
+        .collect()
 }
 
 use std::cmp::Reverse;
@@ -509,7 +1129,7 @@ use std::cmp::Reverse;
 #[must_use]
 pub fn sort_issues(
     issues: &[BeadIssue],
-    sort: BeadSort,
+    sort: &BeadSort,
     direction: SortDirection,
 ) -> Vec<BeadIssue> {
     match sort {
@@ -602,9 +1222,49 @@ pub fn sort_issues(
                 .cloned()
                 .collect(),
         },
+        // Relevance ordering depends on the query's `search_text`, which
+        // `sort_issues` does not receive; `apply_query` ranks those results with
+        // `search_ranked` before this function runs, so here it is a passthrough.
+        BeadSort::Relevance => issues.to_vec(),
+        BeadSort::Property(key) => sort_by_property(issues, key, direction),
     }
 }
 
+/// Orders issues by an arbitrary `key:value` label property.
+///
+/// When every present value parses as a number the ordering is numeric,
+/// otherwise it is lexicographic. Issues that lack the key are sorted last
+/// regardless of `direction`.
+#[must_use]
+fn sort_by_property(issues: &[BeadIssue], key: &str, direction: SortDirection) -> Vec<BeadIssue> {
+    let value_of = |issue: &BeadIssue| issue.property(key);
+    let numeric = issues
+        .iter()
+        .filter_map(|i| value_of(i))
+        .all(|v| v.parse::<f64>().is_ok());
+
+    let mut present: Vec<BeadIssue> = issues.iter().filter(|i| value_of(i).is_some()).cloned().collect();
+    let absent: Vec<BeadIssue> = issues.iter().filter(|i| value_of(i).is_none()).cloned().collect();
+
+    present.sort_by(|a, b| {
+        let (va, vb) = (value_of(a).unwrap_or_default(), value_of(b).unwrap_or_default());
+        let ord = if numeric {
+            let na = va.parse::<f64>().unwrap_or(0.0);
+            let nb = vb.parse::<f64>().unwrap_or(0.0);
+            na.partial_cmp(&nb).unwrap_or(std::cmp::Ordering::Equal)
+        } else {
+            va.cmp(&vb)
+        };
+        match direction {
+            SortDirection::Asc => ord,
+            SortDirection::Desc => ord.reverse(),
+        }
+    });
+
+    present.extend(absent);
+    present
+}
+
 #[must_use]
 pub fn paginate(
     issues: &[BeadIssue],
@@ -618,10 +1278,50 @@ pub fn paginate(
 
 #[must_use]
 pub fn apply_query(issues: &[BeadIssue], query: &BeadQuery) -> Vec<BeadIssue> {
-    issues
-        .pipe(|i| filter_issues(i, &query.filter))
-        .pipe(|i| sort_issues(&i, query.sort, query.direction))
-        .pipe(|i| paginate(&i, query.filter.offset, query.filter.limit))
+    // A custom boolean expression replaces the filter's flat conjunction
+    // entirely, letting callers express OR/NOT the flat fields can't; the
+    // rest of the pipeline (search ranking, sort, pagination) is unchanged.
+    let filtered = if let Some(expr) = &query.expr {
+        issues.iter().filter(|issue| expr.eval(issue)).cloned().collect()
+    } else {
+        filter_issues(issues, &query.filter)
+    };
+
+    // `query.search` is the exact/fuzzy-token path and outranks any field
+    // sort (see the precedence note on `BeadQuery::search`); rank the
+    // filtered set with `search_issues` and keep only the matches,
+    // newest-first order being the Asc variant.
+    if let Some(text) = query.search.as_deref() {
+        let ordered: Vec<BeadIssue> = search_issues(&filtered, text)
+            .into_iter()
+            .map(|scored| scored.issue)
+            .collect();
+        let ordered = if query.direction == SortDirection::Asc {
+            ordered.into_iter().rev().collect()
+        } else {
+            ordered
+        };
+        return paginate(&ordered, query.filter.offset, query.filter.limit);
+    }
+
+    // Relevance sort is driven by the filter's free-text query rather than a
+    // field comparator, so it runs through the BM25 ranker instead of
+    // `sort_issues`. With no search text there is nothing to rank against, so we
+    // preserve the filtered order.
+    let ranked = match (&query.sort, query.filter.search_text.as_deref()) {
+        (BeadSort::Relevance, Some(text)) => {
+            let scored = search_ranked(&filtered, text, &SearchOptions::default());
+            let ordered: Vec<BeadIssue> = scored.into_iter().map(|(issue, _)| issue).collect();
+            if query.direction == SortDirection::Asc {
+                ordered.into_iter().rev().collect()
+            } else {
+                ordered
+            }
+        }
+        _ => sort_issues(&filtered, &query.sort, query.direction),
+    };
+
+    paginate(&ranked, query.filter.offset, query.filter.limit)
 }
 
 #[must_use]
@@ -666,6 +1366,98 @@ pub fn get_dependency_graph(issues: &[BeadIssue]) -> HashMap<String, Vec<String>
         .collect()
 }
 
+/// Maps each parent id to its direct children (issues whose `parent` field
+/// names it), the hierarchical counterpart to [`get_dependency_graph`]'s
+/// `depends_on`-reverse map.
+fn get_child_graph(issues: &[BeadIssue]) -> HashMap<String, Vec<String>> {
+    issues
+        .iter()
+        .filter_map(|issue| issue.parent.as_ref().map(|parent| (parent.clone(), issue.id.clone())))
+        .into_group_map()
+        .into_iter()
+        .collect()
+}
+
+/// Breadth-first walk outward from `focus_id` following both
+/// `depends_on`-reverse edges ([`get_dependency_graph`]) and `parent`-forward
+/// edges ([`get_child_graph`]), returning every id reached. `max_levels`
+/// bounds how many hops are taken; `None` walks the whole reachable subtree.
+/// An id with no outgoing edges in either map is simply a dead end, not an
+/// error — dangling `depends_on`/`parent` references resolve to no-ops rather
+/// than panicking.
+fn subtree_ids(
+    focus_id: &str,
+    dependents: &HashMap<String, Vec<String>>,
+    children: &HashMap<String, Vec<String>>,
+    max_levels: Option<u32>,
+) -> std::collections::HashSet<String> {
+    let mut visited = std::collections::HashSet::new();
+    visited.insert(focus_id.to_string());
+    let mut frontier = vec![focus_id.to_string()];
+    let mut level = 0u32;
+
+    loop {
+        if max_levels.is_some_and(|max| level >= max) {
+            break;
+        }
+        let mut next = Vec::new();
+        for id in &frontier {
+            for child in dependents.get(id).into_iter().flatten() {
+                if visited.insert(child.clone()) {
+                    next.push(child.clone());
+                }
+            }
+            for child in children.get(id).into_iter().flatten() {
+                if visited.insert(child.clone()) {
+                    next.push(child.clone());
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+        level = level.saturating_add(1);
+    }
+
+    visited
+}
+
+/// Scopes `issues` to the subtree around `focus_id`, mostr-style: `depth < 0`
+/// returns only leaf issues anywhere under `focus_id` (issues with no
+/// dependents and no children); `depth == 0` returns just `focus_id` itself;
+/// `depth > 0` descends that many `depends_on`/`parent` levels. An unknown
+/// `focus_id` yields an empty result rather than panicking.
+#[must_use]
+pub fn filter_by_depth(issues: &[BeadIssue], focus_id: &str, depth: i8) -> Vec<BeadIssue> {
+    if !issues.iter().any(|issue| issue.id == focus_id) {
+        return Vec::new();
+    }
+    if depth == 0 {
+        return issues.iter().filter(|issue| issue.id == focus_id).cloned().collect();
+    }
+
+    let dependents = get_dependency_graph(issues);
+    let children = get_child_graph(issues);
+
+    if depth < 0 {
+        let reached = subtree_ids(focus_id, &dependents, &children, None);
+        return issues
+            .iter()
+            .filter(|issue| {
+                reached.contains(&issue.id)
+                    && !dependents.contains_key(&issue.id)
+                    && !children.contains_key(&issue.id)
+            })
+            .cloned()
+            .collect();
+    }
+
+    let levels = u32::from(depth.unsigned_abs());
+    let reached = subtree_ids(focus_id, &dependents, &children, Some(levels));
+    issues.iter().filter(|issue| reached.contains(&issue.id)).cloned().collect()
+}
+
 #[must_use]
 pub fn group_by_status(issues: &[BeadIssue]) -> HashMap<IssueStatus, Vec<BeadIssue>> {
     issues
@@ -702,35 +1494,285 @@ pub fn find_ready(issues: &[BeadIssue]) -> Vec<BeadIssue> {
         .collect()
 }
 
+/// Coefficients for [`urgency`]'s weighted linear sum. Defaults are adapted
+/// from Taskwarrior's urgency formula; teams can tune any term independently.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UrgencyWeights {
+    /// Multiplier applied to the priority term (see [`urgency`]).
+    pub priority: f64,
+    /// Multiplier applied to the age term.
+    pub age: f64,
+    /// Age in days at which the age term saturates at its cap.
+    pub age_cap_days: f64,
+    /// Flat penalty subtracted when an issue has open `blocked_by` entries.
+    pub blocked_penalty: f64,
+    /// Multiplier per other issue that lists this one in its `depends_on`
+    /// (i.e. how much completing it would unblock).
+    pub unblocks: f64,
+    /// Multiplier per label.
+    pub label: f64,
+    /// Flat bonus when `assignee` is set.
+    pub assignee: f64,
+}
+
+impl Default for UrgencyWeights {
+    fn default() -> Self {
+        Self {
+            priority: 1.0,
+            age: 2.0,
+            age_cap_days: 365.0,
+            blocked_penalty: 5.0,
+            unblocks: 1.0,
+            label: 0.1,
+            assignee: 0.5,
+        }
+    }
+}
+
+/// Maps `Priority` onto a Taskwarrior-style High/Med/Low urgency scale: `P0`
+/// is High (6.0), `P1`/`P2` are Med (3.9), `P3`/`P4` are Low (1.8), and a
+/// missing priority contributes nothing.
+#[allow(clippy::match_same_arms)]
+fn priority_urgency(priority: Option<Priority>) -> f64 {
+    match priority {
+        Some(Priority::P0) => 6.0,
+        Some(Priority::P1 | Priority::P2) => 3.9,
+        Some(Priority::P3 | Priority::P4) => 1.8,
+        None => 0.0,
+    }
+}
+
+/// Taskwarrior-style urgency score for ranking [`find_ready`] output: a
+/// weighted linear sum of priority, age (capped so very old issues don't
+/// dominate forever), a penalty for still having open blockers, a bonus
+/// proportional to `unblocks_count` (how many other issues list this one in
+/// their `depends_on`, i.e. how much finishing it unblocks — computed by the
+/// caller via [`get_dependency_graph`] since it depends on the whole issue
+/// set, not just this one), a small bonus per label, and a bonus for having
+/// an assignee.
 #[must_use]
-#[allow(clippy::arithmetic_side_effects, clippy::cast_possible_wrap)]
-pub fn find_stale(issues: &[BeadIssue], days: u64) -> Vec<BeadIssue> {
-    let cutoff = Utc::now() - chrono::Duration::days(days as i64);
+#[allow(clippy::arithmetic_side_effects, clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+pub fn urgency(
+    issue: &BeadIssue,
+    now: DateTime<Utc>,
+    unblocks_count: usize,
+    weights: &UrgencyWeights,
+) -> f64 {
+    let age_days = (now - issue.created_at).num_days().max(0) as f64;
+    let age_term = weights.age * (age_days / weights.age_cap_days).min(1.0);
+
+    let blocked_term = if issue.is_blocked() { -weights.blocked_penalty } else { 0.0 };
+
+    let unblocks_term = weights.unblocks * unblocks_count as f64;
+
+    let label_term = weights.label * issue.labels.as_ref().map_or(0, Vec::len) as f64;
+
+    let assignee_term = if issue.assignee.is_some() { weights.assignee } else { 0.0 };
+
+    weights.priority * priority_urgency(issue.priority)
+        + age_term
+        + blocked_term
+        + unblocks_term
+        + label_term
+        + assignee_term
+}
 
-    issues
-        .iter()
-        .filter(|i| i.updated_at < cutoff && i.status != IssueStatus::Closed)
-        .cloned()
-        .collect()
+/// [`find_ready`] sorted by descending [`urgency`], with ties broken by
+/// `created_at` (oldest first) for a stable order.
+#[must_use]
+pub fn find_ready_ranked(issues: &[BeadIssue], weights: &UrgencyWeights) -> Vec<BeadIssue> {
+    let dependents = get_dependency_graph(issues);
+    let now = Utc::now();
+
+    let mut ready = find_ready(issues);
+    ready.sort_by(|a, b| {
+        let unblocks_a = dependents.get(&a.id).map_or(0, Vec::len);
+        let unblocks_b = dependents.get(&b.id).map_or(0, Vec::len);
+        let ua = urgency(a, now, unblocks_a, weights);
+        let ub = urgency(b, now, unblocks_b, weights);
+        ub.partial_cmp(&ua)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.created_at.cmp(&b.created_at))
+    });
+    ready
 }
 
+/// A dependency cycle that blocked [`topological_order`] from placing every
+/// issue: the ids left over once Kahn's algorithm drains everything it can.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("dependency cycle detected, unresolved issues: {0:?}")]
+pub struct CycleError(pub Vec<String>);
+
+/// Finds every strongly-connected dependency cycle among `issues`'
+/// `depends_on` edges (e.g. "A depends on B depends on A"), via Tarjan's
+/// algorithm. `Closed` issues are treated as already-satisfied dependencies,
+/// so their edges are dropped before looking for cycles.
 #[must_use]
-pub fn find_potential_duplicates(
-    issues: &[BeadIssue],
-    threshold: usize,
-) -> Vec<(BeadIssue, Vec<BeadIssue>)> {
-    let issues_vec: Vec<BeadIssue> = issues.to_vec();
+pub fn detect_cycles(issues: &[BeadIssue]) -> Vec<Vec<String>> {
+    let open_ids: std::collections::HashSet<&str> = issues
+        .iter()
+        .filter(|i| i.status != IssueStatus::Closed)
+        .map(|i| i.id.as_str())
+        .collect();
 
-    issues_vec
+    let adjacency: std::collections::HashMap<&str, Vec<&str>> = issues
         .iter()
-        .enumerate()
-        .filter(|(i, _)| *i < issues_vec.len().saturating_sub(1))
-        .filter_map(|(i, issue)| {
-            #[allow(clippy::arithmetic_side_effects)]
-            let similar: Vec<BeadIssue> = issues_vec
+        .filter(|i| open_ids.contains(i.id.as_str()))
+        .map(|i| {
+            let deps = i
+                .depends_on
                 .iter()
-                .skip(i + 1)
-                .filter(|other| {
+                .flatten()
+                .map(String::as_str)
+                .filter(|d| open_ids.contains(d))
+                .collect();
+            (i.id.as_str(), deps)
+        })
+        .collect();
+
+    let nodes: Vec<&str> = issues
+        .iter()
+        .map(|i| i.id.as_str())
+        .filter(|id| open_ids.contains(id))
+        .collect();
+
+    tarjan_scc(&nodes, &adjacency)
+}
+
+/// Runs Kahn's algorithm over `open`'s `depends_on` edges, returning the ids
+/// it managed to place (in topological order) followed by the ids left over
+/// because they sit on, or depend on, a cycle. The second list is empty iff
+/// every id was placed.
+#[allow(clippy::arithmetic_side_effects)]
+fn kahn_order(open: &[&BeadIssue]) -> (Vec<String>, Vec<String>) {
+    let open_ids: std::collections::HashSet<&str> = open.iter().map(|i| i.id.as_str()).collect();
+
+    let deps_of: std::collections::HashMap<&str, Vec<&str>> = open
+        .iter()
+        .map(|issue| {
+            let deps = issue
+                .depends_on
+                .iter()
+                .flatten()
+                .map(String::as_str)
+                .filter(|d| open_ids.contains(d))
+                .collect();
+            (issue.id.as_str(), deps)
+        })
+        .collect();
+
+    let mut dependents: std::collections::HashMap<&str, Vec<&str>> = std::collections::HashMap::new();
+    let mut indegree: std::collections::HashMap<&str, usize> =
+        open.iter().map(|i| (i.id.as_str(), 0usize)).collect();
+    for (&node, deps) in &deps_of {
+        for &dep in deps {
+            dependents.entry(dep).or_default().push(node);
+            *indegree.entry(node).or_insert(0) += 1;
+        }
+    }
+
+    let mut queue: std::collections::VecDeque<&str> = open
+        .iter()
+        .map(|i| i.id.as_str())
+        .filter(|id| indegree.get(id).copied().unwrap_or(0) == 0)
+        .collect();
+    let mut ordered: Vec<&str> = Vec::new();
+    let mut remaining = indegree.clone();
+    while let Some(node) = queue.pop_front() {
+        ordered.push(node);
+        for &next in dependents.get(node).into_iter().flatten() {
+            if let Some(deg) = remaining.get_mut(next) {
+                *deg = deg.saturating_sub(1);
+                if *deg == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    let placed: std::collections::HashSet<&str> = ordered.iter().copied().collect();
+    let unresolved = open
+        .iter()
+        .map(|i| i.id.as_str())
+        .filter(|id| !placed.contains(id))
+        .map(str::to_string)
+        .collect();
+
+    (ordered.into_iter().map(str::to_string).collect(), unresolved)
+}
+
+/// Topologically sorts `issues` by `depends_on` edges using Kahn's algorithm
+/// (see [`kahn_order`]). `Closed` issues are treated as already-satisfied
+/// dependencies, so their edges are removed up front and they never block or
+/// appear in the order.
+///
+/// # Errors
+/// Returns a [`CycleError`] of the ids left over — those trapped in, or
+/// downstream of, a cycle — when the graph is not fully acyclic.
+#[allow(clippy::arithmetic_side_effects)]
+pub fn topological_order(issues: &[BeadIssue]) -> std::result::Result<Vec<String>, CycleError> {
+    let open: Vec<&BeadIssue> = issues.iter().filter(|i| i.status != IssueStatus::Closed).collect();
+    let (ordered, unresolved) = kahn_order(&open);
+
+    if unresolved.is_empty() {
+        Ok(ordered)
+    } else {
+        Err(CycleError(unresolved))
+    }
+}
+
+/// [`find_ready`], but ordered so that unblocked work is surfaced in a valid
+/// execution sequence (every issue appears after everything it
+/// `depends_on`), by intersecting [`find_ready`] with the order
+/// [`topological_order`] computes. Issues left unplaced by a cycle elsewhere
+/// in the backlog still show up if they're otherwise ready, appended after
+/// the ordered portion in input order, so one deadlock doesn't hide
+/// unrelated ready work.
+#[must_use]
+pub fn find_ready_in_order(issues: &[BeadIssue]) -> Vec<BeadIssue> {
+    let ready = find_ready(issues);
+    let ready_ids: std::collections::HashSet<&str> = ready.iter().map(|i| i.id.as_str()).collect();
+
+    let open: Vec<&BeadIssue> = issues.iter().filter(|i| i.status != IssueStatus::Closed).collect();
+    let (ordered, unresolved) = kahn_order(&open);
+
+    ordered
+        .iter()
+        .chain(unresolved.iter())
+        .filter(|id| ready_ids.contains(id.as_str()))
+        .filter_map(|id| ready.iter().find(|i| &i.id == id).cloned())
+        .collect()
+}
+
+#[must_use]
+#[allow(clippy::arithmetic_side_effects, clippy::cast_possible_wrap)]
+pub fn find_stale(issues: &[BeadIssue], days: u64) -> Vec<BeadIssue> {
+    let cutoff = Utc::now() - chrono::Duration::days(days as i64);
+
+    issues
+        .iter()
+        .filter(|i| i.updated_at < cutoff && i.status != IssueStatus::Closed)
+        .cloned()
+        .collect()
+}
+
+#[must_use]
+pub fn find_potential_duplicates(
+    issues: &[BeadIssue],
+    threshold: usize,
+) -> Vec<(BeadIssue, Vec<BeadIssue>)> {
+    let issues_vec: Vec<BeadIssue> = issues.to_vec();
+
+    issues_vec
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i < issues_vec.len().saturating_sub(1))
+        .filter_map(|(i, issue)| {
+            #[allow(clippy::arithmetic_side_effects)]
+            let similar: Vec<BeadIssue> = issues_vec
+                .iter()
+                .skip(i + 1)
+                .filter(|other| {
                     let self_words: std::collections::HashSet<_> =
                         issue.title.split_whitespace().collect();
                     let other_words: std::collections::HashSet<_> =
@@ -753,6 +1795,187 @@ pub fn get_issue(issues: &[BeadIssue], id: &str) -> Option<BeadIssue> {
     issues.iter().find(|i| i.id == id).cloned()
 }
 
+/// Number of slots in a MinHash signature. Split into `LSH_BANDS` bands of
+/// `LSH_ROWS` rows (`LSH_BANDS * LSH_ROWS == MINHASH_SLOTS`).
+const MINHASH_SLOTS: usize = 64;
+const LSH_BANDS: usize = 16;
+const LSH_ROWS: usize = 4;
+/// Words per shingle when building the text fingerprint.
+const SHINGLE_SIZE: usize = 3;
+
+/// Hashes a single value with the standard library hasher.
+fn hash64<T: std::hash::Hash>(value: &T) -> u64 {
+    use std::hash::Hasher;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Deterministic seed stream (SplitMix64) used as the MinHash permutations, so
+/// results are reproducible across runs.
+#[allow(clippy::arithmetic_side_effects)]
+fn minhash_seeds() -> [u64; MINHASH_SLOTS] {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    let mut seeds = [0u64; MINHASH_SLOTS];
+    for seed in &mut seeds {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        *seed = z ^ (z >> 31);
+    }
+    seeds
+}
+
+/// Builds the set of word-shingle hashes for an issue's lowercased title and
+/// description. Texts shorter than [`SHINGLE_SIZE`] words fall back to a single
+/// shingle over all their words so short issues still fingerprint distinctly.
+fn shingle_hashes(issue: &BeadIssue) -> std::collections::HashSet<u64> {
+    let mut text = issue.title.to_lowercase();
+    if let Some(desc) = &issue.description {
+        text.push(' ');
+        text.push_str(&desc.to_lowercase());
+    }
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    let mut shingles = std::collections::HashSet::new();
+    if words.len() < SHINGLE_SIZE {
+        if !words.is_empty() {
+            shingles.insert(hash64(&words.join(" ")));
+        }
+        return shingles;
+    }
+    for window in words.windows(SHINGLE_SIZE) {
+        shingles.insert(hash64(&window.join(" ")));
+    }
+    shingles
+}
+
+/// Computes an `MINHASH_SLOTS`-long MinHash signature from a shingle set.
+fn minhash_signature(
+    shingles: &std::collections::HashSet<u64>,
+    seeds: &[u64; MINHASH_SLOTS],
+) -> [u64; MINHASH_SLOTS] {
+    let mut signature = [u64::MAX; MINHASH_SLOTS];
+    for &shingle in shingles {
+        for (slot, &seed) in signature.iter_mut().zip(seeds.iter()) {
+            let permuted = shingle ^ seed;
+            if permuted < *slot {
+                *slot = permuted;
+            }
+        }
+    }
+    signature
+}
+
+/// Estimates Jaccard similarity as the fraction of matching signature slots.
+#[allow(clippy::arithmetic_side_effects)]
+fn signature_similarity(a: &[u64; MINHASH_SLOTS], b: &[u64; MINHASH_SLOTS]) -> f32 {
+    let matches = a.iter().zip(b.iter()).filter(|(x, y)| x == y).count();
+    matches as f32 / MINHASH_SLOTS as f32
+}
+
+/// Disjoint-set (union-find) over issue indices.
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let ra = self.find(a);
+        let rb = self.find(b);
+        if ra != rb {
+            self.parent[ra] = rb;
+        }
+    }
+}
+
+/// Groups issues into near-duplicate clusters using MinHash signatures and LSH
+/// banding.
+///
+/// Each issue is fingerprinted into 3-word shingles over its lowercased title
+/// and description, reduced to a 64-slot MinHash signature. LSH banding limits
+/// comparison to issues colliding in at least one band, and pairs whose
+/// estimated Jaccard similarity is at least `similarity` are unioned into
+/// clusters. Only clusters with more than one member are returned.
+#[must_use]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn find_duplicate_clusters(issues: &[BeadIssue], similarity: f32) -> Vec<Vec<BeadIssue>> {
+    let n = issues.len();
+    if n < 2 {
+        return Vec::new();
+    }
+
+    let seeds = minhash_seeds();
+    let signatures: Vec<[u64; MINHASH_SLOTS]> = issues
+        .iter()
+        .map(|issue| minhash_signature(&shingle_hashes(issue), &seeds))
+        .collect();
+
+    // LSH: bucket issues by each band's hash, collecting candidate pairs that
+    // collide in at least one band.
+    let mut candidates: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+    for band in 0..LSH_BANDS {
+        let start = band * LSH_ROWS;
+        let mut buckets: std::collections::HashMap<u64, Vec<usize>> =
+            std::collections::HashMap::new();
+        for (doc, signature) in signatures.iter().enumerate() {
+            let slice = &signature[start..start + LSH_ROWS];
+            buckets.entry(hash64(&slice)).or_default().push(doc);
+        }
+        for bucket in buckets.values() {
+            for i in 0..bucket.len() {
+                for j in (i + 1)..bucket.len() {
+                    candidates.insert((bucket[i], bucket[j]));
+                }
+            }
+        }
+    }
+
+    // Union candidate pairs whose estimated similarity clears the threshold.
+    let mut uf = UnionFind::new(n);
+    for &(a, b) in &candidates {
+        if signature_similarity(&signatures[a], &signatures[b]) >= similarity {
+            uf.union(a, b);
+        }
+    }
+
+    // Gather members by representative root, preserving input order.
+    let mut groups: std::collections::HashMap<usize, Vec<usize>> = std::collections::HashMap::new();
+    for i in 0..n {
+        let root = uf.find(i);
+        groups.entry(root).or_default().push(i);
+    }
+
+    let mut clusters: Vec<Vec<BeadIssue>> = groups
+        .into_values()
+        .filter(|members| members.len() > 1)
+        .map(|members| members.into_iter().map(|i| issues[i].clone()).collect())
+        .collect();
+    // Stable output: order clusters by their first member's input position.
+    clusters.sort_by_key(|cluster| {
+        cluster
+            .first()
+            .and_then(|first| issues.iter().position(|i| i.id == first.id))
+            .unwrap_or(usize::MAX)
+    });
+    clusters
+}
+
 #[must_use]
 pub fn get_issues_by_id(issues: &[BeadIssue], ids: &[String]) -> Vec<BeadIssue> {
     let id_set: std::collections::HashSet<_> = ids.iter().collect();
@@ -807,6 +2030,504 @@ pub fn calculate_critical_path(issues: &[BeadIssue]) -> Vec<BeadIssue> {
         .unwrap_or_default()
 }
 
+/// Result of analysing the `depends_on` DAG of a set of issues.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyAnalysis {
+    /// Strongly-connected components of size > 1 (or self-dependencies): each
+    /// inner vector is a group of issue ids that deadlock each other.
+    pub cycles: Vec<Vec<String>>,
+    /// A topological order of the acyclic portion (dependencies before
+    /// dependents). Nodes involved in a cycle are omitted.
+    pub topological_order: Vec<String>,
+    /// The weighted longest path through the acyclic portion, as issues in
+    /// execution order.
+    pub critical_path: Vec<BeadIssue>,
+}
+
+/// Analyses the dependency graph formed by the issues' `depends_on` edges.
+///
+/// An edge `A → B` means "A must finish before B" (i.e. `B` depends on `A`).
+/// The function runs Kahn's algorithm to produce a topological order, isolates
+/// any nodes left in cycles (grouped into strongly-connected components via
+/// Tarjan's algorithm), and computes the weighted longest path over the acyclic
+/// remainder. Each issue's weight is its [`BeadIssue::estimate`] if present,
+/// otherwise 1.
+#[must_use]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn analyze_dependencies(issues: &[BeadIssue]) -> DependencyAnalysis {
+    let ids: std::collections::HashSet<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+
+    // Dependencies restricted to issues that actually exist.
+    let deps_of: std::collections::HashMap<&str, Vec<&str>> = issues
+        .iter()
+        .map(|issue| {
+            let deps = issue
+                .depends_on
+                .iter()
+                .flatten()
+                .map(String::as_str)
+                .filter(|d| ids.contains(d))
+                .collect();
+            (issue.id.as_str(), deps)
+        })
+        .collect();
+
+    // Forward adjacency A → B (dependents of A) and in-degrees (= dep count).
+    let mut dependents: std::collections::HashMap<&str, Vec<&str>> = std::collections::HashMap::new();
+    let mut indegree: std::collections::HashMap<&str, usize> =
+        issues.iter().map(|i| (i.id.as_str(), 0usize)).collect();
+    for (&node, deps) in &deps_of {
+        for &dep in deps {
+            dependents.entry(dep).or_default().push(node);
+            *indegree.entry(node).or_insert(0) += 1;
+        }
+    }
+
+    // Kahn's algorithm. Process lowest-degree-first in a simple queue.
+    let mut queue: std::collections::VecDeque<&str> = issues
+        .iter()
+        .map(|i| i.id.as_str())
+        .filter(|id| indegree.get(id).copied().unwrap_or(0) == 0)
+        .collect();
+    let mut topo: Vec<&str> = Vec::new();
+    let mut remaining = indegree.clone();
+    while let Some(node) = queue.pop_front() {
+        topo.push(node);
+        for &next in dependents.get(node).into_iter().flatten() {
+            if let Some(deg) = remaining.get_mut(next) {
+                *deg = deg.saturating_sub(1);
+                if *deg == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    let acyclic: std::collections::HashSet<&str> = topo.iter().copied().collect();
+    let cyclic: Vec<&str> = issues
+        .iter()
+        .map(|i| i.id.as_str())
+        .filter(|id| !acyclic.contains(id))
+        .collect();
+    let cycles = tarjan_scc(&cyclic, &dependents);
+
+    // Weighted longest path over the acyclic nodes in topological order.
+    let weight = |id: &str| -> u64 {
+        issues
+            .iter()
+            .find(|i| i.id == id)
+            .and_then(|i| i.estimate)
+            .map_or(1, u64::from)
+    };
+    let mut finish: std::collections::HashMap<&str, u64> = std::collections::HashMap::new();
+    let mut parent: std::collections::HashMap<&str, Option<&str>> = std::collections::HashMap::new();
+    for &node in &topo {
+        let mut best = 0u64;
+        let mut best_pred: Option<&str> = None;
+        for &dep in deps_of.get(node).into_iter().flatten() {
+            if let Some(&f) = finish.get(dep) {
+                if f > best {
+                    best = f;
+                    best_pred = Some(dep);
+                }
+            }
+        }
+        finish.insert(node, best + weight(node));
+        parent.insert(node, best_pred);
+    }
+
+    let mut tail = finish.iter().max_by_key(|(_, &f)| f).map(|(&id, _)| id);
+    let mut path_ids: Vec<&str> = Vec::new();
+    while let Some(id) = tail {
+        path_ids.push(id);
+        tail = parent.get(id).copied().flatten();
+    }
+    path_ids.reverse();
+    let critical_path = path_ids
+        .iter()
+        .filter_map(|id| issues.iter().find(|i| &i.id == id).cloned())
+        .collect();
+
+    DependencyAnalysis {
+        cycles,
+        topological_order: topo.iter().map(|s| (*s).to_string()).collect(),
+        critical_path,
+    }
+}
+
+/// Longest dependency chain of still-open issues, for prioritizing the
+/// sequence that gates delivery. Each issue is weighted 1; use
+/// [`critical_path_with_cost`] to weight by [`Priority`] or any other
+/// measure.
+#[must_use]
+pub fn critical_path(issues: &[BeadIssue]) -> Vec<BeadIssue> {
+    critical_path_with_cost(issues, |_| 1)
+}
+
+/// Same as [`critical_path`], but each node's weight comes from `cost`
+/// instead of a flat 1 — for example `|i| i.priority.map_or(1, |p| 5 -
+/// p.to_u32() as u64)` to favor chains through high-priority work.
+///
+/// `Closed` issues are dropped before the longest-path computation, so they
+/// neither contribute weight nor appear in the returned chain. The DAG is
+/// topologically sorted first (Kahn's algorithm); any node left out by a
+/// cycle is simply absent from that order and so never considered, which
+/// keeps the longest-path pass operating only on the acyclic portion. Each
+/// open node's `longest[node] = cost(node) + max(longest[pred])` is computed
+/// in topological order, recording whichever predecessor achieved the max;
+/// the chain is then reconstructed by starting at the globally-longest node
+/// and walking those recorded predecessors back to the root, then reversing.
+/// Returns an empty vec when no issues are open.
+#[must_use]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn critical_path_with_cost(
+    issues: &[BeadIssue],
+    cost: impl Fn(&BeadIssue) -> u64,
+) -> Vec<BeadIssue> {
+    let open: Vec<&BeadIssue> = issues
+        .iter()
+        .filter(|i| i.status != IssueStatus::Closed)
+        .collect();
+    if open.is_empty() {
+        return Vec::new();
+    }
+
+    let ids: std::collections::HashSet<&str> = open.iter().map(|i| i.id.as_str()).collect();
+    let deps_of: std::collections::HashMap<&str, Vec<&str>> = open
+        .iter()
+        .map(|issue| {
+            let deps = issue
+                .depends_on
+                .iter()
+                .flatten()
+                .map(String::as_str)
+                .filter(|d| ids.contains(d))
+                .collect();
+            (issue.id.as_str(), deps)
+        })
+        .collect();
+
+    let mut dependents: std::collections::HashMap<&str, Vec<&str>> = std::collections::HashMap::new();
+    let mut indegree: std::collections::HashMap<&str, usize> =
+        open.iter().map(|i| (i.id.as_str(), 0usize)).collect();
+    for (&node, deps) in &deps_of {
+        for &dep in deps {
+            dependents.entry(dep).or_default().push(node);
+            *indegree.entry(node).or_insert(0) += 1;
+        }
+    }
+
+    let mut queue: std::collections::VecDeque<&str> = open
+        .iter()
+        .map(|i| i.id.as_str())
+        .filter(|id| indegree.get(id).copied().unwrap_or(0) == 0)
+        .collect();
+    let mut topo: Vec<&str> = Vec::new();
+    let mut remaining = indegree.clone();
+    while let Some(node) = queue.pop_front() {
+        topo.push(node);
+        for &next in dependents.get(node).into_iter().flatten() {
+            if let Some(deg) = remaining.get_mut(next) {
+                *deg = deg.saturating_sub(1);
+                if *deg == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    let weight = |id: &str| -> u64 {
+        open.iter()
+            .find(|i| i.id == id)
+            .map_or(1, |i| cost(i))
+    };
+    let mut longest: std::collections::HashMap<&str, u64> = std::collections::HashMap::new();
+    let mut predecessor: std::collections::HashMap<&str, Option<&str>> =
+        std::collections::HashMap::new();
+    for &node in &topo {
+        let mut best = 0u64;
+        let mut best_pred: Option<&str> = None;
+        for &dep in deps_of.get(node).into_iter().flatten() {
+            if let Some(&f) = longest.get(dep) {
+                if f > best {
+                    best = f;
+                    best_pred = Some(dep);
+                }
+            }
+        }
+        longest.insert(node, best + weight(node));
+        predecessor.insert(node, best_pred);
+    }
+
+    let mut tail = longest.iter().max_by_key(|(_, &f)| f).map(|(&id, _)| id);
+    let mut path_ids: Vec<&str> = Vec::new();
+    while let Some(id) = tail {
+        path_ids.push(id);
+        tail = predecessor.get(id).copied().flatten();
+    }
+    path_ids.reverse();
+
+    path_ids
+        .iter()
+        .filter_map(|id| open.iter().find(|i| i.id == *id).map(|i| (*i).clone()))
+        .collect()
+}
+
+/// Tarjan's strongly-connected-components over the subgraph induced by `nodes`,
+/// returning only components that represent a cycle (size > 1 or a self-loop).
+#[allow(clippy::arithmetic_side_effects)]
+fn tarjan_scc(nodes: &[&str], adjacency: &std::collections::HashMap<&str, Vec<&str>>) -> Vec<Vec<String>> {
+    let node_set: std::collections::HashSet<&str> = nodes.iter().copied().collect();
+    let mut index_of: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    let mut low: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    let mut on_stack: std::collections::HashSet<&str> = std::collections::HashSet::new();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut next_index = 0usize;
+    let mut components: Vec<Vec<String>> = Vec::new();
+
+    // Iterative DFS to stay clear of deep recursion on large backlogs.
+    for &start in nodes {
+        if index_of.contains_key(start) {
+            continue;
+        }
+        let mut call_stack: Vec<(&str, usize)> = vec![(start, 0)];
+        while let Some((v, child)) = call_stack.pop() {
+            if child == 0 {
+                index_of.insert(v, next_index);
+                low.insert(v, next_index);
+                next_index += 1;
+                stack.push(v);
+                on_stack.insert(v);
+            }
+            let neighbors: Vec<&str> = adjacency
+                .get(v)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|w| node_set.contains(w))
+                .collect();
+            if child < neighbors.len() {
+                let w = neighbors[child];
+                call_stack.push((v, child + 1));
+                if !index_of.contains_key(w) {
+                    call_stack.push((w, 0));
+                } else if on_stack.contains(w) {
+                    let wl = index_of.get(w).copied().unwrap_or(0);
+                    let entry = low.entry(v).or_insert(0);
+                    *entry = (*entry).min(wl);
+                }
+            } else {
+                // Finished v: propagate low-link to the parent, if any.
+                if let Some(&(p, _)) = call_stack.last() {
+                    let vl = low.get(v).copied().unwrap_or(0);
+                    let entry = low.entry(p).or_insert(0);
+                    *entry = (*entry).min(vl);
+                }
+                if low.get(v) == index_of.get(v) {
+                    let mut component = Vec::new();
+                    while let Some(w) = stack.pop() {
+                        on_stack.remove(w);
+                        component.push(w.to_string());
+                        if w == v {
+                            break;
+                        }
+                    }
+                    let is_self_loop = adjacency
+                        .get(v)
+                        .into_iter()
+                        .flatten()
+                        .any(|&w| w == v);
+                    if component.len() > 1 || is_self_loop {
+                        components.push(component);
+                    }
+                }
+            }
+        }
+    }
+    components
+}
+
+/// A directed dependency graph over a set of issues, keyed by issue id with an
+/// edge from each issue to every id in its `depends_on`.
+///
+/// Where [`analyze_dependencies`] returns a one-shot snapshot, `DependencyGraph`
+/// is a reusable structure: build it once, then ask for transitive cycles, a
+/// topological order, or the ready-work front. Ids referenced by `depends_on`
+/// that do not name a known issue are dangling and treated as already satisfied
+/// (conceptually closed), so they never block readiness or appear in a cycle.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyGraph {
+    /// Node ids in input order, for stable iteration.
+    ids: Vec<String>,
+    /// id → the ids it depends on, restricted to known nodes.
+    deps_of: std::collections::HashMap<String, Vec<String>>,
+    /// id → its status, used by [`Self::ready_issues`].
+    status_of: std::collections::HashMap<String, IssueStatus>,
+}
+
+impl DependencyGraph {
+    /// Builds the graph from the issues' `depends_on` edges.
+    #[must_use]
+    pub fn new(issues: &[BeadIssue]) -> Self {
+        let known: std::collections::HashSet<&str> =
+            issues.iter().map(|i| i.id.as_str()).collect();
+        let ids = issues.iter().map(|i| i.id.clone()).collect();
+        let deps_of = issues
+            .iter()
+            .map(|issue| {
+                let deps = issue
+                    .depends_on
+                    .iter()
+                    .flatten()
+                    .filter(|d| known.contains(d.as_str()))
+                    .cloned()
+                    .collect();
+                (issue.id.clone(), deps)
+            })
+            .collect();
+        let status_of = issues
+            .iter()
+            .map(|i| (i.id.clone(), i.status))
+            .collect();
+        Self {
+            ids,
+            deps_of,
+            status_of,
+        }
+    }
+
+    /// Detects every dependency cycle via a three-color DFS.
+    ///
+    /// Each node is white (unvisited), gray (on the current recursion stack), or
+    /// black (finished). Reaching a gray node closes a cycle, which is
+    /// reconstructed from the suffix of the active stack. Returns one id list per
+    /// cycle found; an acyclic graph yields an empty vector. Never panics.
+    #[must_use]
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn detect_cycles(&self) -> Vec<Vec<String>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Color {
+            White,
+            Gray,
+            Black,
+        }
+        let mut color: std::collections::HashMap<&str, Color> =
+            self.ids.iter().map(|id| (id.as_str(), Color::White)).collect();
+        let mut cycles: Vec<Vec<String>> = Vec::new();
+
+        for root in &self.ids {
+            if color.get(root.as_str()) != Some(&Color::White) {
+                continue;
+            }
+            // Iterative DFS carrying the active path so a back-edge can be
+            // reconstructed into a cycle without recursion.
+            let mut path: Vec<&str> = Vec::new();
+            let mut stack: Vec<(&str, usize)> = vec![(root.as_str(), 0)];
+            while let Some((node, child)) = stack.pop() {
+                if child == 0 {
+                    color.insert(node, Color::Gray);
+                    path.push(node);
+                }
+                let neighbors = self.deps_of.get(node).map_or(&[][..], Vec::as_slice);
+                if child < neighbors.len() {
+                    let next = neighbors[child].as_str();
+                    stack.push((node, child + 1));
+                    match color.get(next).copied().unwrap_or(Color::White) {
+                        Color::White => stack.push((next, 0)),
+                        Color::Gray => {
+                            // Back-edge: the cycle is the path suffix from `next`.
+                            if let Some(pos) = path.iter().position(|&p| p == next) {
+                                cycles.push(path[pos..].iter().map(|s| (*s).to_string()).collect());
+                            }
+                        }
+                        Color::Black => {}
+                    }
+                } else {
+                    color.insert(node, Color::Black);
+                    path.pop();
+                }
+            }
+        }
+        cycles
+    }
+
+    /// Produces a topological order (dependencies before dependents) via Kahn's
+    /// algorithm.
+    ///
+    /// # Errors
+    /// Returns the ids of the nodes left unscheduled — those trapped in a cycle —
+    /// when the graph is not fully acyclic.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn topological_order(&self) -> Result<Vec<String>, Vec<String>> {
+        let mut indegree: std::collections::HashMap<&str, usize> =
+            self.ids.iter().map(|id| (id.as_str(), 0usize)).collect();
+        let mut dependents: std::collections::HashMap<&str, Vec<&str>> =
+            std::collections::HashMap::new();
+        for (node, deps) in &self.deps_of {
+            for dep in deps {
+                dependents.entry(dep.as_str()).or_default().push(node.as_str());
+                *indegree.entry(node.as_str()).or_insert(0) += 1;
+            }
+        }
+
+        let mut queue: std::collections::VecDeque<&str> = self
+            .ids
+            .iter()
+            .map(String::as_str)
+            .filter(|id| indegree.get(id).copied().unwrap_or(0) == 0)
+            .collect();
+        let mut order: Vec<String> = Vec::new();
+        while let Some(node) = queue.pop_front() {
+            order.push(node.to_string());
+            for &next in dependents.get(node).into_iter().flatten() {
+                if let Some(deg) = indegree.get_mut(next) {
+                    *deg = deg.saturating_sub(1);
+                    if *deg == 0 {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        if order.len() < self.ids.len() {
+            let scheduled: std::collections::HashSet<&str> =
+                order.iter().map(String::as_str).collect();
+            let remaining = self
+                .ids
+                .iter()
+                .filter(|id| !scheduled.contains(id.as_str()))
+                .cloned()
+                .collect();
+            return Err(remaining);
+        }
+        Ok(order)
+    }
+
+    /// Returns the open issues whose every dependency is `Closed` — the
+    /// actionable work front. A dangling dependency (no matching node) is treated
+    /// as satisfied.
+    #[must_use]
+    pub fn ready_issues(&self) -> Vec<String> {
+        self.ids
+            .iter()
+            .filter(|id| {
+                matches!(
+                    self.status_of.get(id.as_str()),
+                    Some(IssueStatus::Open | IssueStatus::InProgress)
+                )
+            })
+            .filter(|id| {
+                self.deps_of.get(id.as_str()).into_iter().flatten().all(|dep| {
+                    self.status_of
+                        .get(dep.as_str())
+                        .map_or(true, |s| *s == IssueStatus::Closed)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+}
+
 #[must_use]
 pub fn to_ids(issues: &[BeadIssue]) -> Vec<String> {
     issues.iter().map(|i| i.id.clone()).collect()
@@ -838,6 +2559,20 @@ pub fn count_by_status(issues: &[BeadIssue]) -> HashMap<IssueStatus, usize> {
         .collect()
 }
 
+/// Counts issues by the stringified value of UDA `name` (see [`UdaValue`]'s
+/// `Display` impl), mirroring [`count_by_status`]. Issues missing that UDA
+/// are omitted rather than counted under some placeholder key.
+#[must_use]
+pub fn count_by_uda(issues: &[BeadIssue], name: &str) -> HashMap<String, usize> {
+    issues
+        .iter()
+        .filter_map(|issue| issue.udas.as_ref()?.get(name))
+        .map(ToString::to_string)
+        .counts()
+        .into_iter()
+        .collect()
+}
+
 #[must_use]
 pub fn any_match(issues: &[BeadIssue], filter: &BeadFilter) -> bool {
     issues.iter().any(|i| matches_filter(i, filter))
@@ -848,14 +2583,731 @@ pub fn all_match(issues: &[BeadIssue], filter: &BeadFilter) -> bool {
     issues.iter().all(|i| matches_filter(i, filter))
 }
 
-#[cfg(test)]
-#[allow(clippy::arithmetic_side_effects, clippy::redundant_clone)]
-mod tests {
-    use super::*;
+/// Throughput and effort for a single time bucket.
+#[derive(Debug, Clone)]
+pub struct VelocityBucket {
+    /// Inclusive start of the bucket
+    pub start: DateTime<Utc>,
+    /// Exclusive end of the bucket
+    pub end: DateTime<Utc>,
+    /// Number of issues closed within the bucket
+    pub closed_count: usize,
+    /// Total tracked time (seconds) logged on issues closed in the bucket
+    pub tracked_seconds: i64,
+    /// Mean cycle time of issues closed in the bucket, if any closed
+    pub mean_cycle_time: Option<chrono::Duration>,
+}
 
-    #[test]
-    fn test_bead_issue_is_blocked() {
-        let blocked = BeadIssue {
+/// Bucketizes closed issues by `closed_at` over the trailing `window`, split
+/// into fixed `bucket`-sized slots, reporting throughput, tracked effort, and
+/// mean cycle time per slot.
+///
+/// Buckets run forward from `now - window` to `now`; issues closed outside that
+/// range are ignored.
+#[must_use]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn velocity(
+    issues: &[BeadIssue],
+    window: chrono::Duration,
+    bucket: chrono::Duration,
+) -> Vec<VelocityBucket> {
+    if bucket <= chrono::Duration::zero() {
+        return Vec::new();
+    }
+    let now = Utc::now();
+    let start = now - window;
+
+    let mut buckets = Vec::new();
+    let mut cursor = start;
+    while cursor < now {
+        let end = (cursor + bucket).min(now);
+        let closed: Vec<&BeadIssue> = issues
+            .iter()
+            .filter(|i| {
+                i.closed_at
+                    .is_some_and(|c| c >= cursor && c < end)
+            })
+            .collect();
+
+        let tracked_seconds = closed.iter().map(|i| i.tracked_seconds()).sum();
+        let mean_cycle_time = mean_duration(
+            &closed
+                .iter()
+                .filter_map(|i| i.cycle_time())
+                .collect::<Vec<_>>(),
+        );
+
+        buckets.push(VelocityBucket {
+            start: cursor,
+            end,
+            closed_count: closed.len(),
+            tracked_seconds,
+            mean_cycle_time,
+        });
+        cursor = end;
+    }
+    buckets
+}
+
+/// Percentile and mean cycle-time statistics over closed issues.
+#[derive(Debug, Clone, Default)]
+pub struct CycleStats {
+    /// Number of closed issues contributing to the stats
+    pub count: usize,
+    /// Median (50th percentile) cycle time
+    pub p50: Option<chrono::Duration>,
+    /// 90th percentile cycle time
+    pub p90: Option<chrono::Duration>,
+    /// Mean cycle time
+    pub mean: Option<chrono::Duration>,
+}
+
+/// Computes p50/p90 (nearest-rank) and mean cycle time across closed issues.
+#[must_use]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn cycle_time_stats(issues: &[BeadIssue]) -> CycleStats {
+    let mut durations: Vec<chrono::Duration> =
+        issues.iter().filter_map(BeadIssue::cycle_time).collect();
+    durations.sort();
+
+    CycleStats {
+        count: durations.len(),
+        p50: nearest_rank(&durations, 50),
+        p90: nearest_rank(&durations, 90),
+        mean: mean_duration(&durations),
+    }
+}
+
+/// Nearest-rank percentile of a pre-sorted duration slice.
+#[allow(clippy::arithmetic_side_effects)]
+fn nearest_rank(sorted: &[chrono::Duration], percentile: u32) -> Option<chrono::Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+    // rank = ceil(p/100 * N), clamped to [1, N]; index is rank - 1.
+    let n = sorted.len();
+    let rank = ((percentile as usize * n) + 99) / 100;
+    let index = rank.clamp(1, n) - 1;
+    sorted.get(index).copied()
+}
+
+/// Arithmetic mean of a set of durations, or `None` when empty.
+#[allow(clippy::arithmetic_side_effects)]
+fn mean_duration(durations: &[chrono::Duration]) -> Option<chrono::Duration> {
+    if durations.is_empty() {
+        return None;
+    }
+    let total: i64 = durations.iter().map(chrono::Duration::num_seconds).sum();
+    Some(chrono::Duration::seconds(total / durations.len() as i64))
+}
+
+/// A single point on a burndown timeline.
+#[derive(Debug, Clone)]
+pub struct BurndownPoint {
+    /// Bucket boundary this count is measured at
+    pub at: DateTime<Utc>,
+    /// Issues created on or before `at` and not yet closed by `at`
+    pub remaining_open: usize,
+}
+
+/// Walks the timeline from `from` to `to` in `bucket` steps, emitting the count
+/// of issues still open at each boundary.
+///
+/// An issue is open at time `t` when it was created on or before `t` and either
+/// has not closed or closed strictly after `t`.
+#[must_use]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn burndown(
+    issues: &[BeadIssue],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    bucket: chrono::Duration,
+) -> Vec<BurndownPoint> {
+    if bucket <= chrono::Duration::zero() || from > to {
+        return Vec::new();
+    }
+    let mut points = Vec::new();
+    let mut at = from;
+    while at <= to {
+        let remaining_open = issues
+            .iter()
+            .filter(|i| i.created_at <= at && i.closed_at.is_none_or(|c| c > at))
+            .count();
+        points.push(BurndownPoint { at, remaining_open });
+        at = at + bucket;
+    }
+    points
+}
+
+/// A short list of English stopwords dropped from the index and queries when
+/// [`SearchOptions::drop_stopwords`] is set. Kept intentionally small so domain
+/// terms are never discarded.
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+/// Tuning parameters for the BM25 ranker.
+#[derive(Debug, Clone, Copy)]
+pub struct SearchOptions {
+    /// BM25 term-frequency saturation (`k1`)
+    pub k1: f32,
+    /// BM25 length-normalization strength (`b`)
+    pub b: f32,
+    /// Multiplier applied to term frequencies coming from an issue's title
+    pub title_boost: f32,
+    /// Drop common stopwords from both the index and the query
+    pub drop_stopwords: bool,
+    /// Fall back to near-matching index terms (Levenshtein ≤ 1, or ≤ 2 for
+    /// terms longer than 8 characters) for query terms with no exact posting
+    pub typo_tolerance: bool,
+    /// Fractional weight applied to scores contributed by fuzzy term matches
+    pub fuzzy_weight: f32,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            k1: 1.2,
+            b: 0.75,
+            title_boost: 2.0,
+            drop_stopwords: true,
+            typo_tolerance: true,
+            fuzzy_weight: 0.5,
+        }
+    }
+}
+
+/// Splits `text` into lowercase alphanumeric terms, optionally dropping
+/// stopwords.
+fn tokenize(text: &str, drop_stopwords: bool) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(str::to_lowercase)
+        .filter(|t| !(drop_stopwords && STOPWORDS.contains(&t.as_str())))
+        .collect()
+}
+
+/// An in-memory inverted index over a bead corpus, scored with Okapi BM25.
+///
+/// Title terms are weighted above description/label terms via
+/// [`SearchOptions::title_boost`]; the index keeps per-term postings plus the
+/// document lengths needed for BM25 length normalization.
+#[derive(Debug, Clone)]
+pub struct SearchIndex {
+    /// term -> list of `(document index, boosted term frequency)`
+    postings: std::collections::HashMap<String, Vec<(usize, f32)>>,
+    /// document frequency per term (number of docs containing it)
+    doc_freq: std::collections::HashMap<String, usize>,
+    /// raw token count per document, indexed by document position
+    doc_len: Vec<f32>,
+    /// mean document length across the corpus
+    avg_doc_len: f32,
+    /// number of indexed documents
+    n_docs: usize,
+    options: SearchOptions,
+}
+
+impl SearchIndex {
+    /// Builds an inverted index over `issues` using the given options.
+    #[must_use]
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn build(issues: &[BeadIssue], options: &SearchOptions) -> Self {
+        let n_docs = issues.len();
+        let mut postings: std::collections::HashMap<String, Vec<(usize, f32)>> =
+            std::collections::HashMap::new();
+        let mut doc_freq: std::collections::HashMap<String, usize> =
+            std::collections::HashMap::new();
+        let mut doc_len = vec![0.0f32; n_docs];
+
+        // Fold one field's tokens into the per-document frequency map, boosting
+        // the term frequency and growing the raw length by the token count.
+        fn ingest(
+            text: &str,
+            boost: f32,
+            drop_stopwords: bool,
+            tf: &mut std::collections::HashMap<String, f32>,
+            length: &mut usize,
+        ) {
+            for term in tokenize(text, drop_stopwords) {
+                *tf.entry(term).or_insert(0.0) += boost;
+                *length += 1;
+            }
+        }
+
+        for (doc, issue) in issues.iter().enumerate() {
+            // Accumulate boosted term frequencies for this document.
+            let mut tf: std::collections::HashMap<String, f32> = std::collections::HashMap::new();
+            let mut length = 0usize;
+            let drop = options.drop_stopwords;
+
+            ingest(&issue.title, options.title_boost, drop, &mut tf, &mut length);
+            if let Some(desc) = &issue.description {
+                ingest(desc, 1.0, drop, &mut tf, &mut length);
+            }
+            if let Some(labels) = &issue.labels {
+                for label in labels {
+                    ingest(label, 1.0, drop, &mut tf, &mut length);
+                }
+            }
+
+            doc_len[doc] = length as f32;
+            for (term, freq) in tf {
+                postings.entry(term.clone()).or_default().push((doc, freq));
+                *doc_freq.entry(term).or_insert(0) += 1;
+            }
+        }
+
+        let total_len: f32 = doc_len.iter().sum();
+        let avg_doc_len = if n_docs == 0 {
+            0.0
+        } else {
+            total_len / n_docs as f32
+        };
+
+        Self {
+            postings,
+            doc_freq,
+            doc_len,
+            avg_doc_len,
+            n_docs,
+            options,
+        }
+    }
+
+    /// Inverse document frequency for a term, per the BM25 `+1` smoothed form.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn idf(&self, df: usize) -> f32 {
+        let n = self.n_docs as f32;
+        let df = df as f32;
+        ((n - df + 0.5) / (df + 0.5) + 1.0).ln()
+    }
+
+    /// Scores every document against `query`, returning `(doc index, score)`
+    /// pairs for documents with a positive score, sorted by descending score.
+    #[must_use]
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn score(&self, query: &str) -> Vec<(usize, f32)> {
+        let mut scores = vec![0.0f32; self.n_docs];
+
+        for term in tokenize(query, self.options.drop_stopwords) {
+            // Exact posting first; otherwise fall back to fuzzy index terms.
+            let contributions: Vec<(&str, f32)> = if self.postings.contains_key(&term) {
+                vec![(term.as_str(), 1.0)]
+            } else if self.options.typo_tolerance {
+                self.fuzzy_terms(&term)
+                    .into_iter()
+                    .map(|t| (t, self.options.fuzzy_weight))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            for (index_term, weight) in contributions {
+                let Some(postings) = self.postings.get(index_term) else {
+                    continue;
+                };
+                let df = self.doc_freq.get(index_term).copied().unwrap_or(0);
+                let idf = self.idf(df);
+                for &(doc, tf) in postings {
+                    let len_norm = 1.0 - self.options.b
+                        + self.options.b * self.doc_len[doc] / self.avg_doc_len.max(1.0);
+                    let denom = tf + self.options.k1 * len_norm;
+                    if denom > 0.0 {
+                        scores[doc] += weight * idf * (tf * (self.options.k1 + 1.0)) / denom;
+                    }
+                }
+            }
+        }
+
+        let mut ranked: Vec<(usize, f32)> = scores
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, s)| s > 0.0)
+            .collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranked
+    }
+
+    /// Returns index terms that fuzzily match `term`: a prefix of the term,
+    /// or within the typo-tolerance edit distance (see [`typo_budget`]).
+    ///
+    /// This is the shared "did this token match?" primitive behind every
+    /// non-exact match in the file: [`SearchIndex::score`] uses it to widen a
+    /// query term with no exact posting, and [`search_issues`] /
+    /// [`search`] classify a token's match against a single document with it
+    /// instead of re-deriving prefix/typo logic of their own.
+    fn fuzzy_terms(&self, term: &str) -> Vec<&str> {
+        self.postings
+            .keys()
+            .filter(|candidate| term_matches(term, candidate).is_some())
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Classifies how `term` matches documents in the index: the set of
+    /// documents reached by an exact posting, and (when `typo_tolerance` is
+    /// in this index's options) the additional set reached only through
+    /// [`SearchIndex::fuzzy_terms`].
+    ///
+    /// Exact and fuzzy are judged per document, not per term globally: a
+    /// document lacking the exact term can still land in `fuzzy` via another
+    /// indexed term even when a *different* document has the exact one.
+    fn matched_docs(&self, term: &str) -> (std::collections::HashSet<usize>, std::collections::HashSet<usize>) {
+        let exact: std::collections::HashSet<usize> = self
+            .postings
+            .get(term)
+            .map(|postings| postings.iter().map(|&(doc, _)| doc).collect())
+            .unwrap_or_default();
+
+        let mut fuzzy = std::collections::HashSet::new();
+        if self.options.typo_tolerance {
+            for index_term in self.fuzzy_terms(term) {
+                if let Some(postings) = self.postings.get(index_term) {
+                    fuzzy.extend(postings.iter().map(|&(doc, _)| doc).filter(|doc| !exact.contains(doc)));
+                }
+            }
+        }
+
+        (exact, fuzzy)
+    }
+}
+
+/// Classic dynamic-programming Levenshtein edit distance between two strings.
+#[must_use]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for (i, &ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Ranks `issues` against a free-text `query` using BM25, returning each
+/// matching issue paired with its relevance score in descending order.
+///
+/// Unlike the substring matching in [`matches_filter`], this tokenizes and
+/// scores terms so `"auth login"` matches `"login authentication"` and results
+/// are ordered by relevance. Issues that score zero are omitted.
+#[must_use]
+pub fn search_ranked(
+    issues: &[BeadIssue],
+    query: &str,
+    options: &SearchOptions,
+) -> Vec<(BeadIssue, f32)> {
+    let index = SearchIndex::build(issues, options);
+    index
+        .score(query)
+        .into_iter()
+        .filter_map(|(doc, score)| issues.get(doc).map(|issue| (issue.clone(), score)))
+        .collect()
+}
+
+/// An issue paired with its match breakdown against a free-text query, as
+/// produced by [`search_issues`]. The three counters are compared
+/// lexicographically to rank results.
+#[derive(Debug, Clone)]
+pub struct ScoredIssue {
+    pub issue: BeadIssue,
+    /// Query tokens matched exactly by one of the issue's tokens
+    pub exact_matches: usize,
+    /// Query tokens matched only by prefix or typo tolerance
+    pub fuzzy_matches: usize,
+    /// Whether every query token found some match in the issue
+    pub complete: bool,
+}
+
+impl ScoredIssue {
+    /// True when the issue matched at least one query token.
+    #[must_use]
+    pub const fn is_match(&self) -> bool {
+        self.exact_matches > 0 || self.fuzzy_matches > 0
+    }
+}
+
+/// Length-scaled Levenshtein budget: no typos for short tokens, growing to two
+/// for long ones, mirroring how search engines relax matching with length.
+const fn typo_budget(len: usize) -> usize {
+    match len {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    }
+}
+
+/// Whether `word` matches query `term`: exact equality, `word` is a prefix
+/// extension of `term`, or `word` is within `term`'s length-scaled
+/// Levenshtein budget (see [`typo_budget`]). `Some(true)` for an exact match,
+/// `Some(false)` for a fuzzy one, `None` for no match.
+///
+/// This is the one predicate behind every non-BM25-internal match in the
+/// file: [`SearchIndex::fuzzy_terms`], [`search_issues`], and [`search`] all
+/// call it instead of each re-deriving their own prefix/typo rule.
+fn term_matches(term: &str, word: &str) -> Option<bool> {
+    if word == term {
+        return Some(true);
+    }
+    (word.starts_with(term) || levenshtein(term, word) <= typo_budget(term.len())).then_some(false)
+}
+
+/// Ordering key for priority where a higher priority sorts first and a missing
+/// priority sorts last.
+fn priority_rank(priority: Option<Priority>) -> u32 {
+    priority.map_or(u32::MAX, |p| p.to_u32())
+}
+
+/// `SearchOptions` used to build the [`SearchIndex`] behind [`search_issues`]:
+/// unweighted, stopwords kept (the caller's query is short and literal), and
+/// typo tolerance on so [`SearchIndex::fuzzy_terms`] supplies the prefix/typo
+/// matches this function classifies per issue.
+fn token_match_options() -> SearchOptions {
+    SearchOptions {
+        title_boost: 1.0,
+        drop_stopwords: false,
+        typo_tolerance: true,
+        ..SearchOptions::default()
+    }
+}
+
+/// Ranked fuzzy full-text search over `title`, `description`, and `labels`.
+///
+/// Both the query and each issue are tokenized on whitespace and punctuation. A
+/// query token matches an issue token when it is equal (exact), a prefix of it,
+/// or within a length-scaled Levenshtein budget (see [`typo_budget`]) — the
+/// same [`SearchIndex::fuzzy_terms`] classification [`search_ranked`] uses to
+/// widen an unmatched BM25 term, so the two rankers agree on what counts as a
+/// match even though they score differently. Each issue is scored by, in
+/// order, its exact-match count, its prefix/typo-match count, and whether
+/// every query token matched; ties fall back to `Priority` then `updated_at`
+/// recency. Issues matching no token are dropped, and results are returned in
+/// descending score order.
+#[must_use]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn search_issues(issues: &[BeadIssue], query: &str) -> Vec<ScoredIssue> {
+    let query_tokens = tokenize(query, false);
+    if query_tokens.is_empty() {
+        return Vec::new();
+    }
+
+    let index = SearchIndex::build(issues, &token_match_options());
+    let per_token: Vec<(std::collections::HashSet<usize>, std::collections::HashSet<usize>)> =
+        query_tokens.iter().map(|token| index.matched_docs(token)).collect();
+
+    let mut scored: Vec<ScoredIssue> = issues
+        .iter()
+        .enumerate()
+        .filter_map(|(doc, issue)| {
+            let mut exact = 0usize;
+            let mut fuzzy = 0usize;
+            let mut matched = 0usize;
+            for (exact_docs, fuzzy_docs) in &per_token {
+                if exact_docs.contains(&doc) {
+                    exact += 1;
+                    matched += 1;
+                } else if fuzzy_docs.contains(&doc) {
+                    fuzzy += 1;
+                    matched += 1;
+                }
+            }
+            (exact + fuzzy > 0).then(|| ScoredIssue {
+                issue: issue.clone(),
+                exact_matches: exact,
+                fuzzy_matches: fuzzy,
+                complete: matched == query_tokens.len(),
+            })
+        })
+        .collect();
+
+    scored.sort_by(|a, b| {
+        b.exact_matches
+            .cmp(&a.exact_matches)
+            .then(b.fuzzy_matches.cmp(&a.fuzzy_matches))
+            .then(b.complete.cmp(&a.complete))
+            .then(priority_rank(a.issue.priority).cmp(&priority_rank(b.issue.priority)))
+            .then(b.issue.updated_at.cmp(&a.issue.updated_at))
+    });
+    scored
+}
+
+/// Which field of a [`BeadIssue`] a [`MatchSpan`] was found in — also the
+/// tie-break [`search`] uses for its field-weight ranking tier. Declared in
+/// rank order so title beats labels beats description under `#[derive(Ord)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchField {
+    Title,
+    Label,
+    Description,
+}
+
+/// One matched query term's location within a [`BeadIssue`] field, as a
+/// byte-offset range into that field's own text so callers can highlight it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchSpan {
+    pub field: MatchField,
+    pub start: usize,
+    pub end: usize,
+    /// `true` for an exact term match, `false` for a typo-tolerant match
+    pub exact: bool,
+}
+
+/// An issue matched by [`search`], together with every matched span across
+/// its title, labels, and description.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub issue: BeadIssue,
+    pub spans: Vec<MatchSpan>,
+}
+
+/// Tuning knobs for [`search`]'s typo-tolerant matching.
+#[derive(Debug, Clone, Copy)]
+pub struct SearchOpts {
+    /// Drop common stopwords (see [`STOPWORDS`]) from the query before matching
+    pub drop_stopwords: bool,
+}
+
+impl Default for SearchOpts {
+    fn default() -> Self {
+        Self {
+            drop_stopwords: false,
+        }
+    }
+}
+
+/// Splits `text` into lowercase alphanumeric words paired with their
+/// byte-offset range in `text`, so a match can be reported back as a
+/// highlightable [`MatchSpan`].
+fn word_offsets(text: &str) -> Vec<(usize, usize, String)> {
+    let mut spans = Vec::new();
+    let mut start: Option<usize> = None;
+    for (i, c) in text.char_indices() {
+        if c.is_alphanumeric() {
+            start.get_or_insert(i);
+        } else if let Some(s) = start.take() {
+            spans.push((s, i, text[s..i].to_lowercase()));
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.len(), text[s..].to_lowercase()));
+    }
+    spans
+}
+
+/// Typo-tolerant full-text search over issue title, labels, and description,
+/// returning match offsets so callers can highlight hits.
+///
+/// Query terms match a field word via [`term_matches`]: exact equality, a
+/// prefix, or within [`typo_budget`]'s length-scaled Levenshtein distance —
+/// the same predicate [`search_issues`] and [`SearchIndex::fuzzy_terms`] use.
+/// Results are ranked by an ordered rule cascade: number of distinct query
+/// terms matched, proximity of those terms in reading order across the
+/// issue's fields (tighter clusters rank higher), exact-vs-typo match count,
+/// then field weight (title, then labels, then description). Issues matching
+/// no term are omitted.
+///
+/// This complements [`get_issues_by_id`] for users who don't know issue ids.
+/// Unlike [`search_ranked`]'s BM25 relevance score, it reports per-match
+/// spans for highlighting rather than a single numeric score.
+#[must_use]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn search(issues: &[BeadIssue], query: &str, opts: &SearchOpts) -> Vec<SearchHit> {
+    let terms = tokenize(query, opts.drop_stopwords);
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut ranked: Vec<(SearchHit, usize, usize, usize, MatchField)> = Vec::new();
+
+    for issue in issues {
+        let mut fields: Vec<(MatchField, &str)> = vec![(MatchField::Title, issue.title.as_str())];
+        for label in issue.labels.iter().flatten() {
+            fields.push((MatchField::Label, label.as_str()));
+        }
+        if let Some(desc) = &issue.description {
+            fields.push((MatchField::Description, desc.as_str()));
+        }
+
+        let mut spans: Vec<MatchSpan> = Vec::new();
+        // Term index -> position of its first match, in reading order across
+        // fields, used below to score proximity.
+        let mut first_match_position: std::collections::HashMap<usize, usize> =
+            std::collections::HashMap::new();
+        let mut exact_count = 0usize;
+        let mut best_field = MatchField::Description;
+        let mut position = 0usize;
+
+        for (field, text) in &fields {
+            for (start, end, word) in word_offsets(text) {
+                for (term_idx, term) in terms.iter().enumerate() {
+                    let Some(exact) = term_matches(term, &word) else {
+                        continue;
+                    };
+                    spans.push(MatchSpan {
+                        field: *field,
+                        start,
+                        end,
+                        exact,
+                    });
+                    first_match_position.entry(term_idx).or_insert(position);
+                    if exact {
+                        exact_count += 1;
+                    }
+                    if *field < best_field {
+                        best_field = *field;
+                    }
+                }
+                position += 1;
+            }
+        }
+
+        if spans.is_empty() {
+            continue;
+        }
+
+        let matched_terms = first_match_position.len();
+        let mut positions: Vec<usize> = first_match_position.into_values().collect();
+        positions.sort_unstable();
+        let proximity: usize = positions.windows(2).map(|w| w[1] - w[0]).sum();
+
+        ranked.push((
+            SearchHit {
+                issue: issue.clone(),
+                spans,
+            },
+            matched_terms,
+            proximity,
+            exact_count,
+            best_field,
+        ));
+    }
+
+    ranked.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| a.2.cmp(&b.2))
+            .then_with(|| b.3.cmp(&a.3))
+            .then_with(|| a.4.cmp(&b.4))
+    });
+    ranked.into_iter().map(|(hit, ..)| hit).collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::arithmetic_side_effects, clippy::redundant_clone)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bead_issue_is_blocked() {
+        let blocked = BeadIssue {
             id: "test".to_string(),
             title: "Test".to_string(),
             status: IssueStatus::Blocked,
@@ -870,6 +3322,9 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             closed_at: None,
+            estimate: None,
+            time_entries: None,
+            udas: None,
         };
 
         let unblocked = BeadIssue {
@@ -887,6 +3342,9 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             closed_at: None,
+            estimate: None,
+            time_entries: None,
+            udas: None,
         };
 
         assert!(blocked.is_blocked());
@@ -910,6 +3368,9 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             closed_at: None,
+            estimate: None,
+            time_entries: None,
+            udas: None,
         };
 
         let in_progress = BeadIssue {
@@ -927,6 +3388,9 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             closed_at: None,
+            estimate: None,
+            time_entries: None,
+            udas: None,
         };
 
         let closed = BeadIssue {
@@ -944,6 +3408,9 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             closed_at: Some(Utc::now()),
+            estimate: None,
+            time_entries: None,
+            udas: None,
         };
 
         assert!(open.is_open());
@@ -969,6 +3436,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -985,6 +3455,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "3".to_string(),
@@ -1001,6 +3474,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "4".to_string(),
@@ -1017,6 +3493,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: Some(Utc::now()),
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1080,6 +3559,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1096,6 +3578,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: Some(Utc::now()),
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1124,6 +3609,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "p0".to_string(),
@@ -1140,6 +3628,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "p2".to_string(),
@@ -1156,10 +3647,13 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
-        let sorted = sort_issues(&issues, BeadSort::Priority, SortDirection::Desc);
+        let sorted = sort_issues(&issues, &BeadSort::Priority, SortDirection::Desc);
 
         assert_eq!(sorted[0].id, "p0");
         assert_eq!(sorted[1].id, "p2");
@@ -1184,6 +3678,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1200,6 +3697,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "3".to_string(),
@@ -1216,6 +3716,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1243,6 +3746,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "blocked".to_string(),
@@ -1259,6 +3765,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "unrelated".to_string(),
@@ -1275,6 +3784,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1302,6 +3814,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "blocked".to_string(),
@@ -1318,6 +3833,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1345,6 +3863,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "zjj-002".to_string(),
@@ -1361,6 +3882,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1390,6 +3914,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1406,6 +3933,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1434,6 +3964,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1450,6 +3983,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1476,6 +4012,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1492,6 +4031,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "3".to_string(),
@@ -1508,6 +4050,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: Some(Utc::now()),
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1575,6 +4120,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1591,6 +4139,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "3".to_string(),
@@ -1607,6 +4158,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: Some(Utc::now()),
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1640,6 +4194,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1656,6 +4213,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: Some(Utc::now()),
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1684,6 +4244,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1700,6 +4263,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1728,6 +4294,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1744,6 +4313,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "3".to_string(),
@@ -1760,6 +4332,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: Some(Utc::now()),
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1786,6 +4361,9 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             closed_at: None,
+            estimate: None,
+            time_entries: None,
+            udas: None,
         };
 
         let stale = BeadIssue {
@@ -1803,6 +4381,9 @@ mod tests {
             created_at: Utc::now() - chrono::Duration::days(30),
             updated_at: Utc::now() - chrono::Duration::days(30),
             closed_at: None,
+            estimate: None,
+            time_entries: None,
+            udas: None,
         };
 
         let issues = vec![recent.clone(), stale.clone()];
@@ -1831,6 +4412,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "blocked".to_string(),
@@ -1847,6 +4431,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "in-progress".to_string(),
@@ -1863,6 +4450,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1892,6 +4482,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1908,6 +4501,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "3".to_string(),
@@ -1924,6 +4520,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1953,6 +4552,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
             BeadIssue {
                 id: "2".to_string(),
@@ -1969,6 +4571,9 @@ mod tests {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 closed_at: None,
+                estimate: None,
+                time_entries: None,
+                udas: None,
             },
         ];
 
@@ -1979,4 +4584,1178 @@ mod tests {
             .map(|v| v.contains(&"1".to_string()))
             .unwrap_or(false));
     }
+
+    /// Minimal issue with a title and description, for search tests.
+    fn text_issue(id: &str, title: &str, description: &str) -> BeadIssue {
+        BeadIssue {
+            id: id.to_string(),
+            title: title.to_string(),
+            status: IssueStatus::Open,
+            priority: None,
+            issue_type: None,
+            description: Some(description.to_string()),
+            labels: None,
+            assignee: None,
+            parent: None,
+            depends_on: None,
+            blocked_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            closed_at: None,
+            estimate: None,
+            time_entries: None,
+            udas: None,
+        }
+    }
+
+    #[test]
+    fn test_tokenize_splits_and_lowercases() {
+        let tokens = tokenize("Login-Authentication, v2!", false);
+        assert_eq!(tokens, vec!["login", "authentication", "v2"]);
+    }
+
+    #[test]
+    fn test_levenshtein_distance() {
+        assert_eq!(levenshtein("login", "login"), 0);
+        assert_eq!(levenshtein("login", "logni"), 2);
+        assert_eq!(levenshtein("auth", "atuh"), 2);
+        assert_eq!(levenshtein("cache", "cahe"), 1);
+    }
+
+    #[test]
+    fn test_search_ranked_matches_out_of_order_terms() {
+        let issues = vec![
+            text_issue("1", "Login authentication flow", "Handle OAuth tokens"),
+            text_issue("2", "Database migration", "Schema upgrade"),
+        ];
+        let results = search_ranked(&issues, "auth login", &SearchOptions::default());
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.id, "1");
+        assert!(results[0].1 > 0.0);
+    }
+
+    #[test]
+    fn test_search_ranked_title_boost_orders_results() {
+        let issues = vec![
+            text_issue("body", "Unrelated", "mentions cache once"),
+            text_issue("title", "Cache eviction", "unrelated body"),
+        ];
+        let results = search_ranked(&issues, "cache", &SearchOptions::default());
+        assert_eq!(results.first().map(|r| r.0.id.as_str()), Some("title"));
+    }
+
+    #[test]
+    fn test_search_issues_ranks_exact_over_fuzzy() {
+        let issues = vec![
+            text_issue("exact", "Login authentication", "body"),
+            text_issue("typo", "Lohin authentication", "body"),
+        ];
+        let results = search_issues(&issues, "login");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].issue.id, "exact");
+        assert_eq!(results[0].exact_matches, 1);
+        assert_eq!(results[1].issue.id, "typo");
+        assert_eq!(results[1].fuzzy_matches, 1);
+    }
+
+    #[test]
+    fn test_search_issues_prefix_matches() {
+        let issues = vec![text_issue("1", "Authentication service", "body")];
+        let results = search_issues(&issues, "auth");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].fuzzy_matches, 1);
+        assert!(!results[0].complete || results[0].fuzzy_matches == 1);
+    }
+
+    #[test]
+    fn test_search_issues_drops_zero_score() {
+        let issues = vec![text_issue("1", "Database migration", "schema")];
+        assert!(search_issues(&issues, "login").is_empty());
+    }
+
+    #[test]
+    fn test_search_issues_completeness_outranks_partial() {
+        let issues = vec![
+            text_issue("both", "Cache eviction policy", "body"),
+            text_issue("one", "Cache warming", "body"),
+        ];
+        let results = search_issues(&issues, "cache eviction");
+        assert_eq!(results[0].issue.id, "both");
+        assert!(results[0].complete);
+    }
+
+    /// Issue with explicit dependencies and an optional estimate.
+    fn dep_issue(id: &str, deps: &[&str], estimate: Option<u32>) -> BeadIssue {
+        BeadIssue {
+            id: id.to_string(),
+            title: id.to_string(),
+            status: IssueStatus::Open,
+            priority: None,
+            issue_type: None,
+            description: None,
+            labels: None,
+            assignee: None,
+            parent: None,
+            depends_on: Some(deps.iter().map(|d| (*d).to_string()).collect()),
+            blocked_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            closed_at: None,
+            estimate,
+            time_entries: None,
+            udas: None,
+        }
+    }
+
+    #[test]
+    fn test_analyze_dependencies_topo_and_path() {
+        // c depends on b depends on a  =>  a must run first.
+        let issues = vec![
+            dep_issue("a", &[], None),
+            dep_issue("b", &["a"], None),
+            dep_issue("c", &["b"], None),
+        ];
+        let analysis = analyze_dependencies(&issues);
+        assert!(analysis.cycles.is_empty());
+        let pos = |id: &str| {
+            analysis
+                .topological_order
+                .iter()
+                .position(|x| x == id)
+                .unwrap_or(usize::MAX)
+        };
+        assert!(pos("a") < pos("b"));
+        assert!(pos("b") < pos("c"));
+        let path: Vec<&str> = analysis.critical_path.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(path, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_analyze_dependencies_weighted_path_prefers_estimate() {
+        // Diamond: d depends on b and c, both depend on a. b is heavier.
+        let issues = vec![
+            dep_issue("a", &[], Some(1)),
+            dep_issue("b", &["a"], Some(5)),
+            dep_issue("c", &["a"], Some(1)),
+            dep_issue("d", &["b", "c"], Some(1)),
+        ];
+        let analysis = analyze_dependencies(&issues);
+        let path: Vec<&str> = analysis.critical_path.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(path, vec!["a", "b", "d"]);
+    }
+
+    #[test]
+    fn test_analyze_dependencies_detects_cycle() {
+        // a -> b -> c -> a is a 3-node cycle; d is acyclic.
+        let issues = vec![
+            dep_issue("a", &["c"], None),
+            dep_issue("b", &["a"], None),
+            dep_issue("c", &["b"], None),
+            dep_issue("d", &[], None),
+        ];
+        let analysis = analyze_dependencies(&issues);
+        assert_eq!(analysis.cycles.len(), 1);
+        let mut cycle = analysis.cycles[0].clone();
+        cycle.sort();
+        assert_eq!(cycle, vec!["a", "b", "c"]);
+        // The acyclic remainder still yields a valid path.
+        assert_eq!(
+            analysis.critical_path.iter().map(|i| i.id.as_str()).collect::<Vec<_>>(),
+            vec!["d"]
+        );
+    }
+
+    #[test]
+    fn test_critical_path_orders_chain() {
+        let issues = vec![
+            dep_issue("a", &[], None),
+            dep_issue("b", &["a"], None),
+            dep_issue("c", &["b"], None),
+        ];
+        let path: Vec<&str> = critical_path(&issues).iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(path, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_critical_path_skips_closed_issues() {
+        let mut closed = dep_issue("b", &["a"], None);
+        closed.status = IssueStatus::Closed;
+        let issues = vec![dep_issue("a", &[], None), closed, dep_issue("c", &["b"], None)];
+        // b is closed, so c's dependency on it is dropped and c stands alone;
+        // the longest open chain is just "a" (length 1, tied with "c").
+        let path = critical_path(&issues);
+        assert!(path.iter().all(|i| i.status != IssueStatus::Closed));
+        assert!(!path.iter().any(|i| i.id == "b"));
+    }
+
+    #[test]
+    fn test_critical_path_with_cost_prefers_heavier_chain() {
+        // Diamond: d depends on b and c, both depend on a. b is heavier.
+        let issues = vec![
+            dep_issue("a", &[], None),
+            dep_issue("b", &["a"], None),
+            dep_issue("c", &["a"], None),
+            dep_issue("d", &["b", "c"], None),
+        ];
+        let cost = |i: &BeadIssue| if i.id == "b" { 5 } else { 1 };
+        let path: Vec<&str> = critical_path_with_cost(&issues, cost)
+            .iter()
+            .map(|i| i.id.as_str())
+            .collect();
+        assert_eq!(path, vec!["a", "b", "d"]);
+    }
+
+    #[test]
+    fn test_critical_path_empty_when_all_closed() {
+        let mut closed = dep_issue("a", &[], None);
+        closed.status = IssueStatus::Closed;
+        assert!(critical_path(&[closed]).is_empty());
+    }
+
+    #[test]
+    fn test_critical_path_empty_on_empty_input() {
+        assert!(critical_path(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_dependency_graph_topological_order() {
+        let issues = vec![
+            dep_issue("c", &["b"], None),
+            dep_issue("b", &["a"], None),
+            dep_issue("a", &[], None),
+        ];
+        let graph = DependencyGraph::new(&issues);
+        let order = graph.topological_order().expect("acyclic");
+        let pos = |id: &str| order.iter().position(|x| x == id).unwrap_or(usize::MAX);
+        assert!(pos("a") < pos("b"));
+        assert!(pos("b") < pos("c"));
+    }
+
+    #[test]
+    fn test_dependency_graph_detects_cycle() {
+        let issues = vec![
+            dep_issue("a", &["c"], None),
+            dep_issue("b", &["a"], None),
+            dep_issue("c", &["b"], None),
+        ];
+        let graph = DependencyGraph::new(&issues);
+        let cycles = graph.detect_cycles();
+        assert_eq!(cycles.len(), 1);
+        let mut nodes = cycles[0].clone();
+        nodes.sort();
+        assert_eq!(nodes, vec!["a", "b", "c"]);
+        // Topological ordering reports the same nodes as unschedulable.
+        let mut remaining = graph.topological_order().expect_err("cyclic");
+        remaining.sort();
+        assert_eq!(remaining, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_dependency_graph_ready_issues() {
+        let mut done = dep_issue("a", &[], None);
+        done.status = IssueStatus::Closed;
+        let issues = vec![
+            done,
+            dep_issue("b", &["a"], None),      // dep closed => ready
+            dep_issue("c", &["b"], None),      // dep still open => not ready
+            dep_issue("d", &["missing"], None), // dangling dep => ready
+        ];
+        let graph = DependencyGraph::new(&issues);
+        let mut ready = graph.ready_issues();
+        ready.sort();
+        assert_eq!(ready, vec!["b", "d"]);
+    }
+
+    #[test]
+    fn test_search_ranked_typo_tolerance() {
+        let issues = vec![text_issue("1", "Authentication service", "")];
+        let opts = SearchOptions::default();
+        // "authentcation" is one deletion away from the indexed term.
+        let results = search_ranked(&issues, "authentcation", &opts);
+        assert_eq!(results.len(), 1);
+
+        let strict = SearchOptions {
+            typo_tolerance: false,
+            ..SearchOptions::default()
+        };
+        assert!(search_ranked(&issues, "authentcation", &strict).is_empty());
+    }
+
+    #[test]
+    fn test_find_duplicate_clusters_groups_near_matches() {
+        let issues = vec![
+            text_issue(
+                "1",
+                "Fix login authentication bug",
+                "Users cannot log in with OAuth tokens",
+            ),
+            text_issue(
+                "2",
+                "Fix login authentication bug",
+                "Users cannot log in with OAuth tokens",
+            ),
+            text_issue(
+                "3",
+                "Add database migration tooling",
+                "Schema versioning and rollbacks",
+            ),
+        ];
+        let clusters = find_duplicate_clusters(&issues, 0.5);
+        assert_eq!(clusters.len(), 1);
+        let mut ids: Vec<&str> = clusters[0].iter().map(|i| i.id.as_str()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["1", "2"]);
+    }
+
+    #[test]
+    fn test_find_duplicate_clusters_ignores_distinct_issues() {
+        let issues = vec![
+            text_issue("1", "Completely unrelated alpha topic", "one"),
+            text_issue("2", "Totally different beta subject", "two"),
+        ];
+        assert!(find_duplicate_clusters(&issues, 0.8).is_empty());
+    }
+
+    /// Closed issue with an explicit creation/closure time and tracked seconds.
+    fn timed_issue(id: &str, created: DateTime<Utc>, closed: DateTime<Utc>, tracked: i64) -> BeadIssue {
+        BeadIssue {
+            id: id.to_string(),
+            title: id.to_string(),
+            status: IssueStatus::Closed,
+            priority: None,
+            issue_type: None,
+            description: None,
+            labels: None,
+            assignee: None,
+            parent: None,
+            depends_on: None,
+            blocked_by: None,
+            created_at: created,
+            updated_at: closed,
+            closed_at: Some(closed),
+            estimate: None,
+            time_entries: Some(vec![TimeEntry {
+                start: 0,
+                stop: tracked,
+                duration: tracked,
+            }]),
+            udas: None,
+        }
+    }
+
+    #[test]
+    fn test_cycle_time_stats_percentiles() {
+        let base = Utc::now();
+        let issues: Vec<BeadIssue> = (1..=4)
+            .map(|d| timed_issue(&d.to_string(), base, base + chrono::Duration::days(d), 0))
+            .collect();
+        let stats = cycle_time_stats(&issues);
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.p50, Some(chrono::Duration::days(2)));
+        assert_eq!(stats.p90, Some(chrono::Duration::days(4)));
+        assert_eq!(stats.mean, Some(chrono::Duration::hours(60)));
+    }
+
+    #[test]
+    fn test_burndown_counts_remaining_open() {
+        let base = Utc::now();
+        let issues = vec![timed_issue(
+            "1",
+            base,
+            base + chrono::Duration::days(2),
+            0,
+        )];
+        let points = burndown(
+            &issues,
+            base,
+            base + chrono::Duration::days(3),
+            chrono::Duration::days(1),
+        );
+        let remaining: Vec<usize> = points.iter().map(|p| p.remaining_open).collect();
+        assert_eq!(remaining, vec![1, 1, 0, 0]);
+    }
+
+    /// Issue carrying the given labels (used for property tests).
+    fn labeled_issue(id: &str, labels: &[&str]) -> BeadIssue {
+        BeadIssue {
+            id: id.to_string(),
+            title: id.to_string(),
+            status: IssueStatus::Open,
+            priority: None,
+            issue_type: None,
+            description: None,
+            labels: Some(labels.iter().map(|l| (*l).to_string()).collect()),
+            assignee: None,
+            parent: None,
+            depends_on: None,
+            blocked_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            closed_at: None,
+            estimate: None,
+            time_entries: None,
+            udas: None,
+        }
+    }
+
+    /// An otherwise-blank issue carrying a single UDA, for UDA filter/count tests.
+    fn uda_issue(id: &str, name: &str, value: UdaValue) -> BeadIssue {
+        let mut issue = labeled_issue(id, &[]);
+        issue
+            .udas
+            .get_or_insert_with(std::collections::BTreeMap::new)
+            .insert(name.to_string(), value);
+        issue
+    }
+
+    #[test]
+    fn test_filter_by_uda_equals() {
+        let issues = vec![
+            uda_issue("1", "customer", UdaValue::String("acme".to_string())),
+            uda_issue("2", "customer", UdaValue::String("globex".to_string())),
+        ];
+        let filter = BeadFilter::new()
+            .with_uda("customer", UdaPredicate::Equals(UdaValue::String("acme".to_string())));
+        let filtered = filter_issues(&issues, &filter);
+        assert_eq!(filtered.iter().map(|i| i.id.as_str()).collect::<Vec<_>>(), vec!["1"]);
+    }
+
+    #[test]
+    fn test_filter_by_uda_number_range() {
+        let issues = vec![
+            uda_issue("1", "story_points", UdaValue::Number(2.0)),
+            uda_issue("2", "story_points", UdaValue::Number(8.0)),
+            uda_issue("3", "story_points", UdaValue::Number(13.0)),
+        ];
+        let filter = BeadFilter::new().with_uda("story_points", UdaPredicate::NumberRange(3.0, 10.0));
+        let filtered = filter_issues(&issues, &filter);
+        assert_eq!(filtered.iter().map(|i| i.id.as_str()).collect::<Vec<_>>(), vec!["2"]);
+    }
+
+    #[test]
+    fn test_filter_by_uda_missing_attribute_excludes_issue() {
+        let issues = vec![labeled_issue("1", &[]), uda_issue("2", "sprint", UdaValue::Number(5.0))];
+        let filter = BeadFilter::new().with_uda("sprint", UdaPredicate::NumberRange(0.0, 10.0));
+        let filtered = filter_issues(&issues, &filter);
+        assert_eq!(filtered.iter().map(|i| i.id.as_str()).collect::<Vec<_>>(), vec!["2"]);
+    }
+
+    #[test]
+    fn test_count_by_uda() {
+        let issues = vec![
+            uda_issue("1", "customer", UdaValue::String("acme".to_string())),
+            uda_issue("2", "customer", UdaValue::String("acme".to_string())),
+            uda_issue("3", "customer", UdaValue::String("globex".to_string())),
+            labeled_issue("4", &[]),
+        ];
+        let counts = count_by_uda(&issues, "customer");
+        assert_eq!(counts.get("acme").copied(), Some(2));
+        assert_eq!(counts.get("globex").copied(), Some(1));
+        assert_eq!(counts.len(), 2);
+    }
+
+    #[test]
+    fn test_uda_round_trips_through_json() {
+        let issue = uda_issue("1", "eta", UdaValue::Date(Utc::now()));
+        let json = serde_json::to_string(&issue).expect("serialize");
+        let restored: BeadIssue = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(restored.udas, issue.udas);
+    }
+
+    #[test]
+    fn test_filter_by_property() {
+        let issues = vec![
+            labeled_issue("1", &["component:auth", "sprint:12"]),
+            labeled_issue("2", &["component:api", "sprint:12"]),
+            labeled_issue("3", &["backend"]),
+        ];
+        let filter = BeadFilter::new().with_property("component", "auth");
+        let filtered = filter_issues(&issues, &filter);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "1");
+    }
+
+    #[test]
+    fn test_filter_by_property_range() {
+        let issues = vec![
+            labeled_issue("1", &["sprint:3"]),
+            labeled_issue("2", &["sprint:12"]),
+            labeled_issue("3", &["sprint:20"]),
+        ];
+        let filter = BeadFilter::new().with_property_range("sprint", 5.0, 15.0);
+        let filtered = filter_issues(&issues, &filter);
+        assert_eq!(filtered.iter().map(|i| i.id.as_str()).collect::<Vec<_>>(), vec!["2"]);
+    }
+
+    #[test]
+    fn test_sort_by_property_numeric_with_missing_last() {
+        let issues = vec![
+            labeled_issue("big", &["sprint:12"]),
+            labeled_issue("none", &["backend"]),
+            labeled_issue("small", &["sprint:3"]),
+        ];
+        let sorted = sort_issues(
+            &issues,
+            &BeadSort::Property("sprint".to_string()),
+            SortDirection::Asc,
+        );
+        let ids: Vec<&str> = sorted.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["small", "big", "none"]);
+    }
+
+    #[test]
+    fn test_list_properties_distinct_values() {
+        let issues = vec![
+            labeled_issue("1", &["component:auth", "sprint:12"]),
+            labeled_issue("2", &["component:api", "sprint:12"]),
+        ];
+        let props = list_properties(&issues);
+        assert_eq!(
+            props.get("component"),
+            Some(&vec!["api".to_string(), "auth".to_string()])
+        );
+        assert_eq!(props.get("sprint"), Some(&vec!["12".to_string()]));
+    }
+
+    #[test]
+    fn test_velocity_buckets_recent_closures() {
+        let now = Utc::now();
+        let issues = vec![timed_issue(
+            "1",
+            now - chrono::Duration::hours(5),
+            now - chrono::Duration::hours(1),
+            3600,
+        )];
+        let buckets = velocity(&issues, chrono::Duration::days(1), chrono::Duration::days(1));
+        let total_closed: usize = buckets.iter().map(|b| b.closed_count).sum();
+        let total_tracked: i64 = buckets.iter().map(|b| b.tracked_seconds).sum();
+        assert_eq!(total_closed, 1);
+        assert_eq!(total_tracked, 3600);
+    }
+
+    fn track(issue_id: &str, kind: TrackKind, at: DateTime<Utc>) -> TrackEvent {
+        TrackEvent {
+            issue_id: issue_id.to_string(),
+            kind,
+            at,
+        }
+    }
+
+    #[test]
+    fn test_time_tracked_for_sums_closed_intervals() {
+        let base = Utc::now() - chrono::Duration::days(1);
+        let events = vec![
+            track("1", TrackKind::Start, base),
+            track("1", TrackKind::Stop, base + chrono::Duration::minutes(30)),
+            track("1", TrackKind::Start, base + chrono::Duration::hours(1)),
+            track("1", TrackKind::Stop, base + chrono::Duration::hours(2)),
+        ];
+        let total = time_tracked_for(&events, "1");
+        assert_eq!(total, chrono::Duration::minutes(90));
+    }
+
+    #[test]
+    fn test_time_tracked_for_implicit_stop_on_other_start() {
+        let base = Utc::now() - chrono::Duration::days(1);
+        let events = vec![
+            track("1", TrackKind::Start, base),
+            // Switching to issue "2" implicitly stops "1" after 20 minutes.
+            track("2", TrackKind::Start, base + chrono::Duration::minutes(20)),
+            track("2", TrackKind::Stop, base + chrono::Duration::minutes(50)),
+        ];
+        assert_eq!(time_tracked_for(&events, "1"), chrono::Duration::minutes(20));
+        assert_eq!(time_tracked_for(&events, "2"), chrono::Duration::minutes(30));
+    }
+
+    #[test]
+    fn test_time_tracked_for_open_interval_counts_to_now() {
+        let start = Utc::now() - chrono::Duration::minutes(10);
+        let events = vec![track("1", TrackKind::Start, start)];
+        let total = time_tracked_for(&events, "1");
+        assert!(total >= chrono::Duration::minutes(10));
+        assert!(total < chrono::Duration::minutes(11));
+    }
+
+    #[test]
+    fn test_time_tracked_for_unordered_events_are_sorted_first() {
+        let base = Utc::now() - chrono::Duration::days(1);
+        // Stop appears before Start in the slice; chronological order still wins.
+        let events = vec![
+            track("1", TrackKind::Stop, base + chrono::Duration::minutes(15)),
+            track("1", TrackKind::Start, base),
+        ];
+        assert_eq!(time_tracked_for(&events, "1"), chrono::Duration::minutes(15));
+    }
+
+    #[test]
+    fn test_total_tracked_per_status_groups_like_group_by_status() {
+        let base = Utc::now() - chrono::Duration::days(1);
+        let mut done = dep_issue("done", &[], None);
+        done.status = IssueStatus::Closed;
+        let issues = vec![dep_issue("open", &[], None), done];
+        let events = vec![
+            track("open", TrackKind::Start, base),
+            track("open", TrackKind::Stop, base + chrono::Duration::minutes(10)),
+            track("done", TrackKind::Start, base),
+            track("done", TrackKind::Stop, base + chrono::Duration::minutes(25)),
+        ];
+
+        let totals = total_tracked_per_status(&issues, &events);
+        assert_eq!(totals.get(&IssueStatus::Open), Some(&chrono::Duration::minutes(10)));
+        assert_eq!(totals.get(&IssueStatus::Closed), Some(&chrono::Duration::minutes(25)));
+    }
+
+    fn priority_issue(id: &str, priority: Priority, issue_type: IssueType) -> BeadIssue {
+        let mut issue = dep_issue(id, &[], None);
+        issue.priority = Some(priority);
+        issue.issue_type = Some(issue_type);
+        issue
+    }
+
+    #[test]
+    fn test_expr_and_or_not() {
+        let bug_p0 = priority_issue("1", Priority::P0, IssueType::Bug);
+        let feature_p0 = priority_issue("2", Priority::P0, IssueType::Feature);
+
+        let expr = Expr::And(vec![
+            Expr::Type(IssueType::Bug),
+            Expr::PriorityRange(Priority::P0, Priority::P0),
+        ]);
+        assert!(expr.eval(&bug_p0));
+        assert!(!expr.eval(&feature_p0));
+
+        let not_bug = Expr::Not(Box::new(Expr::Type(IssueType::Bug)));
+        assert!(!not_bug.eval(&bug_p0));
+        assert!(not_bug.eval(&feature_p0));
+    }
+
+    #[test]
+    fn test_expr_or_label_and_not_closed() {
+        // (Bug AND P0) OR label:urgent AND NOT closed
+        let expr = Expr::And(vec![
+            Expr::Or(vec![
+                Expr::And(vec![
+                    Expr::Type(IssueType::Bug),
+                    Expr::PriorityRange(Priority::P0, Priority::P0),
+                ]),
+                Expr::Label("urgent".to_string()),
+            ]),
+            Expr::Not(Box::new(Expr::Status(IssueStatus::Closed))),
+        ]);
+
+        let bug_p0 = priority_issue("1", Priority::P0, IssueType::Bug);
+        let mut urgent = labeled_issue("2", &["urgent"]);
+        urgent.status = IssueStatus::Closed;
+        let plain = labeled_issue("3", &["backend"]);
+
+        assert!(expr.eval(&bug_p0));
+        assert!(!expr.eval(&urgent)); // closed, so NOT closed fails
+        assert!(!expr.eval(&plain));
+    }
+
+    #[test]
+    fn test_expr_empty_and_is_vacuously_true_empty_or_is_false() {
+        let issue = dep_issue("1", &[], None);
+        assert!(Expr::And(vec![]).eval(&issue));
+        assert!(!Expr::Or(vec![]).eval(&issue));
+    }
+
+    #[test]
+    fn test_bead_filter_to_expr_matches_flat_filter() {
+        let filter = BeadFilter::new()
+            .with_status(IssueStatus::Open)
+            .with_type(IssueType::Bug)
+            .with_priority_range(Priority::P0, Priority::P1)
+            .with_label("urgent");
+
+        let matching = {
+            let mut issue = priority_issue("1", Priority::P0, IssueType::Bug);
+            issue.labels = Some(vec!["urgent".to_string()]);
+            issue
+        };
+        let non_matching = priority_issue("2", Priority::P2, IssueType::Bug);
+
+        let expr = filter.to_expr();
+        assert!(expr.eval(&matching));
+        assert!(!expr.eval(&non_matching));
+    }
+
+    #[test]
+    fn test_apply_query_with_expr_overrides_flat_filter() {
+        let issues = vec![
+            priority_issue("1", Priority::P0, IssueType::Bug),
+            priority_issue("2", Priority::P3, IssueType::Feature),
+        ];
+
+        // Flat filter alone would only keep bugs; the expr instead keeps
+        // anything at P0 or better, regardless of type.
+        let query = BeadQuery::new()
+            .filter(BeadFilter::new().with_type(IssueType::Bug))
+            .with_expr(Expr::PriorityRange(Priority::P0, Priority::P0));
+
+        let results = apply_query(&issues, &query);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "1");
+    }
+
+    #[test]
+    fn test_save_and_load_filter_round_trips() {
+        let dir = tempfile::TempDir::new().expect("tempdir");
+        let query = BeadQuery::new()
+            .filter(BeadFilter::new().with_status(IssueStatus::Open))
+            .sort_by(BeadSort::Priority)
+            .direction(SortDirection::Asc)
+            .with_expr(Expr::Not(Box::new(Expr::Status(IssueStatus::Closed))));
+
+        save_filter(dir.path(), "my-open-blockers", &query).expect("save_filter");
+        let loaded = load_filter(dir.path(), "my-open-blockers").expect("load_filter");
+
+        assert_eq!(loaded.sort, BeadSort::Priority);
+        assert_eq!(loaded.direction, SortDirection::Asc);
+        assert_eq!(loaded.filter.status, vec![IssueStatus::Open]);
+        assert_eq!(
+            loaded.expr,
+            Some(Expr::Not(Box::new(Expr::Status(IssueStatus::Closed))))
+        );
+    }
+
+    #[test]
+    fn test_load_filter_missing_name_is_not_found() {
+        let dir = tempfile::TempDir::new().expect("tempdir");
+        let err = load_filter(dir.path(), "does-not-exist").expect_err("expected NotFound");
+        assert!(matches!(err, BeadsError::NotFound(_)));
+    }
+
+    fn issue_with_time(id: &str, seconds: i64) -> BeadIssue {
+        let mut issue = dep_issue(id, &[], None);
+        issue.time_entries = Some(vec![TimeEntry {
+            start: 0,
+            stop: seconds,
+            duration: seconds,
+        }]);
+        issue
+    }
+
+    #[test]
+    fn test_time_tracked_sums_entries() {
+        let issue = issue_with_time("1", 600);
+        assert_eq!(time_tracked(&issue), chrono::Duration::seconds(600));
+    }
+
+    #[test]
+    fn test_time_tracked_with_no_entries_is_zero() {
+        let issue = dep_issue("1", &[], None);
+        assert_eq!(time_tracked(&issue), chrono::Duration::zero());
+    }
+
+    #[test]
+    fn test_total_time_tracked_rolls_up_dependents() {
+        let epic = issue_with_time("epic", 100);
+        let mut child = issue_with_time("child", 200);
+        child.depends_on = Some(vec!["epic".to_string()]);
+
+        let issues = vec![epic, child];
+        assert_eq!(
+            total_time_tracked(&issues, "epic"),
+            chrono::Duration::seconds(300)
+        );
+    }
+
+    #[test]
+    fn test_total_time_tracked_rolls_up_parent_children() {
+        let epic = issue_with_time("epic", 50);
+        let mut sub = issue_with_time("sub", 150);
+        sub.parent = Some("epic".to_string());
+
+        let issues = vec![epic, sub];
+        assert_eq!(
+            total_time_tracked(&issues, "epic"),
+            chrono::Duration::seconds(200)
+        );
+    }
+
+    #[test]
+    fn test_total_time_tracked_does_not_double_count_shared_descendant() {
+        let epic = issue_with_time("epic", 10);
+        let mut mid = issue_with_time("mid", 20);
+        mid.parent = Some("epic".to_string());
+        let mut leaf = issue_with_time("leaf", 30);
+        // Reachable both as a dependent of "epic" directly and as a child of "mid".
+        leaf.parent = Some("mid".to_string());
+        leaf.depends_on = Some(vec!["epic".to_string()]);
+
+        let issues = vec![epic, mid, leaf];
+        assert_eq!(
+            total_time_tracked(&issues, "epic"),
+            chrono::Duration::seconds(60)
+        );
+    }
+
+    #[test]
+    fn test_urgency_higher_priority_scores_higher() {
+        let now = Utc::now();
+        let high = priority_issue("1", Priority::P0, IssueType::Bug);
+        let low = priority_issue("2", Priority::P4, IssueType::Bug);
+        let weights = UrgencyWeights::default();
+
+        assert!(urgency(&high, now, 0, &weights) > urgency(&low, now, 0, &weights));
+    }
+
+    #[test]
+    fn test_urgency_blocked_penalty_lowers_score() {
+        let now = Utc::now();
+        let mut blocked = dep_issue("1", &[], None);
+        blocked.status = IssueStatus::Blocked;
+        blocked.blocked_by = Some(vec!["other".to_string()]);
+        let open = dep_issue("2", &[], None);
+        let weights = UrgencyWeights::default();
+
+        assert!(urgency(&blocked, now, 0, &weights) < urgency(&open, now, 0, &weights));
+    }
+
+    #[test]
+    fn test_urgency_unblocks_and_assignee_and_label_bonuses() {
+        let now = Utc::now();
+        let weights = UrgencyWeights::default();
+        let plain = dep_issue("1", &[], None);
+        let unblocks_others = dep_issue("2", &[], None);
+        let mut assigned = dep_issue("3", &[], None);
+        assigned.assignee = Some("alice".to_string());
+        let labeled = labeled_issue("4", &["urgent", "backend"]);
+
+        let base = urgency(&plain, now, 0, &weights);
+        assert!(urgency(&unblocks_others, now, 3, &weights) > base);
+        assert!(urgency(&assigned, now, 0, &weights) > base);
+        assert!(urgency(&labeled, now, 0, &weights) > base);
+    }
+
+    #[test]
+    fn test_find_ready_ranked_orders_by_urgency_then_created_at() {
+        let now = Utc::now();
+        let mut urgent = priority_issue("urgent", Priority::P0, IssueType::Bug);
+        urgent.created_at = now;
+        let mut mundane = priority_issue("mundane", Priority::P4, IssueType::Chore);
+        mundane.created_at = now;
+
+        let issues = vec![mundane, urgent];
+        let ranked = find_ready_ranked(&issues, &UrgencyWeights::default());
+
+        assert_eq!(ranked[0].id, "urgent");
+        assert_eq!(ranked[1].id, "mundane");
+    }
+
+    #[test]
+    fn test_find_ready_ranked_excludes_blocked_and_closed() {
+        let mut closed = dep_issue("closed", &[], None);
+        closed.status = IssueStatus::Closed;
+        let mut blocked = dep_issue("blocked", &[], None);
+        blocked.status = IssueStatus::Blocked;
+        blocked.blocked_by = Some(vec!["other".to_string()]);
+        let open = dep_issue("open", &[], None);
+
+        let issues = vec![closed, blocked, open];
+        let ranked = find_ready_ranked(&issues, &UrgencyWeights::default());
+
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].id, "open");
+    }
+
+    /// epic
+    ///  └─ mid (parent: epic)
+    ///      └─ leaf (depends_on: mid)
+    fn epic_mid_leaf() -> Vec<BeadIssue> {
+        let epic = dep_issue("epic", &[], None);
+        let mut mid = dep_issue("mid", &[], None);
+        mid.parent = Some("epic".to_string());
+        let leaf = dep_issue("leaf", &["mid"], None);
+        vec![epic, mid, leaf]
+    }
+
+    #[test]
+    fn test_filter_by_depth_zero_returns_only_focus() {
+        let issues = epic_mid_leaf();
+        let result = filter_by_depth(&issues, "epic", 0);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "epic");
+    }
+
+    #[test]
+    fn test_filter_by_depth_one_level_descends_one_hop() {
+        let issues = epic_mid_leaf();
+        let result = filter_by_depth(&issues, "epic", 1);
+        let ids: std::collections::HashSet<&str> = result.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, std::collections::HashSet::from(["epic", "mid"]));
+    }
+
+    #[test]
+    fn test_filter_by_depth_two_levels_reaches_leaf() {
+        let issues = epic_mid_leaf();
+        let result = filter_by_depth(&issues, "epic", 2);
+        let ids: std::collections::HashSet<&str> = result.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, std::collections::HashSet::from(["epic", "mid", "leaf"]));
+    }
+
+    #[test]
+    fn test_filter_by_depth_negative_returns_only_leaves() {
+        let issues = epic_mid_leaf();
+        let result = filter_by_depth(&issues, "epic", -1);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "leaf");
+    }
+
+    #[test]
+    fn test_filter_by_depth_unknown_focus_is_empty_not_panic() {
+        let issues = epic_mid_leaf();
+        assert!(filter_by_depth(&issues, "does-not-exist", 2).is_empty());
+    }
+
+    #[test]
+    fn test_filter_by_depth_dangling_dependency_is_a_no_op() {
+        // "leaf" depends on an id that doesn't exist in `issues`.
+        let mut issues = epic_mid_leaf();
+        issues.push(dep_issue("dangling-parent", &["missing"], None));
+        let result = filter_by_depth(&issues, "dangling-parent", 3);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "dangling-parent");
+    }
+
+    #[test]
+    fn test_filter_issues_applies_depth_scope_before_other_predicates() {
+        let issues = epic_mid_leaf();
+        let filter = BeadFilter::new().with_depth("epic", 1);
+        let result = filter_issues(&issues, &filter);
+        let ids: std::collections::HashSet<&str> = result.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, std::collections::HashSet::from(["epic", "mid"]));
+    }
+
+    /// epic (label "urgent")
+    ///  └─ mid (parent: epic, no label)
+    ///      └─ leaf (depends_on: mid, no label)
+    fn tagged_epic_mid_leaf() -> Vec<BeadIssue> {
+        let epic = labeled_issue("epic", &["urgent"]);
+        let mut mid = dep_issue("mid", &[], None);
+        mid.parent = Some("epic".to_string());
+        let leaf = dep_issue("leaf", &["mid"], None);
+        vec![epic, mid, leaf]
+    }
+
+    #[test]
+    fn test_tag_expansion_pulls_in_subtree_below_threshold() {
+        let issues = tagged_epic_mid_leaf();
+        let filter = BeadFilter::new().with_label("urgent").with_tag_expansion(5);
+        let result = filter_issues(&issues, &filter);
+        let ids: std::collections::HashSet<&str> = result.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, std::collections::HashSet::from(["epic", "mid", "leaf"]));
+    }
+
+    #[test]
+    fn test_tag_expansion_skipped_at_or_above_threshold() {
+        let issues = tagged_epic_mid_leaf();
+        // Exactly 1 match, threshold 1: not below threshold, so no expansion.
+        let filter = BeadFilter::new().with_label("urgent").with_tag_expansion(1);
+        let result = filter_issues(&issues, &filter);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "epic");
+    }
+
+    #[test]
+    fn test_tag_expansion_disabled_without_threshold_is_unchanged() {
+        let issues = tagged_epic_mid_leaf();
+        let filter = BeadFilter::new().with_label("urgent");
+        let result = filter_issues(&issues, &filter);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "epic");
+    }
+
+    #[test]
+    fn test_tag_expansion_excludes_closed_unless_requested() {
+        let mut issues = tagged_epic_mid_leaf();
+        let mut done = dep_issue("done", &["mid"], None);
+        done.status = IssueStatus::Closed;
+        issues.push(done);
+
+        let filter = BeadFilter::new().with_label("urgent").with_tag_expansion(5);
+        let result = filter_issues(&issues, &filter);
+        assert!(!result.iter().any(|i| i.id == "done"));
+
+        // Explicitly including Closed in the status filter lets it survive
+        // expansion too, without narrowing the primary label match away from
+        // "epic" (which is still Open).
+        let filter_with_closed = BeadFilter::new()
+            .with_label("urgent")
+            .with_tag_expansion(5)
+            .with_status(IssueStatus::Open)
+            .with_status(IssueStatus::Closed);
+        let result_with_closed = filter_issues(&issues, &filter_with_closed);
+        assert!(result_with_closed.iter().any(|i| i.id == "done"));
+    }
+
+    #[test]
+    fn test_detect_cycles_finds_mutual_dependency() {
+        let a = dep_issue("a", &["b"], None);
+        let b = dep_issue("b", &["a"], None);
+        let c = dep_issue("c", &[], None);
+
+        let cycles = detect_cycles(&[a, b, c]);
+        assert_eq!(cycles.len(), 1);
+        let members: std::collections::HashSet<&str> = cycles[0].iter().map(String::as_str).collect();
+        assert_eq!(members, std::collections::HashSet::from(["a", "b"]));
+    }
+
+    #[test]
+    fn test_detect_cycles_ignores_closed_issues() {
+        let mut a = dep_issue("a", &["b"], None);
+        a.status = IssueStatus::Closed;
+        let mut b = dep_issue("b", &["a"], None);
+        b.status = IssueStatus::Closed;
+
+        assert!(detect_cycles(&[a, b]).is_empty());
+    }
+
+    #[test]
+    fn test_detect_cycles_empty_for_acyclic_graph() {
+        let a = dep_issue("a", &[], None);
+        let b = dep_issue("b", &["a"], None);
+        assert!(detect_cycles(&[a, b]).is_empty());
+    }
+
+    #[test]
+    fn test_topological_order_places_dependencies_first() {
+        let a = dep_issue("a", &[], None);
+        let b = dep_issue("b", &["a"], None);
+        let c = dep_issue("c", &["b"], None);
+
+        let order = topological_order(&[c, a, b]).expect("acyclic");
+        assert_eq!(order, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_topological_order_closed_dependency_does_not_block() {
+        let mut a = dep_issue("a", &[], None);
+        a.status = IssueStatus::Closed;
+        let b = dep_issue("b", &["a"], None);
+
+        let order = topological_order(&[a, b]).expect("closed deps don't block");
+        assert_eq!(order, vec!["b"]);
+    }
+
+    #[test]
+    fn test_topological_order_reports_cycle_error() {
+        let a = dep_issue("a", &["b"], None);
+        let b = dep_issue("b", &["a"], None);
+
+        let err = topological_order(&[a, b]).expect_err("expected cycle");
+        let CycleError(unresolved) = err;
+        let members: std::collections::HashSet<String> = unresolved.into_iter().collect();
+        assert_eq!(members, std::collections::HashSet::from(["a".to_string(), "b".to_string()]));
+    }
+
+    #[test]
+    fn test_find_ready_in_order_respects_dependency_order() {
+        let a = dep_issue("a", &[], None);
+        let b = dep_issue("b", &["a"], None);
+
+        let ready = find_ready_in_order(&[b, a]);
+        assert_eq!(ready.iter().map(|i| i.id.as_str()).collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_find_ready_in_order_excludes_blocked_issues() {
+        let a = dep_issue("a", &[], None);
+        let mut blocked = dep_issue("blocked", &[], None);
+        blocked.status = IssueStatus::Blocked;
+        blocked.blocked_by = Some(vec!["other".to_string()]);
+
+        let ready = find_ready_in_order(&[a, blocked]);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].id, "a");
+    }
+
+    #[test]
+    fn test_find_ready_in_order_still_surfaces_ready_work_outside_a_cycle() {
+        let a = dep_issue("a", &["b"], None);
+        let b = dep_issue("b", &["a"], None);
+        let unrelated = dep_issue("unrelated", &[], None);
+
+        let ready = find_ready_in_order(&[a, b, unrelated]);
+        assert!(ready.iter().any(|i| i.id == "unrelated"));
+    }
+
+    #[test]
+    fn test_search_finds_exact_term_in_title() {
+        let issues = vec![text_issue("1", "Fix login bug", "nothing to see")];
+        let hits = search(&issues, "login", &SearchOpts::default());
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].issue.id, "1");
+        assert!(hits[0].spans.iter().any(|s| s.field == MatchField::Title && s.exact));
+    }
+
+    #[test]
+    fn test_search_tolerates_typo_within_budget() {
+        let issues = vec![text_issue("1", "Fix authentication bug", "nothing to see")];
+        // "authentification" is within distance 2 of "authentication" (len >= 9)
+        let hits = search(&issues, "authentification", &SearchOpts::default());
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].spans.iter().any(|s| !s.exact));
+    }
+
+    #[test]
+    fn test_search_rejects_typo_outside_budget() {
+        let issues = vec![text_issue("1", "Fix bug", "nothing to see")];
+        // "xyzq" is short (budget 0) and not equal to "bug"
+        let hits = search(&issues, "xyzq", &SearchOpts::default());
+        assert!(hits.is_empty());
+    }
+
+    #[test]
+    fn test_search_reports_match_offset_for_highlighting() {
+        let issues = vec![text_issue("1", "Fix login bug", "nothing to see")];
+        let hits = search(&issues, "login", &SearchOpts::default());
+        let span = hits[0].spans.first().expect("one span");
+        assert_eq!(&issues[0].title[span.start..span.end], "login");
+    }
+
+    #[test]
+    fn test_search_ranks_more_matched_terms_first() {
+        let issues = vec![
+            text_issue("one-term", "login", "unrelated text"),
+            text_issue("two-terms", "login bug", "unrelated text"),
+        ];
+        let hits = search(&issues, "login bug", &SearchOpts::default());
+        assert_eq!(hits[0].issue.id, "two-terms");
+    }
+
+    #[test]
+    fn test_search_ranks_tighter_proximity_first() {
+        let issues = vec![
+            text_issue("far", "login now and later bug report", "x"),
+            text_issue("close", "login bug report", "x"),
+        ];
+        let hits = search(&issues, "login bug", &SearchOpts::default());
+        assert_eq!(hits[0].issue.id, "close");
+    }
+
+    #[test]
+    fn test_search_ranks_title_above_description() {
+        let issues = vec![
+            text_issue("in-description", "unrelated", "login bug report here"),
+            text_issue("in-title", "login bug report", "unrelated"),
+        ];
+        let hits = search(&issues, "login bug", &SearchOpts::default());
+        assert_eq!(hits[0].issue.id, "in-title");
+    }
+
+    #[test]
+    fn test_search_matches_labels() {
+        let issues = vec![labeled_issue("1", &["urgent", "backend"])];
+        let hits = search(&issues, "backend", &SearchOpts::default());
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].spans.iter().any(|s| s.field == MatchField::Label));
+    }
+
+    #[test]
+    fn test_search_empty_query_returns_no_hits() {
+        let issues = vec![text_issue("1", "Fix login bug", "nothing to see")];
+        assert!(search(&issues, "", &SearchOpts::default()).is_empty());
+    }
 }